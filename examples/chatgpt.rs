@@ -1,5 +1,5 @@
 use fieri::{
-    chat::{chat, ChatMessageBuilder, ChatParamBuilder},
+    chat::{create, ChatMessageBuilder, ChatParamBuilder},
     Client, Error,
 };
 
@@ -10,7 +10,7 @@ async fn main() -> Result<(), Error> {
     let message = ChatMessageBuilder::new("user", "Hello!").build()?;
     let param = ChatParamBuilder::new("gpt-3.5-turbo", vec![message]).build()?;
 
-    let resp = chat(&client, &param).await?;
+    let resp = create(&client, &param).await?;
     println!("Generated text: {:#?}", resp);
 
     Ok(())
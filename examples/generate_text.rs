@@ -1,3 +1,5 @@
+#![allow(deprecated)]
+
 use fieri::{
     completion::{create, CompletionParamBuilder},
     Client, Error,
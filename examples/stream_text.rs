@@ -1,9 +1,12 @@
 //! Create a completion stream for the provided prompt and parameters.
 
+#![allow(deprecated)]
+
 use fieri::{
-    completion::{create_with_stream, Completion, CompletionParamBuilder},
+    completion::{create_stream, CompletionParamBuilder},
     Client, Error,
 };
+use futures::StreamExt;
 
 #[tokio::main]
 async fn main() -> Result<(), Error> {
@@ -14,15 +17,10 @@ async fn main() -> Result<(), Error> {
         .temperature(0.5)
         .build()?;
 
-    let mut resp = create_with_stream(&client, &param).await?;
-
-    while let Some(chunk) = resp.chunk().await? {
-        if chunk.to_vec() == b"data: [DONE]\n\n" {
-            break;
-        }
+    let mut stream = create_stream(&client, &param).await?;
 
-        let v: Completion = serde_json::from_slice(&chunk[5..])?;
-        v.choices.iter().for_each(|c| println!("{:?}", c.text));
+    while let Some(chunk) = stream.next().await {
+        chunk?.choices.iter().for_each(|c| println!("{:?}", c.text));
     }
 
     Ok(())
@@ -0,0 +1,174 @@
+//! Build assistants, which follow instructions and use tools like the code interpreter, file search and your functions,
+//! to respond within [`threads`](crate::thread).
+
+use crate::{Client, Result};
+
+pub use crate::types::{
+    Assistant, AssistantParam, AssistantParamBuilder, AssistantTool, ChunkingStrategy,
+    CodeInterpreterResources, Delete, FileSearchOptions, FileSearchResources, ListAssistants,
+    ListParam, ListParamBuilder, NewVectorStore, Order, RankingOptions, StaticChunking,
+    ToolResources,
+};
+
+/// Creates an assistant with a model and instructions.
+///
+/// Related OpenAI docs: [Create Assistant](https://platform.openai.com/docs/api-reference/assistants/createAssistant)
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{Client, assistant::{create, AssistantParamBuilder, AssistantTool}};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let param = AssistantParamBuilder::new("gpt-4o")
+///         .name("Math Tutor")
+///         .instructions("You are a personal math tutor. Write and run code to answer math questions.")
+///         .tools(vec![AssistantTool::CodeInterpreter])
+///         .build()?;
+///
+///     let resp = create(&client, &param).await?;
+///     println!("{}", resp.id);
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn create(client: &Client, param: &AssistantParam) -> Result<Assistant> {
+    client.create_assistant(param).await
+}
+
+/// Retrieves an assistant.
+///
+/// Related OpenAI docs: [Retrieve Assistant](https://platform.openai.com/docs/api-reference/assistants/getAssistant)
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{Client, assistant::retrieve};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let resp = retrieve(&client, "asst_abc123").await?;
+///     println!("{:#?}", resp);
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn retrieve(client: &Client, assistant_id: impl Into<String>) -> Result<Assistant> {
+    client.retrieve_assistant(assistant_id.into()).await
+}
+
+/// Modifies an assistant, changing only the fields set in the parameters.
+///
+/// Related OpenAI docs: [Modify Assistant](https://platform.openai.com/docs/api-reference/assistants/modifyAssistant)
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{Client, assistant::{modify, AssistantParamBuilder}};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let param = AssistantParamBuilder::default()
+///         .instructions("Always answer in French.")
+///         .build()?;
+///
+///     let resp = modify(&client, "asst_abc123", &param).await?;
+///     println!("{:#?}", resp);
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn modify(
+    client: &Client,
+    assistant_id: impl Into<String>,
+    param: &AssistantParam,
+) -> Result<Assistant> {
+    client.modify_assistant(assistant_id.into(), param).await
+}
+
+/// Deletes an assistant.
+///
+/// Related OpenAI docs: [Delete Assistant](https://platform.openai.com/docs/api-reference/assistants/deleteAssistant)
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{Client, assistant::delete};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let resp = delete(&client, "asst_abc123").await?;
+///     println!("{}", resp.deleted);
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn delete(client: &Client, assistant_id: impl Into<String>) -> Result<Delete> {
+    client.delete_assistant(assistant_id.into()).await
+}
+
+/// Returns a paginated list of assistants.
+///
+/// Related OpenAI docs: [List Assistants](https://platform.openai.com/docs/api-reference/assistants/listAssistants)
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{Client, assistant::{list, ListParamBuilder, Order}};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let param = ListParamBuilder::default()
+///         .order(Order::Asc)
+///         .limit(50u32)
+///         .build()?;
+///
+///     let resp = list(&client, &param).await?;
+///     println!("{:#?}", resp.data);
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn list(client: &Client, param: &ListParam) -> Result<ListAssistants> {
+    client.list_assistants(param).await
+}
+
+impl Client {
+    async fn create_assistant(&self, param: &AssistantParam) -> Result<Assistant> {
+        self.post::<AssistantParam, Assistant>("assistants", Some(param))
+            .await
+    }
+
+    async fn retrieve_assistant(&self, assistant_id: String) -> Result<Assistant> {
+        self.get::<(), Assistant>(&format!("assistants/{assistant_id}"), None)
+            .await
+    }
+
+    async fn modify_assistant(
+        &self,
+        assistant_id: String,
+        param: &AssistantParam,
+    ) -> Result<Assistant> {
+        self.post::<AssistantParam, Assistant>(&format!("assistants/{assistant_id}"), Some(param))
+            .await
+    }
+
+    async fn delete_assistant(&self, assistant_id: String) -> Result<Delete> {
+        self.delete::<(), Delete>(&format!("assistants/{assistant_id}"), None)
+            .await
+    }
+
+    async fn list_assistants(&self, param: &ListParam) -> Result<ListAssistants> {
+        self.get::<ListParam, ListAssistants>("assistants", Some(param))
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {}
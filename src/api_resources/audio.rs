@@ -0,0 +1,269 @@
+//! Turn audio into text with the speech to text models, either transcribed in its language or translated into English,
+//! and text into lifelike spoken audio with the text to speech models.
+//!
+//! Audio files are uploaded from a path, bytes or a stream, see [`FileUpload`], in one of the supported formats:
+//! `mp3`, `mp4`, `mpeg`, `mpga`, `m4a`, `wav`, or `webm`, up to 25MB.
+
+use futures::TryStreamExt;
+
+use crate::{
+    client::{ByteStream, EventStream},
+    Client, Result,
+};
+
+pub use crate::types::{
+    Caption, FileUpload, SpeechFormat, SpeechParam, SpeechParamBuilder, TimestampGranularity,
+    Transcription, TranscriptionEvent, TranscriptionFormat, TranscriptionParam,
+    TranscriptionParamBuilder, TranscriptionSegment, TranscriptionUsage, TranscriptionWord,
+    Translation, TranslationParam, TranslationParamBuilder, Voice,
+};
+
+/// Transcribes audio into the input language.
+///
+/// With the `verbose_json` [`format`](TranscriptionFormat::VerboseJson), the transcription comes along with
+/// its timestamped [`segments`](Transcription::segments) and [`words`](Transcription::words), as requested by the
+/// [`timestamp_granularities`](TranscriptionParam::timestamp_granularities).
+/// With the `srt` or `vtt` formats, the subtitles are also parsed into [`captions`](Transcription::captions).
+///
+/// Related OpenAI docs: [Create Transcription](https://platform.openai.com/docs/api-reference/audio/createTranscription)
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{Client, audio::{transcribe, TranscriptionParamBuilder}};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let param = TranscriptionParamBuilder::new("whisper-1")
+///         .language("en")
+///         .build()?;
+///
+///     let resp = transcribe(&client, "path-to-audio.mp3", &param).await?;
+///     println!("{}", resp.text);
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn transcribe(
+    client: &Client,
+    file: impl Into<FileUpload>,
+    param: &TranscriptionParam,
+) -> Result<Transcription> {
+    client.create_transcription(file.into(), param).await
+}
+
+/// Transcribes audio into the input language, streaming back the text as it's transcribed.
+///
+/// The `stream` parameter is implicitly set, the text arrives as [`Delta`](TranscriptionEvent::Delta) events
+/// followed by a final [`Done`](TranscriptionEvent::Done) event. Not supported by `whisper-1`.
+/// Events unknown to this version of the crate are skipped.
+///
+/// Related OpenAI docs: [Create Transcription](https://platform.openai.com/docs/api-reference/audio/createTranscription)
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{Client, audio::{transcribe_stream, TranscriptionEvent, TranscriptionParamBuilder}};
+/// use futures::StreamExt;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let param = TranscriptionParamBuilder::new("gpt-4o-transcribe").build()?;
+///
+///     let mut stream = transcribe_stream(&client, "path-to-audio.mp3", &param).await?;
+///
+///     while let Some(event) = stream.next().await {
+///         if let TranscriptionEvent::Delta { delta } = event? {
+///             print!("{delta}");
+///         }
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn transcribe_stream(
+    client: &Client,
+    file: impl Into<FileUpload>,
+    param: &TranscriptionParam,
+) -> Result<EventStream<TranscriptionEvent>> {
+    client.create_transcription_stream(file.into(), param).await
+}
+
+/// Translates audio into English.
+///
+/// Related OpenAI docs: [Create Translation](https://platform.openai.com/docs/api-reference/audio/createTranslation)
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{Client, audio::{translate, TranslationParamBuilder}};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let param = TranslationParamBuilder::new("whisper-1").build()?;
+///
+///     let resp = translate(&client, "path-to-german-audio.mp3", &param).await?;
+///     println!("{}", resp.text);
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn translate(
+    client: &Client,
+    file: impl Into<FileUpload>,
+    param: &TranslationParam,
+) -> Result<Translation> {
+    client.create_translation(file.into(), param).await
+}
+
+/// Generates audio from the input text, returned once it's fully generated.
+///
+/// Related OpenAI docs: [Create Speech](https://platform.openai.com/docs/api-reference/audio/createSpeech)
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{Client, audio::{speech, SpeechParamBuilder, Voice}};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let param = SpeechParamBuilder::new("tts-1", "Today is a wonderful day.", Voice::Alloy).build()?;
+///
+///     let audio = speech(&client, &param).await?;
+///     std::fs::write("speech.mp3", audio)?;
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn speech(client: &Client, param: &SpeechParam) -> Result<bytes::Bytes> {
+    client.create_speech(param).await
+}
+
+/// Generates audio from the input text, streaming back its bytes as they are generated.
+///
+/// Useful to start playing the audio, or writing it to a file, before the whole of it is generated.
+///
+/// Related OpenAI docs: [Create Speech](https://platform.openai.com/docs/api-reference/audio/createSpeech)
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{Client, audio::{speech_stream, SpeechFormat, SpeechParamBuilder, Voice}};
+/// use futures::StreamExt;
+/// use tokio::io::AsyncWriteExt;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let param = SpeechParamBuilder::new("tts-1", "Today is a wonderful day.", Voice::Nova)
+///         .response_format(SpeechFormat::Opus)
+///         .speed(1.25)
+///         .build()?;
+///
+///     let mut stream = speech_stream(&client, &param).await?;
+///     let mut file = tokio::fs::File::create("speech.opus").await?;
+///
+///     while let Some(chunk) = stream.next().await {
+///         file.write_all(&chunk?).await?;
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn speech_stream(client: &Client, param: &SpeechParam) -> Result<ByteStream> {
+    client.create_speech_stream(param).await
+}
+
+impl Client {
+    async fn create_transcription(
+        &self,
+        file: FileUpload,
+        param: &TranscriptionParam,
+    ) -> Result<Transcription> {
+        self.limit_form(&param.model, param).await?;
+        let form = param.to_form(file)?;
+
+        let format = param.response_format.unwrap_or_default();
+        if format.is_json() {
+            return self
+                .post_data::<Transcription>("audio/transcriptions", form)
+                .await;
+        }
+
+        let text = self.post_data_text("audio/transcriptions", form).await?;
+        Ok(Transcription {
+            captions: format.is_subtitles().then(|| Caption::parse(&text)),
+            text,
+            ..Default::default()
+        })
+    }
+
+    async fn create_transcription_stream(
+        &self,
+        file: FileUpload,
+        param: &TranscriptionParam,
+    ) -> Result<EventStream<TranscriptionEvent>> {
+        let mut param = param.clone();
+        param.stream = true;
+        self.limit_form(&param.model, &param).await?;
+
+        let stream = self
+            .post_data_event_stream::<TranscriptionEvent>(
+                "audio/transcriptions",
+                param.to_form(file)?,
+            )
+            .await?;
+
+        Ok(Box::pin(stream.try_filter(|event| {
+            futures::future::ready(!matches!(event, TranscriptionEvent::Other))
+        })))
+    }
+
+    async fn create_translation(
+        &self,
+        file: FileUpload,
+        param: &TranslationParam,
+    ) -> Result<Translation> {
+        self.limit_form(&param.model, param).await?;
+        let form = param.to_form(file)?;
+
+        let format = param.response_format.unwrap_or_default();
+        if format.is_json() {
+            return self
+                .post_data::<Translation>("audio/translations", form)
+                .await;
+        }
+
+        let text = self.post_data_text("audio/translations", form).await?;
+        Ok(Translation {
+            captions: format.is_subtitles().then(|| Caption::parse(&text)),
+            text,
+            ..Default::default()
+        })
+    }
+
+    async fn create_speech(&self, param: &SpeechParam) -> Result<bytes::Bytes> {
+        let audio = self
+            .create_speech_stream(param)
+            .await?
+            .try_fold(Vec::new(), |mut audio, chunk| async move {
+                audio.extend_from_slice(&chunk);
+                Ok(audio)
+            })
+            .await?;
+
+        Ok(audio.into())
+    }
+
+    async fn create_speech_stream(&self, param: &SpeechParam) -> Result<ByteStream> {
+        self.post_byte_stream::<SpeechParam>("audio/speech", Some(param))
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {}
@@ -0,0 +1,540 @@
+//! Process large numbers of requests asynchronously with the Batch API, at half the price of the synchronous endpoints,
+//! within 24 hours.
+//!
+//! The requests are uploaded as a JSONL [`file`](crate::file) with the [`batch`](crate::file::Purpose::Batch) purpose,
+//! and their responses downloaded from the [`output_file_id`](Batch::output_file_id) once the batch completed.
+//! The input file is written out of typed params with a [`BatchFileWriter`], and the results read back
+//! as typed responses with [`results`].
+//!
+//! ## Example
+//! ```no_run
+//! use fieri::{Client, batch::{create, BatchFileWriter, CreateBatchParamBuilder}, chat::{ChatMessage, ChatParamBuilder}};
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let client = Client::new();
+//!
+//!     let mut writer = BatchFileWriter::in_memory();
+//!     for (id, question) in [("q1", "What's 2 + 2?"), ("q2", "What's the capital of France?")] {
+//!         let param = ChatParamBuilder::new("gpt-4o-mini", vec![ChatMessage::user(question)]).build()?;
+//!         writer.write(id, &param)?;
+//!     }
+//!
+//!     let (file, endpoint) = writer.upload(&client, "questions.jsonl").await?;
+//!     let resp = create(&client, &CreateBatchParamBuilder::new(file.id, endpoint).build()?).await?;
+//!     println!("{}", resp.id);
+//!
+//!     Ok(())
+//! }
+//! ```
+
+use std::{
+    collections::HashSet,
+    fs,
+    io::{BufRead, BufWriter, Write},
+    path::Path,
+    pin::Pin,
+};
+
+use async_stream::try_stream;
+use futures::{Stream, StreamExt};
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{
+    types::{
+        ChatParam, CompletionParam, EmbeddingParam, File, FileUpload, ModerationParam, Purpose,
+        ResponseParam,
+    },
+    Client, Error, Result,
+};
+
+pub use crate::types::{
+    Batch, BatchEndpoint, BatchError, BatchErrors, BatchRequestCounts, BatchResponse, BatchResult,
+    BatchStatus, CreateBatchParam, CreateBatchParamBuilder, ListBatches, ListBatchesParam,
+    ListBatchesParamBuilder, PollOptions, PollOptionsBuilder,
+};
+
+/// Creates and starts a batch from an uploaded file of requests.
+///
+/// Related OpenAI docs: [Create Batch](https://platform.openai.com/docs/api-reference/batch/create)
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{Client, batch::{create, BatchEndpoint, CreateBatchParamBuilder}};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let param = CreateBatchParamBuilder::new("file-abc123", BatchEndpoint::ChatCompletions).build()?;
+///
+///     let resp = create(&client, &param).await?;
+///     println!("{:?}", resp.status);
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn create(client: &Client, param: &CreateBatchParam) -> Result<Batch> {
+    client.create_batch(param).await
+}
+
+/// Retrieves a batch, along with its status and the numbers of its requests.
+///
+/// Related OpenAI docs: [Retrieve Batch](https://platform.openai.com/docs/api-reference/batch/retrieve)
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{Client, batch::retrieve};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let resp = retrieve(&client, "batch_abc123").await?;
+///     println!("{:?}", resp.request_counts);
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn retrieve(client: &Client, batch_id: impl Into<String>) -> Result<Batch> {
+    client.retrieve_batch(batch_id.into()).await
+}
+
+/// Cancels a batch in progress.
+///
+/// The batch is [`cancelling`](BatchStatus::Cancelling) for up to 10 minutes, before being cancelled
+/// with the responses of the requests already processed in its output file.
+///
+/// Related OpenAI docs: [Cancel Batch](https://platform.openai.com/docs/api-reference/batch/cancel)
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{Client, batch::cancel};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let resp = cancel(&client, "batch_abc123").await?;
+///     println!("{:?}", resp.status);
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn cancel(client: &Client, batch_id: impl Into<String>) -> Result<Batch> {
+    client.cancel_batch(batch_id.into()).await
+}
+
+/// Returns a paginated list of the batches of the organization.
+///
+/// Related OpenAI docs: [List Batches](https://platform.openai.com/docs/api-reference/batch/list)
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{Client, batch::{list, ListBatchesParamBuilder}};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let param = ListBatchesParamBuilder::default().limit(10u32).build()?;
+///
+///     let resp = list(&client, &param).await?;
+///     println!("{:#?}", resp.data);
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn list(client: &Client, param: &ListBatchesParam) -> Result<ListBatches> {
+    client.list_batches(param).await
+}
+
+/// A stream of the results of a batch, as pairs of the custom id of each request and its typed response.
+#[cfg(not(target_arch = "wasm32"))]
+pub type BatchResults<T> = Pin<Box<dyn Stream<Item = Result<(String, Result<T>)>> + Send>>;
+/// A stream of the results of a batch, as pairs of the custom id of each request and its typed response.
+#[cfg(target_arch = "wasm32")]
+pub type BatchResults<T> = Pin<Box<dyn Stream<Item = Result<(String, Result<T>)>>>>;
+
+/// Streams the results out of the output or error file of a batch, parsing the responses as `T`,
+/// like [`Chat`](crate::types::Chat) for a batch of chat completions.
+///
+/// The outer error is of the download of the file, while the inner one is of the request, the API error it failed with.
+///
+/// Related OpenAI docs: [The request output object](https://platform.openai.com/docs/api-reference/batch/request-output)
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{Client, batch::{results, retrieve}, types::Chat};
+/// use futures::StreamExt;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let batch = retrieve(&client, "batch_abc123").await?;
+///     for file_id in [batch.output_file_id, batch.error_file_id].into_iter().flatten() {
+///         let mut stream = results::<Chat>(&client, file_id).await?;
+///
+///         while let Some(result) = stream.next().await {
+///             match result? {
+///                 (custom_id, Ok(chat)) => println!("{custom_id}: {:?}", chat.choices[0].message.content),
+///                 (custom_id, Err(err)) => println!("{custom_id} failed: {err}"),
+///             }
+///         }
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn results<T>(client: &Client, file_id: impl Into<String>) -> Result<BatchResults<T>>
+where
+    T: DeserializeOwned + Send + 'static,
+{
+    let mut bytes = crate::file::content_stream(client, file_id).await?;
+
+    Ok(Box::pin(try_stream! {
+        let mut buffer: Vec<u8> = Vec::new();
+
+        while let Some(chunk) = bytes.next().await {
+            buffer.extend_from_slice(&chunk?);
+
+            while let Some(pos) = buffer.iter().position(|b| *b == b'\n') {
+                let line = buffer.drain(..=pos).collect::<Vec<_>>();
+                if let Some(result) = parse_line(&line)? {
+                    yield result;
+                }
+            }
+        }
+        if let Some(result) = parse_line(&buffer)? {
+            yield result;
+        }
+    }))
+}
+
+/// Reads the results out of an output or error file of a batch already on disk, like one [`downloaded`](crate::file::download_to),
+/// parsing the responses as `T`.
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{batch::read_results, types::Embedding};
+/// use std::{fs::File, io::BufReader};
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let file = BufReader::new(File::open("batch_output.jsonl")?);
+///
+///     for result in read_results::<Embedding>(file) {
+///         let (custom_id, embedding) = result?;
+///         println!("{custom_id}: {:?}", embedding.map(|e| e.data.len()));
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+pub fn read_results<T: DeserializeOwned>(
+    reader: impl BufRead,
+) -> impl Iterator<Item = Result<(String, Result<T>)>> {
+    reader.split(b'\n').filter_map(|line| match line {
+        Ok(line) => parse_line(&line).transpose(),
+        Err(err) => Some(Err(err.into())),
+    })
+}
+
+fn parse_line<T: DeserializeOwned>(line: &[u8]) -> Result<Option<(String, Result<T>)>> {
+    if line.iter().all(u8::is_ascii_whitespace) {
+        return Ok(None);
+    }
+
+    Ok(Some(serde_json::from_slice::<BatchResult>(line)?.parse()))
+}
+
+/// The body of a request that can be sent in a batch, along with the endpoint it's sent to.
+pub trait BatchBody: Serialize {
+    const ENDPOINT: BatchEndpoint;
+}
+
+impl BatchBody for ChatParam {
+    const ENDPOINT: BatchEndpoint = BatchEndpoint::ChatCompletions;
+}
+
+impl BatchBody for EmbeddingParam {
+    const ENDPOINT: BatchEndpoint = BatchEndpoint::Embeddings;
+}
+
+impl BatchBody for CompletionParam {
+    const ENDPOINT: BatchEndpoint = BatchEndpoint::Completions;
+}
+
+impl BatchBody for ResponseParam {
+    const ENDPOINT: BatchEndpoint = BatchEndpoint::Responses;
+}
+
+impl BatchBody for ModerationParam {
+    const ENDPOINT: BatchEndpoint = BatchEndpoint::Moderations;
+}
+
+/// The maximum number of requests in the input file of a batch.
+pub const MAX_BATCH_REQUESTS: usize = 50_000;
+
+/// The reasons why a request can't be added to the input file of a batch.
+#[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum BatchRequestError {
+    #[error("The custom id {0} is already used by another request of the batch.")]
+    DuplicateCustomId(String),
+
+    #[error("The request is sent to {found}, while the batch is sent to {expected}.", found = .found.as_str(), expected = .expected.as_str())]
+    MixedEndpoints {
+        expected: BatchEndpoint,
+        found: BatchEndpoint,
+    },
+
+    #[error("The batch already has the maximum of 50,000 requests.")]
+    TooManyRequests,
+}
+
+#[derive(Serialize)]
+struct BatchRequest<'a, T> {
+    custom_id: &'a str,
+    method: &'static str,
+    url: &'static str,
+    body: &'a T,
+}
+
+/// Writes the requests of a batch as JSONL, one request per line, in the format of the input file of the Batch API.
+///
+/// All the requests of a batch are sent to the same endpoint, and are told apart through their unique custom ids.
+#[derive(Debug)]
+pub struct BatchFileWriter<W: Write> {
+    writer: W,
+    endpoint: Option<BatchEndpoint>,
+    custom_ids: HashSet<String>,
+}
+
+impl<W: Write> BatchFileWriter<W> {
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            endpoint: None,
+            custom_ids: HashSet::new(),
+        }
+    }
+
+    /// Writes the request under the given custom id, failing with [`Error::BatchRequestError`]
+    /// if it can't be part of the batch.
+    pub fn write<T: BatchBody>(&mut self, custom_id: impl Into<String>, body: &T) -> Result<()> {
+        let custom_id = custom_id.into();
+        let fail = |source| Error::BatchRequestError {
+            custom_id: custom_id.clone(),
+            source,
+        };
+
+        match self.endpoint {
+            Some(expected) if expected != T::ENDPOINT => {
+                return Err(fail(BatchRequestError::MixedEndpoints {
+                    expected,
+                    found: T::ENDPOINT,
+                }))
+            }
+            _ => {}
+        }
+        if self.custom_ids.len() >= MAX_BATCH_REQUESTS {
+            return Err(fail(BatchRequestError::TooManyRequests));
+        }
+        if self.custom_ids.contains(&custom_id) {
+            return Err(fail(BatchRequestError::DuplicateCustomId(
+                custom_id.clone(),
+            )));
+        }
+
+        let request = BatchRequest {
+            custom_id: &custom_id,
+            method: "POST",
+            url: T::ENDPOINT.as_str(),
+            body,
+        };
+        serde_json::to_writer(&mut self.writer, &request)?;
+        self.writer.write_all(b"\n")?;
+
+        self.endpoint = Some(T::ENDPOINT);
+        self.custom_ids.insert(custom_id);
+
+        Ok(())
+    }
+
+    /// The endpoint of the requests, once one is written.
+    pub fn endpoint(&self) -> Option<BatchEndpoint> {
+        self.endpoint
+    }
+
+    /// The number of requests written so far.
+    pub fn len(&self) -> usize {
+        self.custom_ids.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.custom_ids.is_empty()
+    }
+
+    /// Flushes the requests, returning the underlying writer.
+    pub fn into_inner(mut self) -> Result<W> {
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+}
+
+impl BatchFileWriter<Vec<u8>> {
+    /// A writer keeping the input file in memory.
+    pub fn in_memory() -> Self {
+        Self::new(Vec::new())
+    }
+
+    /// Turns the input file into an upload for the [`Files`](crate::file::upload) API, under the given name.
+    pub fn into_upload(self, file_name: impl Into<String>) -> Result<FileUpload> {
+        Ok(FileUpload::bytes(file_name, self.into_inner()?))
+    }
+
+    /// Uploads the input file with the [`batch`](Purpose::Batch) purpose,
+    /// returning it along with the endpoint to [`create`] the batch for.
+    ///
+    /// An empty writer has no endpoint, and defaults to [`BatchEndpoint::ChatCompletions`].
+    pub async fn upload(
+        self,
+        client: &Client,
+        file_name: impl Into<String>,
+    ) -> Result<(File, BatchEndpoint)> {
+        let endpoint = self.endpoint.unwrap_or_default();
+        let file =
+            crate::file::upload(client, self.into_upload(file_name)?, Purpose::Batch).await?;
+
+        Ok((file, endpoint))
+    }
+}
+
+impl BatchFileWriter<BufWriter<fs::File>> {
+    /// A writer creating the input file at the given path, or truncating it if it exists.
+    pub fn create(path: impl AsRef<Path>) -> Result<Self> {
+        Ok(Self::new(BufWriter::new(fs::File::create(path)?)))
+    }
+}
+
+impl Client {
+    async fn create_batch(&self, param: &CreateBatchParam) -> Result<Batch> {
+        self.post::<CreateBatchParam, Batch>("batches", Some(param))
+            .await
+    }
+
+    async fn retrieve_batch(&self, batch_id: String) -> Result<Batch> {
+        self.get::<(), Batch>(&format!("batches/{batch_id}"), None)
+            .await
+    }
+
+    async fn cancel_batch(&self, batch_id: String) -> Result<Batch> {
+        self.post::<(), Batch>(&format!("batches/{batch_id}/cancel"), None)
+            .await
+    }
+
+    async fn list_batches(&self, param: &ListBatchesParam) -> Result<ListBatches> {
+        self.get::<ListBatchesParam, ListBatches>("batches", Some(param))
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Chat, ChatContent, ChatMessage, ChatParamBuilder, EmbeddingParamBuilder};
+
+    #[test]
+    fn test_batch_file_writer() {
+        let mut writer = BatchFileWriter::in_memory();
+        assert_eq!(writer.endpoint(), None);
+
+        for (id, question) in [("q1", "Hi!"), ("q2", "Bye!")] {
+            let param = ChatParamBuilder::new("gpt-4o-mini", vec![ChatMessage::user(question)])
+                .build()
+                .unwrap();
+            writer.write(id, &param).unwrap();
+        }
+        assert_eq!(writer.endpoint(), Some(BatchEndpoint::ChatCompletions));
+
+        let param = ChatParamBuilder::new("gpt-4o-mini", vec![ChatMessage::user("Again!")])
+            .build()
+            .unwrap();
+        assert!(matches!(
+            writer.write("q1", &param),
+            Err(Error::BatchRequestError {
+                source: BatchRequestError::DuplicateCustomId(_),
+                ..
+            })
+        ));
+
+        let param = EmbeddingParamBuilder::new("text-embedding-3-small", "Hi!")
+            .build()
+            .unwrap();
+        assert!(matches!(
+            writer.write("e1", &param),
+            Err(Error::BatchRequestError {
+                source: BatchRequestError::MixedEndpoints {
+                    expected: BatchEndpoint::ChatCompletions,
+                    found: BatchEndpoint::Embeddings,
+                },
+                ..
+            })
+        ));
+        assert_eq!(writer.len(), 2);
+
+        let jsonl = String::from_utf8(writer.into_inner().unwrap()).unwrap();
+        let requests = jsonl
+            .lines()
+            .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+            .collect::<Vec<_>>();
+
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0]["custom_id"], "q1");
+        assert_eq!(requests[0]["method"], "POST");
+        assert_eq!(requests[0]["url"], "/v1/chat/completions");
+        assert_eq!(requests[1]["body"]["model"], "gpt-4o-mini");
+        assert_eq!(requests[1]["body"]["messages"][0]["content"], "Bye!");
+    }
+
+    #[test]
+    fn test_batch_results() {
+        let jsonl = r#"{"id": "batch_req_1", "custom_id": "q1", "response": {"status_code": 200, "request_id": "req_1", "body": {"id": "chatcmpl-1", "object": "chat.completion", "model": "gpt-4o-mini", "choices": [{"index": 0, "message": {"role": "assistant", "content": "4"}, "finish_reason": "stop"}]}}, "error": null}
+{"id": "batch_req_2", "custom_id": "q2", "response": {"status_code": 400, "request_id": "req_2", "body": {"error": {"message": "Invalid model.", "type": "invalid_request_error", "param": "model", "code": null}}}, "error": null}
+
+{"id": "batch_req_3", "custom_id": "q3", "response": null, "error": {"code": "batch_expired", "message": "This request could not be executed before the completion window expired."}}
+"#;
+
+        let results = read_results::<Chat>(jsonl.as_bytes())
+            .collect::<Result<Vec<_>>>()
+            .unwrap();
+        assert_eq!(results.len(), 3);
+
+        let (custom_id, chat) = &results[0];
+        assert_eq!(custom_id, "q1");
+        assert!(matches!(
+            &chat.as_ref().unwrap().choices[0].message.content,
+            ChatContent::Text(text) if text == "4"
+        ));
+
+        assert!(matches!(
+            &results[1],
+            (custom_id, Err(Error::APIError(err)))
+                if custom_id == "q2" && err.error.r#type == "invalid_request_error"
+        ));
+        assert_eq!(
+            results[1].1.as_ref().unwrap_err().request_id(),
+            Some("req_2")
+        );
+        assert!(matches!(
+            &results[2],
+            (custom_id, Err(Error::APIError(err)))
+                if custom_id == "q3" && err.error.code == "batch_expired"
+        ));
+
+        assert!(read_results::<Chat>("not json\n".as_bytes())
+            .next()
+            .unwrap()
+            .is_err());
+    }
+}
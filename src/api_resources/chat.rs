@@ -1,15 +1,258 @@
-use crate::{
-    types::{Chat, ChatParam},
-    Client, Result,
+//! Given a list of messages comprising a conversation, the model will return a response.
+//!
+//! The chat completions endpoint is the recommended way of interacting with the newer models, like `gpt-3.5-turbo` and `gpt-4`.
+//!
+//! Each message is described by the [`role`](ChatRole) of its author and its content.
+//! Besides text, the content can include images and audio for models supporting them, see [`ContentPart`].
+//! Typically, a conversation is formatted with a system message first, followed by alternating user and assistant messages.
+
+use serde::de::DeserializeOwned;
+
+use crate::{client::EventStream, types::Delete, Client, Result};
+
+pub use crate::types::{
+    Chat, ChatAccumulator, ChatAudio, ChatAudioFormat, ChatAudioParam, ChatChoice, ChatChunk,
+    ChatChunkChoice, ChatContent, ChatDelta, ChatList, ChatLogprobs, ChatMessage,
+    ChatMessageBuilder, ChatParam, ChatParamBuilder, ChatRole, ContentPart, Conversation,
+    FunctionCall, FunctionChoice, FunctionSpec, FunctionSpecBuilder, ImageDetail, ImageUrl,
+    InputAudio, InputAudioFormat, ListChatParam, ListChatParamBuilder, Modality, Order, Prediction,
+    ReasoningEffort, ResponseFormat, ServiceTier, Stop, StreamOptions, Tool, ToolCall,
+    ToolCallDelta, ToolChoice, ToolType,
 };
 
-pub async fn chat(client: &Client, param: &ChatParam) -> Result<Chat> {
-    client.chat(param).await
+/// Creates a model response for the given chat conversation.
+///
+/// Related OpenAI docs: [Create Chat Completion](https://platform.openai.com/docs/api-reference/chat/create)
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{Client, chat::{create, ChatMessage, ChatParamBuilder}};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let param = ChatParamBuilder::new(
+///         "gpt-3.5-turbo",
+///         vec![
+///             ChatMessage::system("You are a helpful assistant."),
+///             ChatMessage::user("Hello!"),
+///         ],
+///     )
+///     .temperature(0.5)
+///     .build()?;
+///
+///     let resp = create(&client, &param).await?;
+///     println!("{:#?}", resp.choices[0].message.content);
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn create(client: &Client, param: &ChatParam) -> Result<Chat> {
+    client.create_chat(param).await
+}
+
+/// Creates a model response for the given chat conversation, streaming back partial message deltas as they're generated.
+///
+/// The `stream` parameter is implicitly set, each server-sent event is parsed into a [`ChatChunk`].
+/// Use a [`ChatAccumulator`] to fold the chunks back into complete messages.
+/// With [`StreamOptions::include_usage`], a final chunk without choices carries the token usage of the request.
+///
+/// Related OpenAI docs: [Create Chat Completion](https://platform.openai.com/docs/api-reference/chat/create#chat-create-stream)
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{Client, chat::{create_stream, ChatMessage, ChatParamBuilder}};
+/// use futures::StreamExt;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let param = ChatParamBuilder::new("gpt-3.5-turbo", vec![ChatMessage::user("Tell me a story.")])
+///         .build()?;
+///
+///     let mut stream = create_stream(&client, &param).await?;
+///
+///     while let Some(chunk) = stream.next().await {
+///         if let Some(content) = &chunk?.choices[0].delta.content {
+///             print!("{content}");
+///         }
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn create_stream(client: &Client, param: &ChatParam) -> Result<EventStream<ChatChunk>> {
+    client.create_chat_stream(param).await
+}
+
+/// Creates a model response that adheres to the given JSON schema, using Structured Outputs, deserialized into `T`.
+///
+/// The `response_format` of the parameters is replaced by a strict [`ResponseFormat::JsonSchema`] with the given name and schema.
+/// If the model refuses to respond, a [`RefusalError`](crate::Error::RefusalError) is returned.
+///
+/// Related OpenAI docs: [Structured Outputs](https://platform.openai.com/docs/guides/structured-outputs)
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{Client, chat::{create_structured, ChatMessage, ChatParamBuilder}};
+/// use serde::Deserialize;
+/// use serde_json::json;
+///
+/// #[derive(Debug, Deserialize)]
+/// struct Person {
+///     name: String,
+///     age: u32,
+/// }
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let param = ChatParamBuilder::new("gpt-4o", vec![ChatMessage::user("Alice is 30 years old.")])
+///         .build()?;
+///     let schema = json!({
+///         "type": "object",
+///         "properties": {"name": {"type": "string"}, "age": {"type": "integer"}},
+///         "required": ["name", "age"],
+///         "additionalProperties": false
+///     });
+///
+///     let person: Person = create_structured(&client, &param, "person", schema).await?;
+///     println!("{:#?}", person);
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn create_structured<T: DeserializeOwned>(
+    client: &Client,
+    param: &ChatParam,
+    name: impl Into<String>,
+    schema: serde_json::Value,
+) -> Result<T> {
+    client
+        .create_chat_structured(param, name.into(), schema)
+        .await
+}
+
+/// Lists the chat completions stored with the `store` parameter.
+///
+/// Related OpenAI docs: [List Chat Completions](https://platform.openai.com/docs/api-reference/chat/list)
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{Client, chat::{list, ListChatParamBuilder, Order}};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let param = ListChatParamBuilder::default()
+///         .model("gpt-4o")
+///         .order(Order::Desc)
+///         .build()?;
+///
+///     let resp = list(&client, &param).await?;
+///     println!("{:#?}", resp.data);
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn list(client: &Client, param: &ListChatParam) -> Result<ChatList> {
+    client.list_chats(param).await
+}
+
+/// Retrieves a chat completion stored with the `store` parameter.
+///
+/// Related OpenAI docs: [Get Chat Completion](https://platform.openai.com/docs/api-reference/chat/get)
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{Client, chat::retrieve};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let resp = retrieve(&client, "chatcmpl-abc123").await?;
+///     println!("{:#?}", resp);
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn retrieve(client: &Client, completion_id: impl Into<String>) -> Result<Chat> {
+    client.retrieve_chat(completion_id.into()).await
+}
+
+/// Deletes a chat completion stored with the `store` parameter.
+///
+/// Related OpenAI docs: [Delete Chat Completion](https://platform.openai.com/docs/api-reference/chat/delete)
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{Client, chat::delete};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let resp = delete(&client, "chatcmpl-abc123").await?;
+///     println!("{:#?}", resp);
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn delete(client: &Client, completion_id: impl Into<String>) -> Result<Delete> {
+    client.delete_chat(completion_id.into()).await
 }
 
 impl Client {
-    async fn chat(&self, param: &ChatParam) -> Result<Chat> {
-        self.post::<ChatParam, Chat>("chat/completions", Some(param))
+    async fn create_chat(&self, param: &ChatParam) -> Result<Chat> {
+        self.post::<ChatParam, Chat>("chat/completions", Some(&param.for_model_family()))
+            .await
+    }
+
+    async fn create_chat_stream(&self, param: &ChatParam) -> Result<EventStream<ChatChunk>> {
+        let param = ChatParam {
+            stream: true,
+            ..param.for_model_family().into_owned()
+        };
+
+        self.post_event_stream::<ChatParam, ChatChunk>("chat/completions", Some(&param))
+            .await
+    }
+
+    async fn create_chat_structured<T: DeserializeOwned>(
+        &self,
+        param: &ChatParam,
+        name: String,
+        schema: serde_json::Value,
+    ) -> Result<T> {
+        let param = ChatParam {
+            response_format: Some(ResponseFormat::JsonSchema {
+                name,
+                schema,
+                strict: Some(true),
+            }),
+            ..param.clone()
+        };
+
+        self.create_chat(&param).await?.parse_json()
+    }
+
+    async fn list_chats(&self, param: &ListChatParam) -> Result<ChatList> {
+        self.get::<ListChatParam, ChatList>("chat/completions", Some(param))
+            .await
+    }
+
+    async fn retrieve_chat(&self, completion_id: String) -> Result<Chat> {
+        self.get::<(), Chat>(&format!("chat/completions/{completion_id}"), None)
+            .await
+    }
+
+    async fn delete_chat(&self, completion_id: String) -> Result<Delete> {
+        self.delete::<(), Delete>(&format!("chat/completions/{completion_id}"), None)
             .await
     }
 }
@@ -11,14 +11,11 @@
 //!
 //! Showing, not just telling, is often the secret to a good prompt.
 
-use derive_builder::Builder;
+use crate::{client::EventStream, Client, Result};
 
-use serde::{Deserialize, Serialize};
-use serde_with::skip_serializing_none;
-
-use crate::{
-    types::{Completion, CompletionParam},
-    Client, Result,
+pub use crate::types::{
+    Completion, CompletionChunk, CompletionLogprobs, CompletionParam, CompletionParamBuilder,
+    ServiceTier, Stop, StreamOptions,
 };
 
 /// Creates a completion for the provided prompt and parameters.
@@ -93,6 +90,46 @@ pub async fn create_with_stream(
     client.create_completion_with_stream(param).await
 }
 
+/// Creates a completion for the provided prompt and parameters, streaming back the generated tokens as they arrive.
+///
+/// The `stream` parameter is implicitly set, each server-sent event is parsed into a [`CompletionChunk`].
+/// With [`StreamOptions::include_usage`], a final chunk without choices carries the token usage of the request.
+///
+/// Related OpenAI docs: [Create Completions](https://beta.openai.com/docs/api-reference/completions/create#completions/create-stream)
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{Client, completion::{create_stream, CompletionParamBuilder}};
+/// use futures::StreamExt;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let param = CompletionParamBuilder::new("gpt-3.5-turbo-instruct")
+///         .prompt("Generate a poem about Messi and World Cup 2022.")
+///         .build()?;
+///
+///     let mut stream = create_stream(&client, &param).await?;
+///
+///     while let Some(chunk) = stream.next().await {
+///         print!("{}", chunk?.choices[0].text.as_deref().unwrap_or_default());
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+#[deprecated(
+    since = "0.7.0",
+    note = "Please use chat endpoint. More at https://platform.openai.com/docs/guides/text-generation/completions-api"
+)]
+pub async fn create_stream(
+    client: &Client,
+    param: &CompletionParam,
+) -> Result<EventStream<CompletionChunk>> {
+    client.create_completion_stream(param).await
+}
+
 impl Client {
     async fn create_completion(&self, param: &CompletionParam) -> Result<Completion> {
         self.post::<CompletionParam, Completion>("completions", Some(param))
@@ -106,54 +143,16 @@ impl Client {
         self.post_stream("completions", Some(param)).await
     }
 
-    /*
-    fn create_completion_with_stream(
+    async fn create_completion_stream(
         &self,
         param: &CompletionParam,
-    ) -> Pin<
-        Box<
-            dyn Stream<
-                    Item = std::result::Result<Completion, Box<dyn std::error::Error + Send + '_>>,
-                > + Send,
-        >,
-    > {
-        Box::pin(stream! {
-            let mut resp = match self.post_stream("completions", Some(&param)).await {
-                Ok(r) => r,
-                Err(e) => {
-                    yield Err(Box::new(e) as Box<dyn std::error::Error + Send + '_>);
-                    return;
-                }
-            };
+    ) -> Result<EventStream<CompletionChunk>> {
+        let mut param = param.clone();
+        param.stream = true;
 
-            let mut cv = String::new();
-
-            while let Ok(Some(chunk)) = resp.chunk().await {
-                let a = match String::from_utf8(chunk.to_vec()) {
-                    Ok(s) => s,
-                    Err(e) => {
-                        yield Err(Box::new(e) as Box<dyn std::error::Error + Send + '_>);
-                        continue;
-                    }
-                };
-                let whole_val = a.split("data: ").collect::<Vec<_>>();
-
-                for part in whole_val {
-                    match serde_json::from_str::<Completion>(part) {
-                        Ok(v) => yield Ok(v),
-                        Err(_) => {
-                            cv.push_str(part);
-                            if let Ok(v) = serde_json::from_str::<Completion>(&cv) {
-                                cv.clear();
-                                yield Ok(v);
-                            }
-                        }
-                    }
-                }
-            }
-        })
+        self.post_event_stream::<CompletionParam, CompletionChunk>("completions", Some(&param))
+            .await
     }
-    */
 }
 
 #[cfg(test)]
@@ -4,10 +4,9 @@
 //!
 //! This is a natural interface for translating, editing, and tweaking text. This is also useful for refactoring and working with code.
 
-use crate::{
-    types::{Edit, EditParam},
-    Client, Result,
-};
+use crate::{Client, Result};
+
+pub use crate::types::{Edit, EditParam, EditParamBuilder};
 
 /// Creates a new edit for the provided input, instruction, and parameters.
 ///
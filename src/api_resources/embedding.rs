@@ -8,13 +8,19 @@
 //! - Diversity measurement (where similarity distributions are analyzed)
 //! - Classification (where text strings are classified by their most similar label)
 
-use crate::{
-    types::{Embedding, EmbeddingParam},
-    Client, Result,
+use crate::{Client, Error, Result};
+use futures::{StreamExt, TryStreamExt};
+
+pub use crate::types::{
+    CacheFuture, EmbedManyOptions, EmbedManyOptionsBuilder, Embedding, EmbeddingCache,
+    EmbeddingCacheKey, EmbeddingData, EmbeddingInput, EmbeddingParam, EmbeddingParamBuilder,
+    EncodingFormat, InMemoryEmbeddingCache,
 };
 
 /// Creates an embedding vector representing the input text.
 ///
+/// Multiple inputs can be embedded at once, the embeddings of the response are in the same order as the inputs.
+///
 /// Related OpenAI docs: [Create Embeddings](https://beta.openai.com/docs/api-reference/embeddings/create).
 ///
 /// ## Example
@@ -39,12 +45,312 @@ pub async fn create(client: &Client, param: &EmbeddingParam) -> Result<Embedding
     client.create_embeddings(param).await
 }
 
+/// Creates an embedding vector representing the input text, returning the embeddings of previously seen texts from the cache.
+///
+/// Only the texts missing from the cache are requested, their embeddings are then added to the cache.
+/// Inputs made of tokens are never cached. The `usage` of the response only accounts for the requested texts.
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{Client, embedding::{create_cached, EmbeddingParamBuilder, InMemoryEmbeddingCache}};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///     let cache = InMemoryEmbeddingCache::default();
+///
+///     let param = EmbeddingParamBuilder::new("text-embedding-3-small", "Hello world!").build()?;
+///
+///     let resp = create_cached(&client, &param, &cache).await?;
+///     // Returned instantly.
+///     let cached = create_cached(&client, &param, &cache).await?;
+///     assert_eq!(resp.data[0].embedding, cached.data[0].embedding);
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn create_cached<C>(
+    client: &Client,
+    param: &EmbeddingParam,
+    cache: &C,
+) -> Result<Embedding>
+where
+    C: EmbeddingCache + ?Sized,
+{
+    client.create_embeddings_cached(param, cache).await
+}
+
+/// Creates the embedding vectors of any number of inputs, returned in the same order as the inputs.
+///
+/// The inputs are split into batches of [`batch_size`](EmbedManyOptions::batch_size), sent with at most
+/// [`concurrency`](EmbedManyOptions::concurrency) requests at once. Each request is retried per the
+/// [`RetryPolicy`](crate::config::RetryPolicy) of the client, and the whole operation fails along with any batch.
+/// A response without exactly one embedding per input of its batch fails with [`Error::EmbeddingMismatch`](crate::Error::EmbeddingMismatch).
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{Client, embedding::{embed_many, EmbedManyOptionsBuilder}};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let texts = (0..10_000).map(|i| format!("Document number {i}"));
+///     let opts = EmbedManyOptionsBuilder::new("text-embedding-3-small")
+///         .concurrency(8usize)
+///         .build()?;
+///
+///     let embeddings = embed_many(&client, texts, &opts).await?;
+///     println!("{}", embeddings.len());
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn embed_many<I, S>(
+    client: &Client,
+    inputs: I,
+    opts: &EmbedManyOptions,
+) -> Result<Vec<Vec<f32>>>
+where
+    I: IntoIterator<Item = S>,
+    S: Into<String>,
+{
+    client
+        .embed_many(inputs.into_iter().map(Into::into).collect(), opts)
+        .await
+}
+
 impl Client {
     async fn create_embeddings(&self, param: &EmbeddingParam) -> Result<Embedding> {
         self.post::<EmbeddingParam, Embedding>("embeddings", Some(param))
             .await
     }
+
+    async fn create_embeddings_cached<C>(
+        &self,
+        param: &EmbeddingParam,
+        cache: &C,
+    ) -> Result<Embedding>
+    where
+        C: EmbeddingCache + ?Sized,
+    {
+        let Some(texts) = param.texts() else {
+            return self.create_embeddings(param).await;
+        };
+
+        let keys = texts
+            .iter()
+            .map(|text| param.cache_key(text))
+            .collect::<Vec<_>>();
+        let mut embeddings = futures::future::join_all(keys.iter().map(|key| cache.get(key))).await;
+        let missing = (0..texts.len())
+            .filter(|&index| embeddings[index].is_none())
+            .collect::<Vec<_>>();
+
+        let mut resp = Embedding {
+            object: "list".to_string(),
+            model: param.model().to_string(),
+            ..Embedding::default()
+        };
+        if !missing.is_empty() {
+            let param = param.with_texts(
+                missing
+                    .iter()
+                    .map(|&index| texts[index].to_string())
+                    .collect(),
+            );
+            resp = self.create_embeddings(&param).await?;
+
+            let requested = ordered(std::mem::take(&mut resp.data), missing.len())?;
+            let mut inserts = Vec::new();
+            for (&index, embedding) in missing.iter().zip(requested) {
+                inserts.push(cache.insert(keys[index].clone(), embedding.clone()));
+                embeddings[index] = Some(embedding);
+            }
+            futures::future::join_all(inserts).await;
+        }
+
+        // Every input has its embedding by now, either cached or requested.
+        resp.data = embeddings
+            .into_iter()
+            .flatten()
+            .enumerate()
+            .map(|(index, embedding)| EmbeddingData {
+                object: "embedding".to_string(),
+                embedding,
+                index: index as u64,
+            })
+            .collect();
+
+        Ok(resp)
+    }
+
+    async fn embed_many(
+        &self,
+        inputs: Vec<String>,
+        opts: &EmbedManyOptions,
+    ) -> Result<Vec<Vec<f32>>> {
+        let batches = inputs
+            .chunks(opts.batch_size.max(1))
+            .map(|batch| self.embed_batch(batch.to_vec(), opts));
+
+        let embeddings = futures::stream::iter(batches)
+            .buffered(opts.concurrency.max(1))
+            .try_collect::<Vec<_>>()
+            .await?;
+
+        Ok(embeddings.into_iter().flatten().collect())
+    }
+
+    async fn embed_batch(
+        &self,
+        batch: Vec<String>,
+        opts: &EmbedManyOptions,
+    ) -> Result<Vec<Vec<f32>>> {
+        let len = batch.len();
+        let mut param = EmbeddingParamBuilder::new(&opts.model, batch);
+        if let Some(dimensions) = opts.dimensions {
+            param.dimensions(dimensions);
+        }
+        let resp = self.create_embeddings(&param.build()?).await?;
+
+        ordered(resp.data, len)
+    }
+}
+
+// The embeddings of the response in the order of their inputs, failing unless there's exactly one per input.
+fn ordered(data: Vec<EmbeddingData>, len: usize) -> Result<Vec<Vec<f32>>> {
+    if data.len() != len {
+        return Err(Error::EmbeddingMismatch(format!(
+            "Expected {len} embeddings, got {}.",
+            data.len()
+        )));
+    }
+
+    let mut embeddings = vec![None; len];
+    for data in data {
+        match embeddings.get_mut(data.index as usize) {
+            Some(embedding @ None) => *embedding = Some(data.embedding),
+            _ => {
+                return Err(Error::EmbeddingMismatch(format!(
+                    "The index {} is out of bounds or repeated.",
+                    data.index
+                )))
+            }
+        }
+    }
+
+    Ok(embeddings.into_iter().flatten().collect())
 }
 
 #[cfg(test)]
-mod tests {}
+mod tests {
+    use super::*;
+    use crate::{config::RetryPolicy, transport::MockTransport};
+    use reqwest::{Method, StatusCode};
+    use std::{sync::Arc, time::Duration};
+
+    fn embeddings(data: serde_json::Value) -> serde_json::Value {
+        serde_json::json!({"object": "list", "data": data, "model": "text-embedding-3-small", "usage": null})
+    }
+
+    fn error(r#type: &str) -> serde_json::Value {
+        serde_json::json!({"error": {"message": "Failed", "type": r#type, "param": null, "code": null}})
+    }
+
+    #[tokio::test]
+    async fn test_embed_many() {
+        let opts = EmbedManyOptionsBuilder::new("text-embedding-3-small")
+            .build()
+            .unwrap();
+        let retry = RetryPolicy {
+            initial_backoff: Duration::from_millis(1),
+            ..RetryPolicy::default()
+        };
+
+        let mock = Arc::new(
+            MockTransport::new()
+                .respond_with(
+                    Method::POST,
+                    "embeddings",
+                    StatusCode::SERVICE_UNAVAILABLE,
+                    error("server_error"),
+                )
+                .respond(
+                    Method::POST,
+                    "embeddings",
+                    embeddings(serde_json::json!([
+                        {"object": "embedding", "embedding": [2.0], "index": 1},
+                        {"object": "embedding", "embedding": [1.0], "index": 0},
+                    ])),
+                ),
+        );
+        let client = Client::new().retry(retry.clone()).transport(mock.clone());
+        assert_eq!(
+            embed_many(&client, ["foo", "bar"], &opts).await.unwrap(),
+            vec![vec![1.0], vec![2.0]]
+        );
+        assert_eq!(mock.requests().len(), 2);
+
+        let mock = Arc::new(MockTransport::new().respond_with(
+            Method::POST,
+            "embeddings",
+            StatusCode::BAD_REQUEST,
+            error("invalid_request_error"),
+        ));
+        let client = Client::new().retry(retry.clone()).transport(mock.clone());
+        assert!(matches!(
+            embed_many(&client, ["foo"], &opts).await,
+            Err(Error::APIError(_))
+        ));
+        assert_eq!(mock.requests().len(), 1);
+
+        for data in [
+            serde_json::json!([{"object": "embedding", "embedding": [1.0], "index": 0}]),
+            serde_json::json!([
+                {"object": "embedding", "embedding": [1.0], "index": 0},
+                {"object": "embedding", "embedding": [2.0], "index": 0},
+            ]),
+            serde_json::json!([
+                {"object": "embedding", "embedding": [1.0], "index": 0},
+                {"object": "embedding", "embedding": [2.0], "index": 2},
+            ]),
+        ] {
+            let mock = Arc::new(MockTransport::new().respond(
+                Method::POST,
+                "embeddings",
+                embeddings(data),
+            ));
+            let client = Client::new().retry(retry.clone()).transport(mock);
+            assert!(matches!(
+                embed_many(&client, ["foo", "bar"], &opts).await,
+                Err(Error::EmbeddingMismatch(_))
+            ));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_cached_short_response() {
+        let cache = InMemoryEmbeddingCache::default();
+        let param = EmbeddingParamBuilder::new(
+            "text-embedding-3-small",
+            vec!["foo".to_string(), "bar".to_string()],
+        )
+        .build()
+        .unwrap();
+
+        let mock = Arc::new(MockTransport::new().respond(
+            Method::POST,
+            "embeddings",
+            embeddings(
+                serde_json::json!([{"object": "embedding", "embedding": [1.0], "index": 0}]),
+            ),
+        ));
+        let client = Client::new().retry(RetryPolicy::none()).transport(mock);
+        assert!(matches!(
+            create_cached(&client, &param, &cache).await,
+            Err(Error::EmbeddingMismatch(_))
+        ));
+        assert!(cache.is_empty());
+    }
+}
@@ -1,45 +1,63 @@
 //! Files are used to upload documents that can be used with features like [`Fine-tuning`](crate::api_resources::fine_tune).
 
-use reqwest::multipart::{Form, Part};
-use serde::{Deserialize, Serialize};
-use std::{borrow::Cow, fs, path::Path};
+use reqwest::multipart::Form;
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::Path;
 
-use crate::{
-    types::{Delete, File, ListFiles, Purpose},
-    Client, Result,
+use crate::{client::ByteStream, Client, Result};
+
+pub use crate::types::{
+    Delete, File, FileUpload, ListFiles, ListFilesParam, ListFilesParamBuilder, Order, Purpose,
 };
 
 /// Returns a [`list`][ListFiles] of files that belong to the user's organization.
 ///
+/// The files are paginated, the next page starts [`after`](ListFilesParam::after) the [`last_id`](ListFiles::last_id)
+/// of the previous one, as long as it [`has_more`](ListFiles::has_more).
+///
 /// Related OpenAI docs: [List Files](https://beta.openai.com/docs/api-reference/files/list)
 ///
 /// ## Example
 /// ```no_run
-/// use fieri::{Client, file::list};
+/// use fieri::{Client, file::{list, ListFilesParamBuilder, Purpose}};
 ///
 /// #[tokio::main]
 /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
 ///     let client = Client::new()
 ///         .organization("org-..");
 ///
-///     let resp = list(&client).await?;
-///     println!("{:#?}", resp);
+///     let mut param = ListFilesParamBuilder::default()
+///         .purpose(Purpose::FineTune)
+///         .limit(100u32)
+///         .build()?;
+///
+///     loop {
+///         let resp = list(&client, &param).await?;
+///         println!("{:#?}", resp.data);
+///
+///         match resp.last_id {
+///             Some(last_id) if resp.has_more => param.after = Some(last_id),
+///             _ => break,
+///         }
+///     }
 ///
 ///     Ok(())
 /// }
 /// ```
-pub async fn list(client: &Client) -> Result<ListFiles> {
-    client.list_files().await
+pub async fn list(client: &Client, param: &ListFilesParam) -> Result<ListFiles> {
+    client.list_files(param).await
 }
 
 /// Upload a file that contains document(s) to be used across various endpoints/features.
 ///
-/// Related OpenAI docs: [Upload File](https://beta.openai.com/docs/api-reference/files/upload)
+/// The file is uploaded from a path, bytes or a stream, see [`FileUpload`].
+/// Paths and opened files, see [`FileUpload::file`], are streamed from disk as the request is sent, and never fully loaded in memory.
+///
+/// Related OpenAI docs: [Upload File](https://platform.openai.com/docs/api-reference/files/create)
 ///
 /// ## Example
 /// ```no_run
-/// use std::path::Path;
-/// use fieri::{Client, file::{Purpose, upload}};
+/// use fieri::{Client, file::{FileUpload, Purpose, upload}};
 ///
 /// #[tokio::main]
 /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -49,14 +67,23 @@ pub async fn list(client: &Client) -> Result<ListFiles> {
 ///     let resp = upload(&client, "/path/to/file.jsonl", Purpose::FineTune).await?;
 ///     println!("{:#?}", resp);
 ///
+///     let batch = r#"{"custom_id": "1", "method": "POST", "url": "/v1/chat/completions", "body": {}}"#;
+///     let resp = upload(&client, FileUpload::bytes("batch.jsonl", batch), Purpose::Batch).await?;
+///     println!("{:#?}", resp);
+///
+///     let file = tokio::fs::File::open("/path/to/large-training-file.jsonl").await?;
+///     let resp = upload(&client, FileUpload::file("train.jsonl", file).await?, Purpose::FineTune).await?;
+///     println!("{:#?}", resp);
+///
 ///     Ok(())
 /// }
 /// ```
-pub async fn upload<P>(client: &Client, file: P, purpose: Purpose) -> Result<File>
-where
-    P: AsRef<Path> + Into<Cow<'static, str>> + Copy,
-{
-    client.upload_file(file, purpose).await
+pub async fn upload(
+    client: &Client,
+    file: impl Into<FileUpload>,
+    purpose: Purpose,
+) -> Result<File> {
+    client.upload_file(file.into(), purpose).await
 }
 
 /// Delete a file.
@@ -105,19 +132,98 @@ pub async fn retrieve(client: &Client, file_id: impl Into<String>) -> Result<Fil
     client.retrieve_file(file_id.into()).await
 }
 
+/// Returns the contents of a file.
+///
+/// The whole of it is buffered in memory, large files are better streamed with [`content_stream`] or [`download_to`].
+///
+/// Related OpenAI docs: [Retrieve File Content](https://platform.openai.com/docs/api-reference/files/retrieve-contents)
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{Client, file::content};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let resp = content(&client, "file-to-retrieve").await?;
+///     println!("{}", String::from_utf8_lossy(&resp));
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn content(client: &Client, file_id: impl Into<String>) -> Result<bytes::Bytes> {
+    client.retrieve_file_content(file_id.into()).await
+}
+
+/// Streams back the contents of a file as they arrive, instead of buffering the whole of it in memory.
+///
+/// Related OpenAI docs: [Retrieve File Content](https://platform.openai.com/docs/api-reference/files/retrieve-contents)
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{Client, file::content_stream};
+/// use futures::StreamExt;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let mut stream = content_stream(&client, "file-to-retrieve").await?;
+///     let mut total = 0;
+///
+///     while let Some(chunk) = stream.next().await {
+///         total += chunk?.len();
+///     }
+///     println!("{total} bytes");
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn content_stream(client: &Client, file_id: impl Into<String>) -> Result<ByteStream> {
+    client.retrieve_file_content_stream(file_id.into()).await
+}
+
+/// Downloads the contents of a file to the given path, chunk by chunk, returning the number of bytes written.
+///
+/// The file at the path is created, or truncated if it exists.
+///
+/// Related OpenAI docs: [Retrieve File Content](https://platform.openai.com/docs/api-reference/files/retrieve-contents)
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{Client, file::download_to};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let written = download_to(&client, "file-batch-output", "batch_output.jsonl").await?;
+///     println!("{written} bytes written");
+///
+///     Ok(())
+/// }
+/// ```
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn download_to(
+    client: &Client,
+    file_id: impl Into<String>,
+    path: impl AsRef<Path>,
+) -> Result<u64> {
+    client
+        .download_file_content(file_id.into(), path.as_ref())
+        .await
+}
+
 impl Client {
-    async fn list_files(&self) -> Result<ListFiles> {
-        self.get::<(), ListFiles>("files", None).await
+    async fn list_files(&self, param: &ListFilesParam) -> Result<ListFiles> {
+        self.get::<ListFilesParam, ListFiles>("files", Some(param))
+            .await
     }
 
-    async fn upload_file<P>(&self, file: P, purpose: Purpose) -> Result<File>
-    where
-        P: AsRef<Path> + Into<Cow<'static, str>> + Copy,
-    {
-        let data = fs::read(file.as_ref())?;
-        let part = Part::bytes(data).file_name(file);
+    async fn upload_file(&self, file: FileUpload, purpose: Purpose) -> Result<File> {
         let form = Form::new()
-            .part("file", part)
+            .part("file", file.into_part()?)
             .text("purpose", purpose.to_string());
 
         self.post_data::<File>("files", form).await
@@ -132,6 +238,35 @@ impl Client {
         self.get::<(), File>(&format!("files/{file_id}"), None)
             .await
     }
+
+    async fn retrieve_file_content(&self, file_id: String) -> Result<bytes::Bytes> {
+        self.get_bytes::<()>(&format!("files/{file_id}/content"), None)
+            .await
+    }
+
+    async fn retrieve_file_content_stream(&self, file_id: String) -> Result<ByteStream> {
+        self.get_byte_stream::<()>(&format!("files/{file_id}/content"), None)
+            .await
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    async fn download_file_content(&self, file_id: String, path: &Path) -> Result<u64> {
+        use futures::TryStreamExt;
+        use tokio::io::AsyncWriteExt;
+
+        // The request is sent first, not to create the file when the download fails.
+        let mut stream = self.retrieve_file_content_stream(file_id).await?;
+        let mut file = tokio::fs::File::create(path).await?;
+
+        let mut written = 0;
+        while let Some(chunk) = stream.try_next().await? {
+            file.write_all(&chunk).await?;
+            written += chunk.len() as u64;
+        }
+        file.flush().await?;
+
+        Ok(written)
+    }
 }
 
 #[cfg(test)]
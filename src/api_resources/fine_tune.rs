@@ -14,15 +14,16 @@
 //! letting you achieve better results on a wide number of tasks.
 //! Once a model has been fine-tuned, you won't need to provide examples in the prompt anymore.
 //! This saves costs and enables lower-latency requests.
+//!
+//! The legacy fine-tunes routes are deprecated in favor of the fine-tuning jobs of the [`fine_tuning`](crate::fine_tuning) module.
 
-use derive_builder::Builder;
-use serde::{Deserialize, Serialize};
 use serde_json::json;
-use serde_with::skip_serializing_none;
 
-use crate::{
-    types::{CreateFineTuneParam, Delete, FineTune, ListEvents, ListFineTune},
-    Client, Result,
+use crate::{Client, Result};
+
+pub use crate::types::{
+    CreateFineTuneParam, CreateFineTuneParamBuilder, Delete, Event, FineTune, HyperParams,
+    ListEvents, ListFineTune,
 };
 
 /// Creates a job that fine-tunes a specified model from a given dataset.
@@ -56,6 +57,7 @@ use crate::{
 ///     Ok(())
 /// }
 /// ```
+#[deprecated(since = "0.7.0", note = "Please use `fine_tuning::create`.")]
 pub async fn create(client: &Client, param: &CreateFineTuneParam) -> Result<FineTune> {
     client.create_fine_tune(param).await
 }
@@ -78,6 +80,7 @@ pub async fn create(client: &Client, param: &CreateFineTuneParam) -> Result<Fine
 ///     Ok(())
 /// }
 /// ```
+#[deprecated(since = "0.7.0", note = "Please use `fine_tuning::list`.")]
 pub async fn list(client: &Client) -> Result<ListFineTune> {
     client.list_fine_tune().await
 }
@@ -99,6 +102,7 @@ pub async fn list(client: &Client) -> Result<ListFineTune> {
 ///
 ///     Ok(())
 /// }
+#[deprecated(since = "0.7.0", note = "Please use `fine_tuning::retrieve`.")]
 pub async fn retrieve(client: &Client, fine_tune_id: impl Into<String>) -> Result<FineTune> {
     client.retrieve_fine_tune(fine_tune_id.into()).await
 }
@@ -121,6 +125,7 @@ pub async fn retrieve(client: &Client, fine_tune_id: impl Into<String>) -> Resul
 ///     Ok(())
 /// }
 /// ```
+#[deprecated(since = "0.7.0", note = "Please use `fine_tuning::cancel`.")]
 pub async fn cancel(client: &Client, fine_tune_id: impl Into<String>) -> Result<FineTune> {
     client.cancel_fine_tune(fine_tune_id.into()).await
 }
@@ -143,6 +148,7 @@ pub async fn cancel(client: &Client, fine_tune_id: impl Into<String>) -> Result<
 ///     Ok(())
 /// }
 /// ```
+#[deprecated(since = "0.7.0", note = "Please use `fine_tuning::list_events`.")]
 pub async fn list_events(client: &Client, fine_tune_id: impl Into<String>) -> Result<ListEvents> {
     client.list_fine_tune_events(fine_tune_id.into()).await
 }
@@ -167,6 +173,7 @@ pub async fn list_events(client: &Client, fine_tune_id: impl Into<String>) -> Re
 ///
 ///     Ok(())
 /// }
+#[deprecated(since = "0.7.0", note = "Please use `fine_tuning::follow`.")]
 pub async fn list_events_with_stream(
     client: &Client,
     fine_tune_id: impl Into<String>,
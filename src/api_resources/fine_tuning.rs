@@ -0,0 +1,284 @@
+//! Manage fine-tuning jobs to tailor a model to your specific training data.
+//!
+//! The jobs train a model on a training file uploaded with the [`Fine-tune`](crate::file::Purpose::FineTune) purpose,
+//! producing a [`fine-tuned model`](FineTuningJob::fine_tuned_model) once it succeeded.
+//!
+//! These are the successors of the deprecated [`fine_tune`](crate::fine_tune) routes.
+
+use std::time::Duration;
+
+use async_stream::try_stream;
+
+use crate::{client::EventStream, Client, Result};
+
+pub use crate::types::{
+    CreateFineTuningJobParam, CreateFineTuningJobParamBuilder, DpoHyperparameters, DpoMethod,
+    FineTuningHyperparameters, FineTuningJob, FineTuningJobError, FineTuningJobEvent,
+    FineTuningJobStatus, FineTuningMethod, Hyperparameter, ListFineTuningJobEvents,
+    ListFineTuningJobEventsParam, ListFineTuningJobEventsParamBuilder, ListFineTuningJobs,
+    ListFineTuningJobsParam, ListFineTuningJobsParamBuilder, SupervisedMethod,
+};
+
+/// Creates a fine-tuning job, which begins the process of creating a new model from a given dataset.
+///
+/// Related OpenAI docs: [Create Fine-tuning Job](https://platform.openai.com/docs/api-reference/fine-tuning/create)
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{Client, fine_tuning::{create, CreateFineTuningJobParamBuilder, FineTuningHyperparameters, FineTuningMethod}};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let param = CreateFineTuningJobParamBuilder::new("gpt-4o-mini-2024-07-18", "file-abc123")
+///         .suffix("support")
+///         .method(FineTuningMethod::supervised(FineTuningHyperparameters {
+///             n_epochs: Some(3.into()),
+///             ..Default::default()
+///         }))
+///         .build()?;
+///
+///     let resp = create(&client, &param).await?;
+///     println!("{:#?}", resp);
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn create(client: &Client, param: &CreateFineTuningJobParam) -> Result<FineTuningJob> {
+    client.create_fine_tuning_job(param).await
+}
+
+/// Lists your organization's fine-tuning jobs.
+///
+/// Related OpenAI docs: [List Fine-tuning Jobs](https://platform.openai.com/docs/api-reference/fine-tuning/list)
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{Client, fine_tuning::{list, ListFineTuningJobsParamBuilder}};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let param = ListFineTuningJobsParamBuilder::default()
+///         .limit(10u32)
+///         .build()?;
+///
+///     let resp = list(&client, &param).await?;
+///     println!("{:#?}", resp.data);
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn list(client: &Client, param: &ListFineTuningJobsParam) -> Result<ListFineTuningJobs> {
+    client.list_fine_tuning_jobs(param).await
+}
+
+/// Retrieves a fine-tuning job, along with its status.
+///
+/// Related OpenAI docs: [Retrieve Fine-tuning Job](https://platform.openai.com/docs/api-reference/fine-tuning/retrieve)
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{Client, fine_tuning::retrieve};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let resp = retrieve(&client, "ftjob-abc123").await?;
+///     println!("{:?} {:?}", resp.status, resp.fine_tuned_model);
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn retrieve(client: &Client, job_id: impl Into<String>) -> Result<FineTuningJob> {
+    client.retrieve_fine_tuning_job(job_id.into()).await
+}
+
+/// Immediately cancels a fine-tuning job.
+///
+/// Related OpenAI docs: [Cancel Fine-tuning Job](https://platform.openai.com/docs/api-reference/fine-tuning/cancel)
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{Client, fine_tuning::cancel};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let resp = cancel(&client, "ftjob-abc123").await?;
+///     println!("{:#?}", resp);
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn cancel(client: &Client, job_id: impl Into<String>) -> Result<FineTuningJob> {
+    client.cancel_fine_tuning_job(job_id.into()).await
+}
+
+/// Lists the status updates of a fine-tuning job, from the newest to the oldest.
+///
+/// The events are paginated, the next page starts [`after`](ListFineTuningJobEventsParam::after) the last event of the previous one,
+/// as long as it [`has_more`](ListFineTuningJobEvents::has_more).
+///
+/// Related OpenAI docs: [List Fine-tuning Events](https://platform.openai.com/docs/api-reference/fine-tuning/list-events)
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{Client, fine_tuning::{list_events, ListFineTuningJobEventsParamBuilder}};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let param = ListFineTuningJobEventsParamBuilder::default()
+///         .limit(50u32)
+///         .build()?;
+///
+///     let resp = list_events(&client, "ftjob-abc123", &param).await?;
+///     for event in resp.data {
+///         println!("{}", event.message);
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn list_events(
+    client: &Client,
+    job_id: impl Into<String>,
+    param: &ListFineTuningJobEventsParam,
+) -> Result<ListFineTuningJobEvents> {
+    client
+        .list_fine_tuning_job_events(job_id.into(), param)
+        .await
+}
+
+/// Follows a fine-tuning job, streaming its new events in chronological order until the job reaches a [terminal](FineTuningJobStatus::is_terminal) status.
+///
+/// The job and its events are polled at the given interval, the events logged before the call are streamed first.
+///
+/// ## Example
+/// ```no_run
+/// use std::time::Duration;
+/// use fieri::{Client, fine_tuning::follow};
+/// use futures::StreamExt;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let mut events = follow(&client, "ftjob-abc123", Duration::from_secs(10));
+///
+///     while let Some(event) = events.next().await {
+///         println!("{}", event?.message);
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+pub fn follow(
+    client: &Client,
+    job_id: impl Into<String>,
+    interval: Duration,
+) -> EventStream<FineTuningJobEvent> {
+    client.follow_fine_tuning_job(job_id.into(), interval)
+}
+
+impl Client {
+    async fn create_fine_tuning_job(
+        &self,
+        param: &CreateFineTuningJobParam,
+    ) -> Result<FineTuningJob> {
+        self.post::<CreateFineTuningJobParam, FineTuningJob>("fine_tuning/jobs", Some(param))
+            .await
+    }
+
+    async fn list_fine_tuning_jobs(
+        &self,
+        param: &ListFineTuningJobsParam,
+    ) -> Result<ListFineTuningJobs> {
+        self.get::<ListFineTuningJobsParam, ListFineTuningJobs>("fine_tuning/jobs", Some(param))
+            .await
+    }
+
+    async fn retrieve_fine_tuning_job(&self, job_id: String) -> Result<FineTuningJob> {
+        self.get::<(), FineTuningJob>(&format!("fine_tuning/jobs/{job_id}"), None)
+            .await
+    }
+
+    async fn cancel_fine_tuning_job(&self, job_id: String) -> Result<FineTuningJob> {
+        self.post::<(), FineTuningJob>(&format!("fine_tuning/jobs/{job_id}/cancel"), None)
+            .await
+    }
+
+    async fn list_fine_tuning_job_events(
+        &self,
+        job_id: String,
+        param: &ListFineTuningJobEventsParam,
+    ) -> Result<ListFineTuningJobEvents> {
+        self.get::<ListFineTuningJobEventsParam, ListFineTuningJobEvents>(
+            &format!("fine_tuning/jobs/{job_id}/events"),
+            Some(param),
+        )
+        .await
+    }
+
+    fn follow_fine_tuning_job(
+        &self,
+        job_id: String,
+        interval: Duration,
+    ) -> EventStream<FineTuningJobEvent> {
+        let client = self.clone();
+
+        Box::pin(try_stream! {
+            let mut latest: Option<String> = None;
+
+            loop {
+                // The job is retrieved first, for its events to be complete once it's terminal.
+                let job = client.retrieve_fine_tuning_job(job_id.clone()).await?;
+
+                // Pages go from the newest event to the oldest, until the latest event already streamed.
+                let mut events = Vec::new();
+                let mut param = ListFineTuningJobEventsParam {
+                    limit: Some(100),
+                    ..Default::default()
+                };
+                'pages: loop {
+                    let page = client
+                        .list_fine_tuning_job_events(job_id.clone(), &param)
+                        .await?;
+
+                    for event in page.data {
+                        if latest.as_ref() == Some(&event.id) {
+                            break 'pages;
+                        }
+                        events.push(event);
+                    }
+
+                    match events.last() {
+                        Some(event) if page.has_more => param.after = Some(event.id.clone()),
+                        _ => break,
+                    }
+                }
+
+                if let Some(event) = events.first() {
+                    latest = Some(event.id.clone());
+                }
+                for event in events.into_iter().rev() {
+                    yield event;
+                }
+
+                if job.status.is_terminal() {
+                    break;
+                }
+                crate::utils::sleep(interval).await;
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {}
@@ -5,26 +5,23 @@
 //! - Creating edits of an existing image based on a new text prompt
 //! - Creating variations of an existing image
 
-use derive_builder::Builder;
-use reqwest::{
-    get,
-    multipart::{Form, Part},
-};
-use serde::{Deserialize, Serialize};
-use serde_with::skip_serializing_none;
-use std::{
-    borrow::Cow,
-    fs,
-    io::{copy, Cursor},
-    path::Path,
-    str::FromStr,
-};
+use futures::TryStreamExt;
+use reqwest::multipart::Form;
+use std::{borrow::Cow, path::Path};
+
+use crate::{client::EventStream, Client, Result};
 
-use crate::{
-    types::{EditImageParam, GenerateImageParam, Image, VariateImageParam},
-    Client, Result,
+pub use crate::types::{
+    EditImageParam, EditImageParamBuilder, FileUpload, GenerateImageParam,
+    GenerateImageParamBuilder, Image, ImageBackground, ImageData, ImageFrame,
+    ImageInputTokensDetails, ImageModeration, ImageOutputFormat, ImageParamError, ImageQuality,
+    ImageResponseFormat, ImageSize, ImageStreamEvent, ImageStyle, ImageUsage, UploadBody,
+    VariateImageParam, VariateImageParamBuilder,
 };
 
+#[allow(deprecated)]
+pub use crate::types::Link;
+
 /// The image generations endpoint allows you to create an original image given a text prompt. Generated images can have a size of `256x256`, `512x512`, or `1024x1024` pixels.
 ///
 /// Smaller sizes are faster to generate.
@@ -54,39 +51,102 @@ pub async fn generate(client: &Client, param: &GenerateImageParam) -> Result<Ima
     client.generate_image(param).await
 }
 
+/// Generates an image given a prompt, streaming back progressively refined frames as they are rendered.
+///
+/// The `stream` parameter is implicitly set, up to [`partial_images`](GenerateImageParamBuilder::partial_images) partial frames
+/// are sent before the [`Completed`](ImageStreamEvent::Completed) image. Only supported by `gpt-image-1`.
+/// Events unknown to this version of the crate are skipped.
+///
+/// Related OpenAI docs: [Image Generation Streaming](https://platform.openai.com/docs/api-reference/images-streaming)
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{Client, image::{generate_stream, GenerateImageParamBuilder, ImageStreamEvent}};
+/// use futures::StreamExt;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let param = GenerateImageParamBuilder::new("Dogs playing poker.")
+///         .model("gpt-image-1")
+///         .partial_images(2)
+///         .build()?;
+///
+///     let mut stream = generate_stream(&client, &param).await?;
+///
+///     while let Some(event) = stream.next().await {
+///         match event? {
+///             ImageStreamEvent::PartialImage { partial_image_index, image } => {
+///                 std::fs::write(format!("partial_{partial_image_index}.png"), image.bytes()?)?;
+///             }
+///             ImageStreamEvent::Completed { image, .. } => {
+///                 std::fs::write("final.png", image.bytes()?)?;
+///             }
+///             _ => {}
+///         }
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn generate_stream(
+    client: &Client,
+    param: &GenerateImageParam,
+) -> Result<EventStream<ImageStreamEvent>> {
+    client.generate_image_stream(param).await
+}
+
 /// Creates an edited or extended image given an original image and a prompt.
 ///
+/// The image, and the optional [`mask`](EditImageParam::mask), are uploaded from a path, bytes or a stream, see [`FileUpload`].
+/// The image must be a valid square PNG file, less than 4MB.
+///
+/// Related OpenAI docs: [Create Image Edit](https://platform.openai.com/docs/api-reference/images/createEdit)
+///
 /// ## Example
 /// ```no_run
-/// use fieri::{Client, image::{ImageSize, EditImageParamBuilder, edit}};
+/// use fieri::{Client, image::{ImageSize, EditImageParamBuilder, FileUpload, edit}};
 ///
 /// #[tokio::main]
 /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
 ///     let client = Client::new();
 ///
 ///     let param = EditImageParamBuilder::new("Transform the image to a dog playing poker.")
+///         .mask("path-to-mask.png")
 ///         .size(ImageSize::S256x256)
 ///         .n(1)
 ///         .build()?;
 ///
-///     let resp = edit(&client, "path-to-image", &param).await?;
+///     let resp = edit(&client, "path-to-image.png", &param).await?;
+///     println!("{:#?}", resp);
+///
+///     // Or from bytes, like an image that was just downloaded.
+///     let image = std::fs::read("path-to-image.png")?;
+///     let resp = edit(&client, FileUpload::bytes("image.png", image), &param).await?;
 ///     println!("{:#?}", resp);
 ///
 ///     Ok(())
 /// }
 /// ```
-pub async fn edit<P>(client: &Client, image: P, param: &EditImageParam) -> Result<Image>
-where
-    P: AsRef<Path> + Into<Cow<'static, str>> + Copy,
-{
-    client.edit_image(image, param).await
+pub async fn edit(
+    client: &Client,
+    image: impl Into<FileUpload>,
+    param: &EditImageParam,
+) -> Result<Image> {
+    client.edit_image(image.into(), param).await
 }
 
 /// Creates a variation of a given image.
 ///
+/// The image is uploaded from a path, bytes, a reader or a stream, see [`FileUpload`].
+/// It must be a valid square PNG file, less than 4MB.
+///
+/// Related OpenAI docs: [Create Image Variation](https://platform.openai.com/docs/api-reference/images/createVariation)
+///
 /// ## Example
 /// ```no_run
-/// use fieri::{Client, image::{ImageSize, VariateImageParamBuilder, variate}};
+/// use fieri::{Client, image::{ImageSize, FileUpload, VariateImageParamBuilder, variation}};
 ///
 /// #[tokio::main]
 /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -94,19 +154,41 @@ where
 ///
 ///     let param = VariateImageParamBuilder::new()
 ///         .size(ImageSize::S256x256)
+///         .n(2)
 ///         .build()?;
 ///
-///     let resp = variate(&client, "path-to-image", &param).await?;
+///     let resp = variation(&client, "path-to-image.png", &param).await?;
+///     println!("{:#?}", resp);
+///
+///     let file = std::fs::File::open("path-to-image.png")?;
+///     let resp = variation(&client, FileUpload::reader("image.png", file)?, &param).await?;
+///     println!("{:#?}", resp);
+///
+///     // Or streamed, without reading the whole file in memory first.
+///     let file = tokio::fs::File::open("path-to-image.png").await?;
+///     let resp = variation(&client, FileUpload::async_reader("image.png", file), &param).await?;
 ///     println!("{:#?}", resp);
 ///
 ///     Ok(())
 /// }
 /// ```
+pub async fn variation(
+    client: &Client,
+    image: impl Into<FileUpload>,
+    param: &VariateImageParam,
+) -> Result<Image> {
+    client.variate_image(image.into(), param).await
+}
+
+/// Creates a variation of a given image.
+#[deprecated(since = "0.7.0", note = "Please use `variation`.")]
 pub async fn variate<P>(client: &Client, image: P, param: &VariateImageParam) -> Result<Image>
 where
     P: AsRef<Path> + Into<Cow<'static, str>> + Copy,
 {
-    client.variate_image(image, param).await
+    client
+        .variate_image(FileUpload::from(image.as_ref()), param)
+        .await
 }
 
 impl Client {
@@ -115,39 +197,72 @@ impl Client {
             .await
     }
 
-    async fn edit_image<P>(&self, image: P, param: &EditImageParam) -> Result<Image>
-    where
-        P: AsRef<Path> + Into<Cow<'static, str>> + Copy,
-    {
-        let data = fs::read(image)?;
-        let part = Part::bytes(data).file_name(image);
-        let form = Form::new()
-            .part("image", part)
-            .text("prompt", "22")
+    async fn generate_image_stream(
+        &self,
+        param: &GenerateImageParam,
+    ) -> Result<EventStream<ImageStreamEvent>> {
+        let mut param = param.clone();
+        param.stream = true;
+
+        let stream = self
+            .post_event_stream::<GenerateImageParam, ImageStreamEvent>(
+                "images/generations",
+                Some(&param),
+            )
+            .await?;
+
+        Ok(Box::pin(stream.try_filter(|event| {
+            futures::future::ready(!matches!(event, ImageStreamEvent::Other))
+        })))
+    }
+
+    async fn edit_image(&self, image: FileUpload, param: &EditImageParam) -> Result<Image> {
+        // The API defaults to `dall-e-2` when the request doesn't name the model.
+        let model = param.model.as_deref().unwrap_or("dall-e-2");
+        self.limit_form(model, &param.prompt).await?;
+        let mut form = Form::new()
+            .part("image", image.into_part()?)
+            .text("prompt", param.prompt.clone())
             .text("n", param.n.to_string())
-            .text("size", param.size.to_string())
-            .text("user", param.user.to_string());
+            .text("size", param.size.to_string());
+
+        if let Some(model) = &param.model {
+            form = form.text("model", model.clone());
+        }
+        if let Some(mask) = &param.mask {
+            form = form.part("mask", mask.clone().into_part()?);
+        }
+        if let Some(format) = param.response_format {
+            form = form.text("response_format", format.to_string());
+        }
+        if !param.user.is_empty() {
+            form = form.text("user", param.user.clone());
+        }
 
         self.post_data::<Image>("images/edits", form).await
     }
 
-    async fn variate_image<P>(&self, image: P, param: &VariateImageParam) -> Result<Image>
-    where
-        P: AsRef<Path> + Into<Cow<'static, str>> + Copy,
-    {
-        let data = fs::read(image)?;
-        let part = Part::bytes(data).file_name(image);
-        let form = Form::new()
-            .part("image", part)
+    async fn variate_image(&self, image: FileUpload, param: &VariateImageParam) -> Result<Image> {
+        self.limit_form(param.model.as_deref().unwrap_or("dall-e-2"), &())
+            .await?;
+        let mut form = Form::new()
+            .part("image", image.into_part()?)
             .text("n", param.n.to_string())
-            .text("size", param.size.to_string())
-            .text("user", param.user.to_string());
+            .text("size", param.size.to_string());
+
+        if let Some(model) = &param.model {
+            form = form.text("model", model.clone());
+        }
+        if let Some(format) = param.response_format {
+            form = form.text("response_format", format.to_string());
+        }
+        if !param.user.is_empty() {
+            form = form.text("user", param.user.clone());
+        }
 
         self.post_data::<Image>("images/variations", form).await
     }
 }
 
 #[cfg(test)]
-mod tests {
-    use super::*;
-}
+mod tests {}
@@ -1,15 +1,15 @@
 //! List and describe the various models available in the API.
+//!
+//! The models available to the organization, including its fine-tuned ones, can be discovered at runtime,
+//! instead of hardcoding their names.
 
-use serde::{Deserialize, Serialize};
+use crate::{Client, Result};
 
-use crate::{
-    types::{Model, Models},
-    Client, Result,
-};
+pub use crate::types::{Model, ModelName, ModelOwner, Models, Permissions};
 
 /// Retrieves a model instance, providing basic information about the model such as the owner and permissioning.
 ///
-/// Related OpenAI docs: [Retrieve a Model](https://beta.openai.com/docs/api-reference/models/retrieve)
+/// Related OpenAI docs: [Retrieve a Model](https://platform.openai.com/docs/api-reference/models/retrieve)
 ///
 /// ## Example:
 /// ```no_run
@@ -18,8 +18,8 @@ use crate::{
 /// #[tokio::main]
 /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
 ///     let client = Client::new();
-///     let resp = retrieve(&client, "text-babbage-001").await?;
-///     println!("{:#?}", resp);
+///     let resp = retrieve(&client, "gpt-4o-mini").await?;
+///     println!("{:?} since {:?}", resp.owned_by, resp.created_at());
 ///
 ///     Ok(())
 /// }
@@ -30,7 +30,7 @@ pub async fn retrieve(client: &Client, model: impl Into<String>) -> Result<Model
 
 /// Lists the currently available models, and provides basic information about each one.
 ///
-/// Related OpenAI docs: [List Models](https://beta.openai.com/docs/api-reference/models/list)
+/// Related OpenAI docs: [List Models](https://platform.openai.com/docs/api-reference/models/list)
 ///
 /// ## Example
 /// ```no_run
@@ -40,7 +40,9 @@ pub async fn retrieve(client: &Client, model: impl Into<String>) -> Result<Model
 /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
 ///     let client = Client::new();
 ///     let resp = list(&client).await?;
-///     println!("{:#?}", resp);
+///     for model in resp.data.iter().filter(|model| model.is_fine_tuned()) {
+///         println!("{}", model.id);
+///     }
 ///
 ///     Ok(())
 /// }
@@ -61,7 +63,4 @@ impl Client {
 }
 
 #[cfg(test)]
-mod tests {
-    use super::*;
-
-}
+mod tests {}
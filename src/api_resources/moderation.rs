@@ -1,21 +1,30 @@
 //! Given a input text, outputs if the model classifies it as violating OpenAI's content policy.
 //!
 //! The models classifies the following categories:
+//! - Harassment - Content that expresses, incites, or promotes harassing language towards any target.
+//! - Harassment/threatening - Harassment content that also includes violence or serious harm towards any target.
 //! - Hate - Content that expresses, incites, or promotes hate based on race, gender, ethnicity, religion, nationality, sexual orientation, disability status, or caste.
 //! - Hate/Threatening - Hateful content that also includes violence or serious harm towards the targeted group.
+//! - Illicit - Content that gives advice or instruction on how to commit illicit acts, only classified by the omni models.
+//! - Illicit/violent - Illicit content that also includes references to violence or procuring a weapon, only classified by the omni models.
 //! - Self-harm - Content that promotes, encourages, or depicts acts of self-harm, such as suicide, cutting, and eating disorders.
+//! - Self-harm/intent - Content where the speaker expresses that they are engaging or intend to engage in acts of self-harm.
+//! - Self-harm/instructions - Content that encourages performing acts of self-harm, or gives instructions on how to commit them.
 //! - Sexual - Content meant to arouse sexual excitement, such as the description of sexual activity, or that promotes sexual services (excluding sex education and wellness).
 //! - Sexual/minors - Sexual content that includes an individual who is under 18 years old.
 //! - Violence - Content that promotes or glorifies violence or celebrates the suffering or humiliation of others.
 //! - Violence/graphic - Violent content that depicts death, violence, or serious physical injury in extreme graphic detail.
+//!
+//! Categories added after this version of the crate are kept in the `extra` maps of [`Categories`] and [`CategoryScores`].
+
+use futures::{StreamExt, TryStreamExt};
 
-use derive_builder::Builder;
-use serde::{Deserialize, Serialize};
-use serde_with::skip_serializing_none;
+use crate::{Client, Result};
 
-use crate::{
-    types::{Moderation, ModerationParam},
-    Client, Result,
+pub use crate::types::{
+    Categories, CategoryRule, CategoryScores, ContentPart, Moderation, ModerationInput,
+    ModerationInputType, ModerationParam, ModerationParamBuilder, ModerationPolicy,
+    ModerationResult, ModerationVerdict, PolicyViolation,
 };
 
 /// Classifies if text violates OpenAI's Content Policy.
@@ -40,18 +49,103 @@ use crate::{
 ///     Ok(())
 /// }
 /// ```
+///
+/// ## Multiple inputs
+/// ```no_run
+/// use fieri::{Client, moderation::{ModerationParamBuilder, create}};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let comments = vec!["What a lovely day!", "I want to kill them."];
+///     let param = ModerationParamBuilder::new(comments.clone())
+///         .model("omni-moderation-latest")
+///         .build()?;
+///
+///     let resp = create(&client, &param).await?;
+///     for (index, _) in resp.flagged_results() {
+///         println!("flagged: {}", comments[index]);
+///     }
+///
+///     Ok(())
+/// }
+/// ```
 pub async fn create(client: &Client, param: &ModerationParam) -> Result<Moderation> {
     client.create_moderation(param).await
 }
 
+/// The number of texts sent per request by [`screen_many`].
+pub const SCREEN_BATCH_SIZE: usize = 32;
+
+/// Screens many texts against the policy, returning a verdict per text, in the order of the texts.
+///
+/// The texts are sent in requests of [`SCREEN_BATCH_SIZE`] texts, up to `concurrency` of them running at once,
+/// classified by the default moderation model.
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{Client, moderation::{screen_many, ModerationPolicy}};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let comments = vec!["What a lovely day!"; 1000];
+///     let policy = ModerationPolicy::new().threshold("violence", 0.7);
+///
+///     let verdicts = screen_many(&client, comments, &policy, 4).await?;
+///     println!("{} denied", verdicts.iter().filter(|verdict| !verdict.allowed).count());
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn screen_many<I, S>(
+    client: &Client,
+    texts: I,
+    policy: &ModerationPolicy,
+    concurrency: usize,
+) -> Result<Vec<ModerationVerdict>>
+where
+    I: IntoIterator<Item = S>,
+    S: Into<String>,
+{
+    client
+        .screen_many(
+            texts.into_iter().map(Into::into).collect(),
+            policy,
+            concurrency,
+        )
+        .await
+}
+
 impl Client {
     async fn create_moderation(&self, param: &ModerationParam) -> Result<Moderation> {
         self.post::<ModerationParam, Moderation>("moderations", Some(param))
             .await
     }
+
+    async fn screen_many(
+        &self,
+        texts: Vec<String>,
+        policy: &ModerationPolicy,
+        concurrency: usize,
+    ) -> Result<Vec<ModerationVerdict>> {
+        let batches = texts.chunks(SCREEN_BATCH_SIZE).map(|batch| async move {
+            let param = ModerationParamBuilder::new(batch.to_vec()).build()?;
+            let resp = self.create_moderation(&param).await?;
+
+            Ok::<_, crate::Error>(resp.evaluate(policy))
+        });
+
+        let verdicts = futures::stream::iter(batches)
+            .buffered(concurrency.max(1))
+            .try_collect::<Vec<_>>()
+            .await?;
+
+        Ok(verdicts.into_iter().flatten().collect())
+    }
 }
 
 #[cfg(test)]
-mod tests {
-    use super::*;
-}
+mod tests {}
@@ -0,0 +1,199 @@
+//! Hold low-latency conversations, in text and audio, with the realtime models over a WebSocket.
+//!
+//! A session is driven by [`ClientEvent`]s sent to it, like [`session.update`](ClientEvent::SessionUpdate)
+//! or [`input_audio_buffer.append`](ClientEvent::InputAudioBufferAppend), while the model answers with [`ServerEvent`]s.
+//!
+//! Connecting requires the `realtime` feature, while the sessions of browsers and mobile clients, which must not hold the API key,
+//! are created server-side with [`create_session`] and connected to with its ephemeral [`ClientSecret`].
+
+#[cfg(feature = "realtime")]
+use futures::{Sink, SinkExt, StreamExt};
+#[cfg(feature = "realtime")]
+use std::pin::Pin;
+#[cfg(feature = "realtime")]
+use tokio_tungstenite::tungstenite::Message;
+
+#[cfg(feature = "realtime")]
+use crate::{client::EventStream, error::Error};
+use crate::{Client, Result};
+
+pub use crate::types::{
+    ClientEvent, ClientSecret, InputAudioTranscription, NoiseReduction, RealtimeAudioFormat,
+    RealtimeModality, RealtimeResponseConfig, RealtimeSession, RealtimeSessionConfig,
+    RealtimeTranscriptionSession, RealtimeTranscriptionSessionConfig, ServerEvent, TurnDetection,
+    Voice,
+};
+
+/// The sending half of a realtime session.
+#[cfg(feature = "realtime")]
+pub type RealtimeSink = Pin<Box<dyn Sink<ClientEvent, Error = Error> + Send>>;
+
+/// The receiving half of a realtime session, ending once the session is closed.
+#[cfg(feature = "realtime")]
+pub type RealtimeStream = EventStream<ServerEvent>;
+
+/// Opens a realtime session with the given model, split into the sink of the events sent and the stream of the events received.
+///
+/// The server starts with a [`SessionCreated`](ServerEvent::SessionCreated) event, holding the default configuration of the session.
+///
+/// Related OpenAI docs: [Realtime](https://platform.openai.com/docs/api-reference/realtime)
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{Client, realtime::{connect, ClientEvent, RealtimeModality, RealtimeSessionConfig, ServerEvent}};
+/// use futures::{SinkExt, StreamExt};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let (mut sink, mut stream) = connect(&client, "gpt-4o-realtime-preview").await?;
+///
+///     sink.send(ClientEvent::SessionUpdate {
+///         session: RealtimeSessionConfig {
+///             modalities: Some(vec![RealtimeModality::Text]),
+///             instructions: Some("Answer in one sentence.".into()),
+///             ..Default::default()
+///         },
+///     })
+///     .await?;
+///     sink.send(ClientEvent::ConversationItemCreate {
+///         item: serde_json::json!({
+///             "type": "message",
+///             "role": "user",
+///             "content": [{"type": "input_text", "text": "What's the capital of France?"}]
+///         }),
+///     })
+///     .await?;
+///     sink.send(ClientEvent::ResponseCreate { response: None }).await?;
+///
+///     while let Some(event) = stream.next().await {
+///         match event? {
+///             ServerEvent::TextDelta { delta, .. } => print!("{delta}"),
+///             ServerEvent::ResponseDone { .. } => break,
+///             _ => {}
+///         }
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+#[cfg(feature = "realtime")]
+pub async fn connect(client: &Client, model: &str) -> Result<(RealtimeSink, RealtimeStream)> {
+    client.connect_realtime(model).await
+}
+
+/// Creates a realtime session, along with an ephemeral token for a client to connect to it in place of the API key.
+///
+/// The [`model`](RealtimeSessionConfig::model) is required, the rest of the configuration defaults for the client to update.
+///
+/// Related OpenAI docs: [Create Session](https://platform.openai.com/docs/api-reference/realtime-sessions/create)
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{Client, realtime::{create_session, RealtimeSessionConfig, Voice}};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let config = RealtimeSessionConfig {
+///         model: Some("gpt-4o-realtime-preview".into()),
+///         voice: Some(Voice::Verse),
+///         ..Default::default()
+///     };
+///
+///     let session = create_session(&client, &config).await?;
+///     // Handed over to the browser, which connects with it until it expires.
+///     println!("{}", session.client_secret.value);
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn create_session(
+    client: &Client,
+    config: &RealtimeSessionConfig,
+) -> Result<RealtimeSession> {
+    client.create_realtime_session(config).await
+}
+
+/// Creates a realtime session dedicated to transcription, along with an ephemeral token for a client to connect to it.
+///
+/// Related OpenAI docs: [Create Transcription Session](https://platform.openai.com/docs/api-reference/realtime-sessions/create-transcription)
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{Client, realtime::{
+///     create_transcription_session, InputAudioTranscription, NoiseReduction, RealtimeTranscriptionSessionConfig,
+/// }};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let config = RealtimeTranscriptionSessionConfig {
+///         input_audio_transcription: Some(InputAudioTranscription::new("gpt-4o-transcribe")),
+///         input_audio_noise_reduction: Some(NoiseReduction::NearField),
+///         ..Default::default()
+///     };
+///
+///     let session = create_transcription_session(&client, &config).await?;
+///     println!("{}", session.client_secret.value);
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn create_transcription_session(
+    client: &Client,
+    config: &RealtimeTranscriptionSessionConfig,
+) -> Result<RealtimeTranscriptionSession> {
+    client.create_realtime_transcription_session(config).await
+}
+
+impl Client {
+    async fn create_realtime_session(
+        &self,
+        config: &RealtimeSessionConfig,
+    ) -> Result<RealtimeSession> {
+        self.post::<RealtimeSessionConfig, RealtimeSession>("realtime/sessions", Some(config))
+            .await
+    }
+
+    async fn create_realtime_transcription_session(
+        &self,
+        config: &RealtimeTranscriptionSessionConfig,
+    ) -> Result<RealtimeTranscriptionSession> {
+        self.post::<RealtimeTranscriptionSessionConfig, RealtimeTranscriptionSession>(
+            "realtime/transcription_sessions",
+            Some(config),
+        )
+        .await
+    }
+
+    #[cfg(feature = "realtime")]
+    async fn connect_realtime(&self, model: &str) -> Result<(RealtimeSink, RealtimeStream)> {
+        let socket = self
+            .connect_websocket("realtime", &[("model", model)])
+            .await?;
+        let (sink, stream) = socket.split();
+
+        let sink = sink
+            .sink_map_err(Error::from)
+            .with(|event: ClientEvent| async move {
+                Ok::<_, Error>(Message::Text(serde_json::to_string(&event)?))
+            });
+
+        let stream = stream.filter_map(|message| async move {
+            match message {
+                Ok(Message::Text(text)) => Some(serde_json::from_str(&text).map_err(Error::from)),
+                Ok(_) => None,
+                Err(err) => Some(Err(Error::from(err))),
+            }
+        });
+
+        Ok((Box::pin(sink), Box::pin(stream)))
+    }
+}
+
+#[cfg(test)]
+mod tests {}
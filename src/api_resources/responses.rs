@@ -0,0 +1,141 @@
+//! Generate responses with the unified Responses API, combining the text and image inputs, the function calling
+//! and the built-in tools of the other endpoints, where new OpenAI features land first.
+//!
+//! Responses are stored by default, a conversation continues from the previous response with
+//! [`previous_response_id`](ResponseParam::previous_response_id), without sending its input and output again.
+
+use crate::{Client, Result};
+
+pub use crate::types::{
+    Delete, FileSearchToolCall, FileSearchToolResult, FunctionSpec, FunctionSpecBuilder,
+    FunctionToolCall, ImageDetail, InputContent, InputContentPart, InputItem, InputRole,
+    ItemStatus, McpApproval, McpApprovalRequest, McpListTools, McpToolCall, McpToolInfo,
+    OutputAnnotation, OutputContent, OutputItem, OutputMessage, RankingOptions, ReasoningConfig,
+    ReasoningEffort, ReasoningItem, ReasoningSummary, Response, ResponseError, ResponseInput,
+    ResponseParam, ResponseParamBuilder, ResponseStatus, ResponseTool, ResponseToolChoice,
+    ResponseUsage, SearchContextSize, ServiceTier, UserLocation, WebSearchAction, WebSearchFilters,
+    WebSearchToolCall,
+};
+
+/// Creates a response of the model to the given input.
+///
+/// Related OpenAI docs: [Create Response](https://platform.openai.com/docs/api-reference/responses/create)
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{Client, responses::{create, ResponseParamBuilder}};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let param = ResponseParamBuilder::new("gpt-4o", "Tell me a three sentence bedtime story about a unicorn.")
+///         .instructions("Talk like a pirate.")
+///         .build()?;
+///
+///     let resp = create(&client, &param).await?;
+///     println!("{}", resp.output_text());
+///
+///     Ok(())
+/// }
+/// ```
+///
+/// ## Function calling
+/// ```no_run
+/// use fieri::{Client, responses::{create, FunctionSpecBuilder, InputItem, ResponseParamBuilder, ResponseTool}};
+/// use serde_json::json;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let weather = FunctionSpecBuilder::new("get_weather", json!({"type": "object"})).build()?;
+///     let param = ResponseParamBuilder::new("gpt-4o", "What's the weather like in Paris today?")
+///         .tools(vec![ResponseTool::function(weather)])
+///         .build()?;
+///
+///     let resp = create(&client, &param).await?;
+///
+///     let outputs = resp
+///         .function_calls()
+///         .iter()
+///         .map(|call| InputItem::function_call_output(&call.call_id, "22C"))
+///         .collect::<Vec<_>>();
+///
+///     let param = ResponseParamBuilder::new("gpt-4o", outputs)
+///         .previous_response_id(&resp.id)
+///         .build()?;
+///
+///     let resp = create(&client, &param).await?;
+///     println!("{}", resp.output_text());
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn create(client: &Client, param: &ResponseParam) -> Result<Response> {
+    client.create_response(param).await
+}
+
+/// Retrieves a stored response.
+///
+/// Related OpenAI docs: [Get a Model Response](https://platform.openai.com/docs/api-reference/responses/get)
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{Client, responses::retrieve};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let resp = retrieve(&client, "resp_abc123").await?;
+///     println!("{}", resp.output_text());
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn retrieve(client: &Client, response_id: impl Into<String>) -> Result<Response> {
+    client.retrieve_response(response_id.into()).await
+}
+
+/// Deletes a stored response.
+///
+/// Related OpenAI docs: [Delete a Model Response](https://platform.openai.com/docs/api-reference/responses/delete)
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{Client, responses::delete};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let resp = delete(&client, "resp_abc123").await?;
+///     println!("{}", resp.deleted);
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn delete(client: &Client, response_id: impl Into<String>) -> Result<Delete> {
+    client.delete_response(response_id.into()).await
+}
+
+impl Client {
+    async fn create_response(&self, param: &ResponseParam) -> Result<Response> {
+        self.post::<ResponseParam, Response>("responses", Some(param))
+            .await
+    }
+
+    async fn retrieve_response(&self, response_id: String) -> Result<Response> {
+        self.get::<(), Response>(&format!("responses/{response_id}"), None)
+            .await
+    }
+
+    async fn delete_response(&self, response_id: String) -> Result<Delete> {
+        self.delete::<(), Delete>(&format!("responses/{response_id}"), None)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {}
@@ -0,0 +1,426 @@
+//! Runs invoke an [`assistant`](crate::assistant) on a [`thread`](crate::thread), to respond to its messages.
+//!
+//! A run goes from [`queued`](RunStatus::Queued) to [`in progress`](RunStatus::InProgress), until it reaches a terminal status.
+//! When the assistant calls functions, the run [`requires action`](RunStatus::RequiresAction): the outputs of the calls
+//! are to be [`submitted`](submit_tool_outputs) for the run to continue.
+//!
+//! [`Run::wait`] polls a run until it's done, or requires action.
+//!
+//! Each run goes through [`steps`](list_steps), creating messages or calling tools, to introspect what the assistant did.
+//! The images generated by the code interpreter are stored as files, see [`RunStep::code_interpreter_calls`].
+
+use crate::{client::EventStream, Client, Result};
+
+pub use crate::types::{
+    AssistantStreamEvent, CodeInterpreterCall, CodeInterpreterCallDelta, CodeInterpreterOutput,
+    CodeInterpreterOutputDelta, FileSearchCall, FileSearchResult, ListParam, ListParamBuilder,
+    ListRunSteps, ListRuns, MessageContentDelta, MessageCreation, MessageDelta, MessageDeltaBody,
+    MessageTextDelta, Order, PollOptions, PollOptionsBuilder, RequiredAction, RequiredToolCalls,
+    Run, RunError, RunParam, RunParamBuilder, RunStatus, RunStep, RunStepDelta, RunStepDeltaBody,
+    RunStepStatus, StepDetails, StepDetailsDelta, StepFunctionCall, StepFunctionCallDelta,
+    StepToolCall, StepToolCallDelta, SubmitToolOutputsParam, ToolCall, ToolOutput,
+};
+
+/// Creates a run of an assistant on a thread.
+///
+/// Related OpenAI docs: [Create Run](https://platform.openai.com/docs/api-reference/runs/createRun)
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{Client, run::{create, RunParamBuilder}};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let param = RunParamBuilder::new("asst_abc123").build()?;
+///
+///     let resp = create(&client, "thread_abc123", &param).await?;
+///     println!("{:?}", resp.status);
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn create(
+    client: &Client,
+    thread_id: impl Into<String>,
+    param: &RunParam,
+) -> Result<Run> {
+    client.create_run(thread_id.into(), param).await
+}
+
+/// Creates a run of an assistant on a thread, streaming back the events of the run as it goes.
+///
+/// The `stream` parameter is implicitly set. The text of the messages arrives as
+/// [`MessageDelta`](AssistantStreamEvent::MessageDelta) events, and the stream ends with the [`Done`](AssistantStreamEvent::Done) event.
+/// When the run [`requires action`](AssistantStreamEvent::RunRequiresAction), it continues in the stream of
+/// [`submit_tool_outputs_stream`].
+///
+/// Related OpenAI docs: [Create Run](https://platform.openai.com/docs/api-reference/runs/createRun)
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{Client, run::{create_and_stream, AssistantStreamEvent, RunParamBuilder}};
+/// use futures::StreamExt;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let param = RunParamBuilder::new("asst_abc123").build()?;
+///
+///     let mut stream = create_and_stream(&client, "thread_abc123", &param).await?;
+///
+///     while let Some(event) = stream.next().await {
+///         if let AssistantStreamEvent::MessageDelta(delta) = event? {
+///             print!("{}", delta.text());
+///         }
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn create_and_stream(
+    client: &Client,
+    thread_id: impl Into<String>,
+    param: &RunParam,
+) -> Result<EventStream<AssistantStreamEvent>> {
+    client.create_run_stream(thread_id.into(), param).await
+}
+
+/// Retrieves a run, along with its status.
+///
+/// Related OpenAI docs: [Retrieve Run](https://platform.openai.com/docs/api-reference/runs/getRun)
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{Client, run::retrieve};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let resp = retrieve(&client, "thread_abc123", "run_abc123").await?;
+///     println!("{:?}", resp.status);
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn retrieve(
+    client: &Client,
+    thread_id: impl Into<String>,
+    run_id: impl Into<String>,
+) -> Result<Run> {
+    client.retrieve_run(thread_id.into(), run_id.into()).await
+}
+
+/// Cancels a run in progress.
+///
+/// Related OpenAI docs: [Cancel Run](https://platform.openai.com/docs/api-reference/runs/cancelRun)
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{Client, run::cancel};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let resp = cancel(&client, "thread_abc123", "run_abc123").await?;
+///     println!("{:?}", resp.status);
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn cancel(
+    client: &Client,
+    thread_id: impl Into<String>,
+    run_id: impl Into<String>,
+) -> Result<Run> {
+    client.cancel_run(thread_id.into(), run_id.into()).await
+}
+
+/// Returns a paginated list of the runs of a thread.
+///
+/// Related OpenAI docs: [List Runs](https://platform.openai.com/docs/api-reference/runs/listRuns)
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{Client, run::{list, ListParamBuilder}};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let param = ListParamBuilder::default().limit(10u32).build()?;
+///
+///     let resp = list(&client, "thread_abc123", &param).await?;
+///     println!("{:#?}", resp.data);
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn list(
+    client: &Client,
+    thread_id: impl Into<String>,
+    param: &ListParam,
+) -> Result<ListRuns> {
+    client.list_runs(thread_id.into(), param).await
+}
+
+/// Returns a paginated list of the steps of a run.
+///
+/// Related OpenAI docs: [List Run Steps](https://platform.openai.com/docs/api-reference/run-steps/listRunSteps)
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{Client, run::{list_steps, ListParamBuilder, StepDetails, StepToolCall}};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let param = ListParamBuilder::default().build()?;
+///
+///     let resp = list_steps(&client, "thread_abc123", "run_abc123", &param).await?;
+///     for step in resp.data {
+///         if let Some(StepDetails::ToolCalls { tool_calls }) = step.step_details {
+///             for call in tool_calls {
+///                 if let StepToolCall::CodeInterpreter { code_interpreter, .. } = call {
+///                     println!("{}", code_interpreter.input);
+///                 }
+///             }
+///         }
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn list_steps(
+    client: &Client,
+    thread_id: impl Into<String>,
+    run_id: impl Into<String>,
+    param: &ListParam,
+) -> Result<ListRunSteps> {
+    client
+        .list_run_steps(thread_id.into(), run_id.into(), param)
+        .await
+}
+
+/// Retrieves a step of a run.
+///
+/// Related OpenAI docs: [Retrieve Run Step](https://platform.openai.com/docs/api-reference/run-steps/getRunStep)
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{Client, run::retrieve_step};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let resp = retrieve_step(&client, "thread_abc123", "run_abc123", "step_abc123").await?;
+///     println!("{:#?}", resp.step_details);
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn retrieve_step(
+    client: &Client,
+    thread_id: impl Into<String>,
+    run_id: impl Into<String>,
+    step_id: impl Into<String>,
+) -> Result<RunStep> {
+    client
+        .retrieve_run_step(thread_id.into(), run_id.into(), step_id.into())
+        .await
+}
+
+/// Submits the outputs of the tool calls of a run which [`requires action`](RunStatus::RequiresAction), for it to continue.
+///
+/// The outputs of all the calls of [`required_action`](Run::required_action) must be submitted at once.
+///
+/// Related OpenAI docs: [Submit Tool Outputs](https://platform.openai.com/docs/api-reference/runs/submitToolOutputs)
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{Client, run::{retrieve, submit_tool_outputs, ToolOutput}};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let run = retrieve(&client, "thread_abc123", "run_abc123").await?;
+///     if let Some(action) = &run.required_action {
+///         let outputs = action
+///             .tool_calls()
+///             .iter()
+///             .map(|call| ToolOutput::new(&call.id, "22C"))
+///             .collect();
+///
+///         let resp = submit_tool_outputs(&client, &run.thread_id, &run.id, outputs).await?;
+///         println!("{:?}", resp.status);
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn submit_tool_outputs(
+    client: &Client,
+    thread_id: impl Into<String>,
+    run_id: impl Into<String>,
+    tool_outputs: Vec<ToolOutput>,
+) -> Result<Run> {
+    let param = SubmitToolOutputsParam {
+        tool_outputs,
+        ..Default::default()
+    };
+
+    client
+        .submit_tool_outputs(thread_id.into(), run_id.into(), &param)
+        .await
+}
+
+/// Submits the outputs of the tool calls of a run, like [`submit_tool_outputs`], streaming back the events of the run as it continues.
+///
+/// Related OpenAI docs: [Submit Tool Outputs](https://platform.openai.com/docs/api-reference/runs/submitToolOutputs)
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{Client, run::{create_and_stream, submit_tool_outputs_stream, AssistantStreamEvent, RunParamBuilder, ToolOutput}};
+/// use futures::StreamExt;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let param = RunParamBuilder::new("asst_abc123").build()?;
+///     let mut stream = create_and_stream(&client, "thread_abc123", &param).await?;
+///
+///     while let Some(event) = stream.next().await {
+///         match event? {
+///             AssistantStreamEvent::MessageDelta(delta) => print!("{}", delta.text()),
+///             AssistantStreamEvent::RunRequiresAction(run) => {
+///                 let outputs = run
+///                     .required_action
+///                     .iter()
+///                     .flat_map(|action| action.tool_calls())
+///                     .map(|call| ToolOutput::new(&call.id, "22C"))
+///                     .collect();
+///
+///                 stream = submit_tool_outputs_stream(&client, &run.thread_id, &run.id, outputs).await?;
+///             }
+///             _ => {}
+///         }
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn submit_tool_outputs_stream(
+    client: &Client,
+    thread_id: impl Into<String>,
+    run_id: impl Into<String>,
+    tool_outputs: Vec<ToolOutput>,
+) -> Result<EventStream<AssistantStreamEvent>> {
+    let param = SubmitToolOutputsParam {
+        tool_outputs,
+        stream: true,
+    };
+
+    client
+        .submit_tool_outputs_stream(thread_id.into(), run_id.into(), &param)
+        .await
+}
+
+impl Client {
+    async fn create_run(&self, thread_id: String, param: &RunParam) -> Result<Run> {
+        self.post::<RunParam, Run>(&format!("threads/{thread_id}/runs"), Some(param))
+            .await
+    }
+
+    async fn create_run_stream(
+        &self,
+        thread_id: String,
+        param: &RunParam,
+    ) -> Result<EventStream<AssistantStreamEvent>> {
+        let mut param = param.clone();
+        param.stream = true;
+
+        self.post_named_event_stream::<RunParam, AssistantStreamEvent>(
+            &format!("threads/{thread_id}/runs"),
+            Some(&param),
+        )
+        .await
+    }
+
+    async fn retrieve_run(&self, thread_id: String, run_id: String) -> Result<Run> {
+        self.get::<(), Run>(&format!("threads/{thread_id}/runs/{run_id}"), None)
+            .await
+    }
+
+    async fn cancel_run(&self, thread_id: String, run_id: String) -> Result<Run> {
+        self.post::<(), Run>(&format!("threads/{thread_id}/runs/{run_id}/cancel"), None)
+            .await
+    }
+
+    async fn list_runs(&self, thread_id: String, param: &ListParam) -> Result<ListRuns> {
+        self.get::<ListParam, ListRuns>(&format!("threads/{thread_id}/runs"), Some(param))
+            .await
+    }
+
+    async fn list_run_steps(
+        &self,
+        thread_id: String,
+        run_id: String,
+        param: &ListParam,
+    ) -> Result<ListRunSteps> {
+        self.get::<ListParam, ListRunSteps>(
+            &format!("threads/{thread_id}/runs/{run_id}/steps"),
+            Some(param),
+        )
+        .await
+    }
+
+    async fn retrieve_run_step(
+        &self,
+        thread_id: String,
+        run_id: String,
+        step_id: String,
+    ) -> Result<RunStep> {
+        self.get::<(), RunStep>(
+            &format!("threads/{thread_id}/runs/{run_id}/steps/{step_id}"),
+            None,
+        )
+        .await
+    }
+
+    async fn submit_tool_outputs(
+        &self,
+        thread_id: String,
+        run_id: String,
+        param: &SubmitToolOutputsParam,
+    ) -> Result<Run> {
+        self.post::<SubmitToolOutputsParam, Run>(
+            &format!("threads/{thread_id}/runs/{run_id}/submit_tool_outputs"),
+            Some(param),
+        )
+        .await
+    }
+
+    async fn submit_tool_outputs_stream(
+        &self,
+        thread_id: String,
+        run_id: String,
+        param: &SubmitToolOutputsParam,
+    ) -> Result<EventStream<AssistantStreamEvent>> {
+        self.post_named_event_stream::<SubmitToolOutputsParam, AssistantStreamEvent>(
+            &format!("threads/{thread_id}/runs/{run_id}/submit_tool_outputs"),
+            Some(param),
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {}
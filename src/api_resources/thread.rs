@@ -0,0 +1,307 @@
+//! Threads are conversations between a user and an [`assistant`](crate::assistant), storing their messages.
+//!
+//! Once the messages of a thread no longer fit in the context of the model, the thread is truncated automatically.
+
+use std::collections::HashMap;
+
+use serde_json::json;
+
+use crate::{Client, Result};
+
+pub use crate::types::{
+    Annotation, Attachment, CreateMessageParam, CreateMessageParamBuilder, Delete, FileReference,
+    ImageFile, ListMessages, ListMessagesParam, ListMessagesParamBuilder, Message, MessageContent,
+    MessageContentPart, MessageInput, MessageRole, MessageStatus, MessageText, Order, Thread,
+    ThreadParam, ThreadParamBuilder, ToolResources,
+};
+
+/// Creates a thread, possibly starting with some messages.
+///
+/// Related OpenAI docs: [Create Thread](https://platform.openai.com/docs/api-reference/threads/createThread)
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{Client, thread::{create, CreateMessageParam, ThreadParamBuilder}};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let param = ThreadParamBuilder::default()
+///         .messages(vec![CreateMessageParam::user("I need to solve the equation `3x + 11 = 14`.")])
+///         .build()?;
+///
+///     let resp = create(&client, &param).await?;
+///     println!("{}", resp.id);
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn create(client: &Client, param: &ThreadParam) -> Result<Thread> {
+    client.create_thread(param).await
+}
+
+/// Retrieves a thread.
+///
+/// Related OpenAI docs: [Retrieve Thread](https://platform.openai.com/docs/api-reference/threads/getThread)
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{Client, thread::retrieve};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let resp = retrieve(&client, "thread_abc123").await?;
+///     println!("{:#?}", resp);
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn retrieve(client: &Client, thread_id: impl Into<String>) -> Result<Thread> {
+    client.retrieve_thread(thread_id.into()).await
+}
+
+/// Modifies the tool resources or the metadata of a thread.
+///
+/// Related OpenAI docs: [Modify Thread](https://platform.openai.com/docs/api-reference/threads/modifyThread)
+///
+/// ## Example
+/// ```no_run
+/// use std::collections::HashMap;
+/// use fieri::{Client, thread::{modify, ThreadParamBuilder}};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let param = ThreadParamBuilder::default()
+///         .metadata(HashMap::from([("user".to_string(), "abc123".to_string())]))
+///         .build()?;
+///
+///     let resp = modify(&client, "thread_abc123", &param).await?;
+///     println!("{:#?}", resp);
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn modify(
+    client: &Client,
+    thread_id: impl Into<String>,
+    param: &ThreadParam,
+) -> Result<Thread> {
+    client.modify_thread(thread_id.into(), param).await
+}
+
+/// Deletes a thread.
+///
+/// Related OpenAI docs: [Delete Thread](https://platform.openai.com/docs/api-reference/threads/deleteThread)
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{Client, thread::delete};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let resp = delete(&client, "thread_abc123").await?;
+///     println!("{}", resp.deleted);
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn delete(client: &Client, thread_id: impl Into<String>) -> Result<Delete> {
+    client.delete_thread(thread_id.into()).await
+}
+
+/// Adds a message to a thread.
+///
+/// Related OpenAI docs: [Create Message](https://platform.openai.com/docs/api-reference/messages/createMessage)
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{Client, thread::{create_message, CreateMessageParam, MessageContentPart}};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let param = CreateMessageParam::user(vec![
+///         MessageContentPart::text("What's in this image?"),
+///         MessageContentPart::image_url("https://example.com/image.png"),
+///     ]);
+///
+///     let resp = create_message(&client, "thread_abc123", &param).await?;
+///     println!("{}", resp.id);
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn create_message(
+    client: &Client,
+    thread_id: impl Into<String>,
+    param: &CreateMessageParam,
+) -> Result<Message> {
+    client.create_message(thread_id.into(), param).await
+}
+
+/// Returns a paginated list of the messages of a thread.
+///
+/// Related OpenAI docs: [List Messages](https://platform.openai.com/docs/api-reference/messages/listMessages)
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{Client, thread::{list_messages, ListMessagesParamBuilder, Order}};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let param = ListMessagesParamBuilder::default()
+///         .order(Order::Asc)
+///         .build()?;
+///
+///     let resp = list_messages(&client, "thread_abc123", &param).await?;
+///     for message in resp.data {
+///         println!("{:?}: {}", message.role, message.text());
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn list_messages(
+    client: &Client,
+    thread_id: impl Into<String>,
+    param: &ListMessagesParam,
+) -> Result<ListMessages> {
+    client.list_messages(thread_id.into(), param).await
+}
+
+/// Retrieves a message of a thread.
+///
+/// Related OpenAI docs: [Retrieve Message](https://platform.openai.com/docs/api-reference/messages/getMessage)
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{Client, thread::retrieve_message};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let resp = retrieve_message(&client, "thread_abc123", "msg_abc123").await?;
+///     println!("{}", resp.text());
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn retrieve_message(
+    client: &Client,
+    thread_id: impl Into<String>,
+    message_id: impl Into<String>,
+) -> Result<Message> {
+    client
+        .retrieve_message(thread_id.into(), message_id.into())
+        .await
+}
+
+/// Modifies the metadata of a message of a thread.
+///
+/// Related OpenAI docs: [Modify Message](https://platform.openai.com/docs/api-reference/messages/modifyMessage)
+///
+/// ## Example
+/// ```no_run
+/// use std::collections::HashMap;
+/// use fieri::{Client, thread::modify_message};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let metadata = HashMap::from([("reviewed".to_string(), "true".to_string())]);
+///
+///     let resp = modify_message(&client, "thread_abc123", "msg_abc123", metadata).await?;
+///     println!("{:#?}", resp.metadata);
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn modify_message(
+    client: &Client,
+    thread_id: impl Into<String>,
+    message_id: impl Into<String>,
+    metadata: HashMap<String, String>,
+) -> Result<Message> {
+    client
+        .modify_message(thread_id.into(), message_id.into(), metadata)
+        .await
+}
+
+impl Client {
+    async fn create_thread(&self, param: &ThreadParam) -> Result<Thread> {
+        self.post::<ThreadParam, Thread>("threads", Some(param))
+            .await
+    }
+
+    async fn retrieve_thread(&self, thread_id: String) -> Result<Thread> {
+        self.get::<(), Thread>(&format!("threads/{thread_id}"), None)
+            .await
+    }
+
+    async fn modify_thread(&self, thread_id: String, param: &ThreadParam) -> Result<Thread> {
+        self.post::<ThreadParam, Thread>(&format!("threads/{thread_id}"), Some(param))
+            .await
+    }
+
+    async fn delete_thread(&self, thread_id: String) -> Result<Delete> {
+        self.delete::<(), Delete>(&format!("threads/{thread_id}"), None)
+            .await
+    }
+
+    async fn create_message(
+        &self,
+        thread_id: String,
+        param: &CreateMessageParam,
+    ) -> Result<Message> {
+        self.post::<CreateMessageParam, Message>(
+            &format!("threads/{thread_id}/messages"),
+            Some(param),
+        )
+        .await
+    }
+
+    async fn list_messages(
+        &self,
+        thread_id: String,
+        param: &ListMessagesParam,
+    ) -> Result<ListMessages> {
+        self.get::<ListMessagesParam, ListMessages>(
+            &format!("threads/{thread_id}/messages"),
+            Some(param),
+        )
+        .await
+    }
+
+    async fn retrieve_message(&self, thread_id: String, message_id: String) -> Result<Message> {
+        self.get::<(), Message>(&format!("threads/{thread_id}/messages/{message_id}"), None)
+            .await
+    }
+
+    async fn modify_message(
+        &self,
+        thread_id: String,
+        message_id: String,
+        metadata: HashMap<String, String>,
+    ) -> Result<Message> {
+        self.post::<serde_json::Value, Message>(
+            &format!("threads/{thread_id}/messages/{message_id}"),
+            Some(&json!({ "metadata": metadata })),
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {}
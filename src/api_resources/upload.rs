@@ -0,0 +1,476 @@
+//! Upload large files in parts, for files over the size limit of a single [`file upload`](crate::file::upload).
+//!
+//! An [`Upload`] is created for the total size of the file, its parts of up to 64MB each are then added,
+//! and the upload is completed with the parts in order, turning it into a regular [`File`].
+//! The API accepts parts added in parallel, [`upload_reader`] and [`upload_resumable`] add them one at a time though.
+//! Uploads expire an hour after their creation.
+//!
+//! An interrupted upload can be resumed from its last added part by persisting its [`UploadSession`], see [`upload_resumable`].
+
+use reqwest::multipart::Form;
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::Path;
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+use crate::{Client, Result};
+
+pub use crate::types::{
+    CompleteUploadParam, CreateUploadParam, CreateUploadParamBuilder, File, FileUpload, Purpose,
+    Upload, UploadPart, UploadSession, UploadStatus,
+};
+
+/// The maximum size of a single part, in bytes.
+pub const MAX_PART_SIZE: usize = 64 * 1024 * 1024;
+
+/// Creates an upload, to which parts are then added.
+///
+/// Related OpenAI docs: [Create Upload](https://platform.openai.com/docs/api-reference/uploads/create)
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{Client, upload::{create, CreateUploadParamBuilder, Purpose}};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let param = CreateUploadParamBuilder::new("train.jsonl", Purpose::FineTune, 2_147_483_648, "text/jsonl")
+///         .build()?;
+///
+///     let resp = create(&client, &param).await?;
+///     println!("{}", resp.id);
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn create(client: &Client, param: &CreateUploadParam) -> Result<Upload> {
+    client.create_upload(param).await
+}
+
+/// Adds a part of up to 64MB to an upload.
+///
+/// Related OpenAI docs: [Add Upload Part](https://platform.openai.com/docs/api-reference/uploads/add-part)
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{Client, upload::{add_part, FileUpload}};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let part = std::fs::read("train.jsonl.part0")?;
+///     let resp = add_part(&client, "upload_abc123", FileUpload::bytes("part0", part)).await?;
+///     println!("{}", resp.id);
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn add_part(
+    client: &Client,
+    upload_id: impl Into<String>,
+    data: impl Into<FileUpload>,
+) -> Result<UploadPart> {
+    client.add_upload_part(upload_id.into(), data.into()).await
+}
+
+/// Completes an upload with its parts in order, creating the uploaded [`File`].
+///
+/// Related OpenAI docs: [Complete Upload](https://platform.openai.com/docs/api-reference/uploads/complete)
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{Client, upload::{complete, CompleteUploadParam}};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let param = CompleteUploadParam {
+///         part_ids: vec!["part_def456".into(), "part_ghi789".into()],
+///         md5: None,
+///     };
+///
+///     let resp = complete(&client, "upload_abc123", &param).await?;
+///     println!("{:#?}", resp.file);
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn complete(
+    client: &Client,
+    upload_id: impl Into<String>,
+    param: &CompleteUploadParam,
+) -> Result<Upload> {
+    client.complete_upload(upload_id.into(), param).await
+}
+
+/// Cancels an upload, no parts can be added to it afterwards.
+///
+/// Related OpenAI docs: [Cancel Upload](https://platform.openai.com/docs/api-reference/uploads/cancel)
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{Client, upload::cancel};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let resp = cancel(&client, "upload_abc123").await?;
+///     println!("{:?}", resp.status);
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn cancel(client: &Client, upload_id: impl Into<String>) -> Result<Upload> {
+    client.cancel_upload(upload_id.into()).await
+}
+
+/// Uploads the whole contents of the reader in parts of `part_size` bytes, up to [`MAX_PART_SIZE`], then completes the upload.
+///
+/// The reader must hold exactly the [`bytes`](CreateUploadParam::bytes) of the upload.
+/// A single part is held in memory at a time.
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{Client, upload::{upload_reader, CreateUploadParamBuilder, Purpose, MAX_PART_SIZE}};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let file = tokio::fs::File::open("train.jsonl").await?;
+///     let bytes = file.metadata().await?.len();
+///
+///     let param = CreateUploadParamBuilder::new("train.jsonl", Purpose::FineTune, bytes, "text/jsonl")
+///         .build()?;
+///
+///     let resp = upload_reader(&client, &param, file, MAX_PART_SIZE).await?;
+///     println!("{:#?}", resp.file);
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn upload_reader<R>(
+    client: &Client,
+    param: &CreateUploadParam,
+    reader: R,
+    part_size: usize,
+) -> Result<Upload>
+where
+    R: AsyncRead + Unpin,
+{
+    client.upload_reader(param, reader, part_size).await
+}
+
+/// Uploads the file at `path`, saving the [`UploadSession`] at `session_path` after each part, to resume from it if interrupted.
+///
+/// When a session was saved for the same file, with the same contents, the upload resumes after its last added part,
+/// otherwise a new upload is created. A saved upload that is expired, or that the API rejects, is started anew.
+/// The file is read once beforehand, to hash its contents.
+///
+/// The session is removed once the upload is completed. It's kept when a part fails with a transient error,
+/// like a timeout, to be resumed later, while the upload is cancelled and the session removed on any other failure.
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{Client, upload::{upload_resumable, CreateUploadParamBuilder, Purpose, MAX_PART_SIZE}};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let bytes = std::fs::metadata("train.jsonl")?.len();
+///     let param = CreateUploadParamBuilder::new("train.jsonl", Purpose::FineTune, bytes, "text/jsonl")
+///         .build()?;
+///
+///     // Running it again after an interruption picks up where it stopped.
+///     let resp = upload_resumable(&client, &param, "train.jsonl", "train.jsonl.upload", MAX_PART_SIZE).await?;
+///     println!("{:#?}", resp.file);
+///
+///     Ok(())
+/// }
+/// ```
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn upload_resumable(
+    client: &Client,
+    param: &CreateUploadParam,
+    path: impl AsRef<Path>,
+    session_path: impl AsRef<Path>,
+    part_size: usize,
+) -> Result<Upload> {
+    let path = path.as_ref();
+    let session_path = session_path.as_ref();
+    let sha1 = file_sha1(path).await?;
+
+    let (mut session, mut resumed) = match UploadSession::load(session_path) {
+        Ok(session)
+            if session.filename == param.filename
+                && session.bytes == param.bytes
+                && session.sha1 == sha1
+                && !session.is_expired() =>
+        {
+            (session, true)
+        }
+        _ => (
+            start_session(client, param, part_size, &sha1, session_path).await?,
+            false,
+        ),
+    };
+
+    let mut file = tokio::fs::File::open(path).await?;
+    loop {
+        let uploaded = match session.upload_part(client, &mut file).await {
+            Ok(true) => {
+                session.save(session_path)?;
+                continue;
+            }
+            Ok(false) => session.complete(client).await,
+            Err(err) => Err(err),
+        };
+
+        match uploaded {
+            Ok(upload) => {
+                std::fs::remove_file(session_path)?;
+                return Ok(upload);
+            }
+            Err(err) if err.is_transient() => return Err(err),
+            // The saved upload is no longer accepted, like once cancelled, so the file is uploaded anew.
+            Err(crate::Error::APIError(_)) if resumed => {
+                resumed = false;
+                session = start_session(client, param, part_size, &sha1, session_path).await?;
+            }
+            Err(err) => {
+                if let Err(cancel) = client.cancel_upload(session.upload_id.clone()).await {
+                    log::warn!(
+                        "Unable to cancel the upload {}: {cancel}",
+                        session.upload_id
+                    );
+                }
+                std::fs::remove_file(session_path)?;
+                return Err(err);
+            }
+        }
+    }
+}
+
+// Creates the upload of the file, saving its session before any part is added.
+#[cfg(not(target_arch = "wasm32"))]
+async fn start_session(
+    client: &Client,
+    param: &CreateUploadParam,
+    part_size: usize,
+    sha1: &str,
+    session_path: &Path,
+) -> Result<UploadSession> {
+    let mut session = UploadSession::create(client, param, part_size).await?;
+    session.sha1 = sha1.to_string();
+    session.save(session_path)?;
+
+    Ok(session)
+}
+
+// The SHA-1 of the contents of the file, hex encoded.
+#[cfg(not(target_arch = "wasm32"))]
+async fn file_sha1(path: &Path) -> Result<String> {
+    use sha1::{Digest, Sha1};
+
+    let mut file = tokio::fs::File::open(path).await?;
+    let mut hasher = Sha1::new();
+    let mut buffer = vec![0; 64 * 1024];
+    loop {
+        let read = file.read(&mut buffer).await?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+    }
+
+    Ok(hasher
+        .finalize()
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect())
+}
+
+/// Reads the next part of the reader, of up to `part_size` bytes, empty once the reader is exhausted.
+pub(crate) async fn read_part<R>(reader: &mut R, part_size: usize) -> Result<Vec<u8>>
+where
+    R: AsyncRead + Unpin,
+{
+    let mut part = Vec::with_capacity(part_size);
+    reader.take(part_size as u64).read_to_end(&mut part).await?;
+
+    Ok(part)
+}
+
+impl Client {
+    async fn create_upload(&self, param: &CreateUploadParam) -> Result<Upload> {
+        self.post::<CreateUploadParam, Upload>("uploads", Some(param))
+            .await
+    }
+
+    async fn add_upload_part(&self, upload_id: String, data: FileUpload) -> Result<UploadPart> {
+        let form = Form::new().part("data", data.into_part()?);
+
+        self.post_data::<UploadPart>(&format!("uploads/{upload_id}/parts"), form)
+            .await
+    }
+
+    async fn complete_upload(
+        &self,
+        upload_id: String,
+        param: &CompleteUploadParam,
+    ) -> Result<Upload> {
+        self.post::<CompleteUploadParam, Upload>(
+            &format!("uploads/{upload_id}/complete"),
+            Some(param),
+        )
+        .await
+    }
+
+    async fn cancel_upload(&self, upload_id: String) -> Result<Upload> {
+        self.post::<(), Upload>(&format!("uploads/{upload_id}/cancel"), None)
+            .await
+    }
+
+    async fn upload_reader<R>(
+        &self,
+        param: &CreateUploadParam,
+        mut reader: R,
+        part_size: usize,
+    ) -> Result<Upload>
+    where
+        R: AsyncRead + Unpin,
+    {
+        let part_size = part_size.clamp(1, MAX_PART_SIZE);
+        let upload = self.create_upload(param).await?;
+
+        let mut part_ids = Vec::new();
+        loop {
+            let part = read_part(&mut reader, part_size).await?;
+            if part.is_empty() {
+                break;
+            }
+
+            let name = format!("{}.part{}", param.filename, part_ids.len());
+            let part = self
+                .add_upload_part(upload.id.clone(), FileUpload::bytes(name, part))
+                .await?;
+            part_ids.push(part.id);
+        }
+
+        let param = CompleteUploadParam {
+            part_ids,
+            md5: None,
+        };
+        self.complete_upload(upload.id, &param).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_read_part() {
+        let mut reader = &b"abcdefg"[..];
+
+        assert_eq!(read_part(&mut reader, 3).await.unwrap(), b"abc");
+        assert_eq!(read_part(&mut reader, 3).await.unwrap(), b"def");
+        assert_eq!(read_part(&mut reader, 3).await.unwrap(), b"g");
+        assert!(read_part(&mut reader, 3).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_upload_resumable() {
+        use crate::transport::MockTransport;
+        use reqwest::{Method, StatusCode};
+        use std::sync::Arc;
+
+        let dir = std::env::temp_dir().join(format!("fieri-upload-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let (path, session_path) = (dir.join("train.jsonl"), dir.join("train.jsonl.upload"));
+        std::fs::write(&path, "abcdefg").unwrap();
+
+        let param =
+            CreateUploadParamBuilder::new("train.jsonl", Purpose::FineTune, 7, "text/jsonl")
+                .build()
+                .unwrap();
+        let upload = |id: &str, status: &str| serde_json::json!({"id": id, "status": status, "expires_at": u64::MAX});
+        let rejected = serde_json::json!({"error": {"message": "Upload is expired.", "type": "invalid_request_error", "param": null, "code": null}});
+
+        // The saved upload is rejected, so the file is uploaded anew.
+        UploadSession {
+            upload_id: "upload_old".into(),
+            filename: "train.jsonl".into(),
+            bytes: 7,
+            part_size: 4,
+            part_ids: vec!["part_old".into()],
+            sha1: file_sha1(&path).await.unwrap(),
+            expires_at: u64::MAX,
+        }
+        .save(&session_path)
+        .unwrap();
+        let mock = Arc::new(
+            MockTransport::new()
+                .respond_with(
+                    Method::POST,
+                    "uploads/upload_old/parts",
+                    StatusCode::NOT_FOUND,
+                    &rejected,
+                )
+                .respond(Method::POST, "uploads", upload("upload_new", "pending"))
+                .respond(
+                    Method::POST,
+                    "uploads/upload_new/parts",
+                    serde_json::json!({"id": "part_new"}),
+                )
+                .respond(
+                    Method::POST,
+                    "uploads/upload_new/complete",
+                    upload("upload_new", "completed"),
+                ),
+        );
+        let client = Client::new().transport(mock.clone());
+        let resp = upload_resumable(&client, &param, &path, &session_path, 4)
+            .await
+            .unwrap();
+        assert_eq!(resp.status, UploadStatus::Completed);
+        assert_eq!(
+            mock.requests().last().unwrap().body.as_ref().unwrap()["part_ids"],
+            serde_json::json!(["part_new", "part_new"])
+        );
+        assert!(!session_path.exists());
+
+        // A part failing for good cancels the upload.
+        let mock = Arc::new(
+            MockTransport::new()
+                .respond(Method::POST, "uploads", upload("upload_new", "pending"))
+                .respond_with(
+                    Method::POST,
+                    "uploads/upload_new/parts",
+                    StatusCode::BAD_REQUEST,
+                    &rejected,
+                )
+                .respond(
+                    Method::POST,
+                    "uploads/upload_new/cancel",
+                    upload("upload_new", "cancelled"),
+                ),
+        );
+        let client = Client::new().transport(mock.clone());
+        assert!(matches!(
+            upload_resumable(&client, &param, &path, &session_path, 4).await,
+            Err(crate::Error::APIError(_))
+        ));
+        assert_eq!(
+            mock.requests().last().unwrap().path,
+            "uploads/upload_new/cancel"
+        );
+        assert!(!session_path.exists());
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,352 @@
+//! Vector stores hold processed [`files`](crate::file), searched by the [`file search`](crate::assistant::AssistantTool::FileSearch) tool of assistants.
+//!
+//! Files are attached one by one, or many at once in a batch. They are chunked following their [`chunking strategy`](ChunkingStrategy)
+//! and embedded once attached, and only searchable once their processing [`completed`](VectorStoreFileStatus::Completed).
+//! [`VectorStoreFileBatch::wait`] polls a batch until all its files are processed.
+
+use crate::{Client, Result};
+
+pub use crate::types::{
+    ChunkingStrategy, CreateFileBatchParam, CreateFileBatchParamBuilder,
+    CreateVectorStoreFileParam, CreateVectorStoreFileParamBuilder, Delete, FileCounts,
+    ListVectorStoreFiles, ListVectorStoreFilesParam, ListVectorStoreFilesParamBuilder, Order,
+    PollOptions, PollOptionsBuilder, StaticChunking, VectorStoreFile, VectorStoreFileBatch,
+    VectorStoreFileError, VectorStoreFileStatus,
+};
+
+/// Attaches an uploaded file to a vector store.
+///
+/// Related OpenAI docs: [Create Vector Store File](https://platform.openai.com/docs/api-reference/vector-stores-files/createFile)
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{Client, vector_store::{create_file, CreateVectorStoreFileParamBuilder}};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let param = CreateVectorStoreFileParamBuilder::new("file-abc123").build()?;
+///
+///     let resp = create_file(&client, "vs_abc123", &param).await?;
+///     println!("{:?}", resp.status);
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn create_file(
+    client: &Client,
+    vector_store_id: impl Into<String>,
+    param: &CreateVectorStoreFileParam,
+) -> Result<VectorStoreFile> {
+    client
+        .create_vector_store_file(vector_store_id.into(), param)
+        .await
+}
+
+/// Returns a paginated list of the files of a vector store, along with their processing status.
+///
+/// Related OpenAI docs: [List Vector Store Files](https://platform.openai.com/docs/api-reference/vector-stores-files/listFiles)
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{Client, vector_store::{list_files, ListVectorStoreFilesParamBuilder, VectorStoreFileStatus}};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let param = ListVectorStoreFilesParamBuilder::default()
+///         .filter(VectorStoreFileStatus::Failed)
+///         .build()?;
+///
+///     let resp = list_files(&client, "vs_abc123", &param).await?;
+///     println!("{:#?}", resp.data);
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn list_files(
+    client: &Client,
+    vector_store_id: impl Into<String>,
+    param: &ListVectorStoreFilesParam,
+) -> Result<ListVectorStoreFiles> {
+    client
+        .list_vector_store_files(vector_store_id.into(), param)
+        .await
+}
+
+/// Retrieves a file of a vector store, along with its processing status.
+///
+/// Related OpenAI docs: [Retrieve Vector Store File](https://platform.openai.com/docs/api-reference/vector-stores-files/getFile)
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{Client, vector_store::retrieve_file};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let resp = retrieve_file(&client, "vs_abc123", "file-abc123").await?;
+///     println!("{:?}", resp.status);
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn retrieve_file(
+    client: &Client,
+    vector_store_id: impl Into<String>,
+    file_id: impl Into<String>,
+) -> Result<VectorStoreFile> {
+    client
+        .retrieve_vector_store_file(vector_store_id.into(), file_id.into())
+        .await
+}
+
+/// Detaches a file from a vector store, the uploaded file itself isn't deleted.
+///
+/// Related OpenAI docs: [Delete Vector Store File](https://platform.openai.com/docs/api-reference/vector-stores-files/deleteFile)
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{Client, vector_store::delete_file};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let resp = delete_file(&client, "vs_abc123", "file-abc123").await?;
+///     println!("{}", resp.deleted);
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn delete_file(
+    client: &Client,
+    vector_store_id: impl Into<String>,
+    file_id: impl Into<String>,
+) -> Result<Delete> {
+    client
+        .delete_vector_store_file(vector_store_id.into(), file_id.into())
+        .await
+}
+
+/// Attaches many uploaded files to a vector store at once.
+///
+/// Related OpenAI docs: [Create Vector Store File Batch](https://platform.openai.com/docs/api-reference/vector-stores-file-batches/createBatch)
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{Client, vector_store::{create_file_batch, CreateFileBatchParamBuilder}};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let param = CreateFileBatchParamBuilder::new(["file-abc123", "file-def456"]).build()?;
+///
+///     let resp = create_file_batch(&client, "vs_abc123", &param).await?;
+///     println!("{:?}", resp.file_counts);
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn create_file_batch(
+    client: &Client,
+    vector_store_id: impl Into<String>,
+    param: &CreateFileBatchParam,
+) -> Result<VectorStoreFileBatch> {
+    client
+        .create_vector_store_file_batch(vector_store_id.into(), param)
+        .await
+}
+
+/// Retrieves a batch of files of a vector store, along with its processing status.
+///
+/// Related OpenAI docs: [Retrieve Vector Store File Batch](https://platform.openai.com/docs/api-reference/vector-stores-file-batches/getBatch)
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{Client, vector_store::retrieve_file_batch};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let resp = retrieve_file_batch(&client, "vs_abc123", "vsfb_abc123").await?;
+///     println!("{:?}", resp.status);
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn retrieve_file_batch(
+    client: &Client,
+    vector_store_id: impl Into<String>,
+    batch_id: impl Into<String>,
+) -> Result<VectorStoreFileBatch> {
+    client
+        .retrieve_vector_store_file_batch(vector_store_id.into(), batch_id.into())
+        .await
+}
+
+/// Cancels a batch of files, stopping the processing of its files as soon as possible.
+///
+/// Related OpenAI docs: [Cancel Vector Store File Batch](https://platform.openai.com/docs/api-reference/vector-stores-file-batches/cancelBatch)
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{Client, vector_store::cancel_file_batch};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let resp = cancel_file_batch(&client, "vs_abc123", "vsfb_abc123").await?;
+///     println!("{:?}", resp.status);
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn cancel_file_batch(
+    client: &Client,
+    vector_store_id: impl Into<String>,
+    batch_id: impl Into<String>,
+) -> Result<VectorStoreFileBatch> {
+    client
+        .cancel_vector_store_file_batch(vector_store_id.into(), batch_id.into())
+        .await
+}
+
+/// Returns a paginated list of the files of a batch, along with their processing status.
+///
+/// Related OpenAI docs: [List Vector Store Files In A Batch](https://platform.openai.com/docs/api-reference/vector-stores-file-batches/listBatchFiles)
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{Client, vector_store::{list_batch_files, ListVectorStoreFilesParamBuilder}};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let param = ListVectorStoreFilesParamBuilder::default().build()?;
+///
+///     let resp = list_batch_files(&client, "vs_abc123", "vsfb_abc123", &param).await?;
+///     println!("{:#?}", resp.data);
+///
+///     Ok(())
+/// }
+/// ```
+pub async fn list_batch_files(
+    client: &Client,
+    vector_store_id: impl Into<String>,
+    batch_id: impl Into<String>,
+    param: &ListVectorStoreFilesParam,
+) -> Result<ListVectorStoreFiles> {
+    client
+        .list_vector_store_batch_files(vector_store_id.into(), batch_id.into(), param)
+        .await
+}
+
+impl Client {
+    async fn create_vector_store_file(
+        &self,
+        vector_store_id: String,
+        param: &CreateVectorStoreFileParam,
+    ) -> Result<VectorStoreFile> {
+        self.post::<CreateVectorStoreFileParam, VectorStoreFile>(
+            &format!("vector_stores/{vector_store_id}/files"),
+            Some(param),
+        )
+        .await
+    }
+
+    async fn list_vector_store_files(
+        &self,
+        vector_store_id: String,
+        param: &ListVectorStoreFilesParam,
+    ) -> Result<ListVectorStoreFiles> {
+        self.get::<ListVectorStoreFilesParam, ListVectorStoreFiles>(
+            &format!("vector_stores/{vector_store_id}/files"),
+            Some(param),
+        )
+        .await
+    }
+
+    async fn retrieve_vector_store_file(
+        &self,
+        vector_store_id: String,
+        file_id: String,
+    ) -> Result<VectorStoreFile> {
+        self.get::<(), VectorStoreFile>(
+            &format!("vector_stores/{vector_store_id}/files/{file_id}"),
+            None,
+        )
+        .await
+    }
+
+    async fn delete_vector_store_file(
+        &self,
+        vector_store_id: String,
+        file_id: String,
+    ) -> Result<Delete> {
+        self.delete::<(), Delete>(
+            &format!("vector_stores/{vector_store_id}/files/{file_id}"),
+            None,
+        )
+        .await
+    }
+
+    async fn create_vector_store_file_batch(
+        &self,
+        vector_store_id: String,
+        param: &CreateFileBatchParam,
+    ) -> Result<VectorStoreFileBatch> {
+        self.post::<CreateFileBatchParam, VectorStoreFileBatch>(
+            &format!("vector_stores/{vector_store_id}/file_batches"),
+            Some(param),
+        )
+        .await
+    }
+
+    async fn retrieve_vector_store_file_batch(
+        &self,
+        vector_store_id: String,
+        batch_id: String,
+    ) -> Result<VectorStoreFileBatch> {
+        self.get::<(), VectorStoreFileBatch>(
+            &format!("vector_stores/{vector_store_id}/file_batches/{batch_id}"),
+            None,
+        )
+        .await
+    }
+
+    async fn cancel_vector_store_file_batch(
+        &self,
+        vector_store_id: String,
+        batch_id: String,
+    ) -> Result<VectorStoreFileBatch> {
+        self.post::<(), VectorStoreFileBatch>(
+            &format!("vector_stores/{vector_store_id}/file_batches/{batch_id}/cancel"),
+            None,
+        )
+        .await
+    }
+
+    async fn list_vector_store_batch_files(
+        &self,
+        vector_store_id: String,
+        batch_id: String,
+        param: &ListVectorStoreFilesParam,
+    ) -> Result<ListVectorStoreFiles> {
+        self.get::<ListVectorStoreFilesParam, ListVectorStoreFiles>(
+            &format!("vector_stores/{vector_store_id}/file_batches/{batch_id}/files"),
+            Some(param),
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {}
@@ -0,0 +1,122 @@
+//! Export of embeddings into [Arrow](https://arrow.apache.org) record batches, for analytics workflows.
+//!
+//! A [`RecordBatch`] can be turned into a Polars `DataFrame`, queried with DataFusion or written to Parquet.
+//!
+//! Requires the `arrow` feature.
+
+use std::sync::Arc;
+
+use arrow_array::{
+    builder::{FixedSizeListBuilder, Float32Builder},
+    RecordBatch, StringArray,
+};
+use arrow_schema::{ArrowError, DataType, Field, Schema};
+
+use crate::Result;
+
+/// Builds a record batch with an `id`, `text` and `vector` column, one row per embedded text.
+///
+/// The vectors are stored as a fixed size list of floats, so they must all have the same dimensions.
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{Client, arrow::to_record_batch, embedding::{embed_many, EmbedManyOptionsBuilder}};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let texts = vec!["A cat.", "A car."];
+///     let opts = EmbedManyOptionsBuilder::new("text-embedding-3-small").build()?;
+///     let vectors = embed_many(&client, texts.clone(), &opts).await?;
+///
+///     let batch = to_record_batch(&["1", "2"], &texts, &vectors)?;
+///     println!("{:?}", batch.schema());
+///
+///     Ok(())
+/// }
+/// ```
+pub fn to_record_batch<I, T>(ids: &[I], texts: &[T], vectors: &[Vec<f32>]) -> Result<RecordBatch>
+where
+    I: AsRef<str>,
+    T: AsRef<str>,
+{
+    if ids.len() != texts.len() || ids.len() != vectors.len() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Expected the same number of ids, texts and vectors, got {}, {} and {}.",
+            ids.len(),
+            texts.len(),
+            vectors.len()
+        ))
+        .into());
+    }
+
+    let dimensions = vectors.first().map_or(0, Vec::len);
+    let mut builder = FixedSizeListBuilder::new(Float32Builder::new(), dimensions as i32);
+    for vector in vectors {
+        if vector.len() != dimensions {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Expected vectors of {dimensions} dimensions, got {}.",
+                vector.len()
+            ))
+            .into());
+        }
+
+        builder.values().append_slice(vector);
+        builder.append(true);
+    }
+
+    let schema = Schema::new(vec![
+        Field::new("id", DataType::Utf8, false),
+        Field::new("text", DataType::Utf8, false),
+        Field::new(
+            "vector",
+            DataType::FixedSizeList(
+                Arc::new(Field::new("item", DataType::Float32, true)),
+                dimensions as i32,
+            ),
+            false,
+        ),
+    ]);
+
+    Ok(RecordBatch::try_new(
+        Arc::new(schema),
+        vec![
+            Arc::new(StringArray::from_iter_values(ids)),
+            Arc::new(StringArray::from_iter_values(texts)),
+            Arc::new(builder.finish()),
+        ],
+    )?)
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow_array::{Array, FixedSizeListArray, Float32Array};
+
+    use super::*;
+
+    #[test]
+    fn test_to_record_batch() {
+        let batch = to_record_batch(
+            &["a", "b"],
+            &["A cat.", "A car."],
+            &[vec![0.1, 0.2], vec![0.3, 0.4]],
+        )
+        .unwrap();
+
+        assert_eq!(batch.num_rows(), 2);
+        assert_eq!(batch.schema().field(2).name(), "vector");
+
+        let vectors = batch
+            .column(2)
+            .as_any()
+            .downcast_ref::<FixedSizeListArray>()
+            .unwrap();
+        let second = vectors.value(1);
+        let second = second.as_any().downcast_ref::<Float32Array>().unwrap();
+        assert_eq!(second.values(), &[0.3, 0.4]);
+
+        assert!(to_record_batch(&["a"], &["A cat."], &[]).is_err());
+        assert!(to_record_batch(&["a", "b"], &["A", "B"], &[vec![0.1], vec![0.1, 0.2]]).is_err());
+    }
+}
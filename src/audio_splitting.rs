@@ -0,0 +1,381 @@
+//! Transcription of audio files over the 25MB upload limit, by splitting them into chunks transcribed concurrently.
+//!
+//! The chunks are cut at fixed windows, or at the quietest moment before each window ends so that words aren't cut in half.
+//! Their transcriptions are then stitched back together, with the timestamps of segments, words and captions shifted to the whole audio.
+//!
+//! Only WAV files can be split, other formats must be converted first, like with `ffmpeg -i audio.mp3 audio.wav`.
+//!
+//! Requires the `audio-splitting` feature.
+
+use std::{fs, io::Cursor, path::Path, time::Duration};
+
+use futures::{StreamExt, TryStreamExt};
+use hound::{Sample, WavReader, WavSpec, WavWriter};
+
+use crate::{
+    audio::{transcribe, Caption, Transcription, TranscriptionFormat, TranscriptionParam},
+    types::FileUpload,
+    Client, Result,
+};
+
+/// The upload limit of the transcription endpoint.
+const MAX_UPLOAD_BYTES: usize = 25 * 1024 * 1024;
+
+/// Room left for the header of each chunk.
+const HEADER_BYTES: usize = 1024;
+
+/// The duration of the blocks compared when looking for the quietest moment.
+const SILENCE_BLOCK: Duration = Duration::from_millis(10);
+
+/// Splits WAV audio into chunks small enough to be uploaded.
+///
+/// ## Example
+/// ```no_run
+/// use std::time::Duration;
+/// use fieri::{Client, audio::TranscriptionParamBuilder, audio_splitting::{transcribe_large, AudioSplitter}};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let param = TranscriptionParamBuilder::new("whisper-1").build()?;
+///     let splitter = AudioSplitter::default().on_silence(Duration::from_secs(5));
+///
+///     let resp = transcribe_large(&client, "path-to-podcast.wav", &param, &splitter, 4).await?;
+///     println!("{}", resp.text);
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct AudioSplitter {
+    max_bytes: usize,
+    silence: Option<Duration>,
+}
+
+impl Default for AudioSplitter {
+    /// Splits at fixed windows, into chunks of up to the 25MB upload limit.
+    fn default() -> Self {
+        Self::new(MAX_UPLOAD_BYTES)
+    }
+}
+
+impl AudioSplitter {
+    /// Splits into chunks of up to `max_bytes`, header included.
+    pub fn new(max_bytes: usize) -> Self {
+        Self {
+            max_bytes,
+            silence: None,
+        }
+    }
+
+    /// Cuts each chunk at the quietest moment within its last `window`, instead of at a fixed window.
+    pub fn on_silence(mut self, window: Duration) -> Self {
+        self.silence = Some(window);
+        self
+    }
+
+    /// Splits the WAV audio into chunks, each a WAV file of its own, in the order they appear in the audio.
+    pub fn split(&self, wav: &[u8]) -> Result<Vec<AudioChunk>> {
+        let reader = WavReader::new(Cursor::new(wav))?;
+        let spec = reader.spec();
+
+        match spec.sample_format {
+            hound::SampleFormat::Float => {
+                let samples = reader.into_samples::<f32>().collect::<hound::Result<_>>()?;
+                self.split_samples(spec, samples, f32::abs)
+            }
+            hound::SampleFormat::Int => {
+                let scale = (1_i64 << (spec.bits_per_sample - 1)) as f32;
+                let samples = reader.into_samples::<i32>().collect::<hound::Result<_>>()?;
+                self.split_samples(spec, samples, |sample| (sample as f32 / scale).abs())
+            }
+        }
+    }
+
+    fn split_samples<T, F>(
+        &self,
+        spec: WavSpec,
+        samples: Vec<T>,
+        amplitude: F,
+    ) -> Result<Vec<AudioChunk>>
+    where
+        T: Sample + Copy,
+        F: Fn(T) -> f32,
+    {
+        let channels = spec.channels.max(1) as usize;
+        let frame_bytes = channels * ((spec.bits_per_sample as usize + 7) / 8);
+        let rate = spec.sample_rate as f64;
+
+        let frames = samples.len() / channels;
+        let max_frames = (self.max_bytes.saturating_sub(HEADER_BYTES) / frame_bytes).max(1);
+
+        let mut chunks = Vec::new();
+        let mut start = 0;
+        while start < frames {
+            let mut end = (start + max_frames).min(frames);
+
+            if let Some(window) = self.silence.filter(|_| end < frames) {
+                let window = ((window.as_secs_f64() * rate) as usize).min(end - start - 1);
+                let block = ((SILENCE_BLOCK.as_secs_f64() * rate) as usize).max(1);
+
+                end = (end - window..end)
+                    .step_by(block)
+                    .min_by(|&a, &b| {
+                        let loudness = |from: usize| -> f32 {
+                            samples[from * channels..(from + block).min(end) * channels]
+                                .iter()
+                                .map(|&sample| amplitude(sample))
+                                .sum()
+                        };
+
+                        loudness(a).total_cmp(&loudness(b))
+                    })
+                    .map_or(end, |quietest| (quietest + block / 2).clamp(start + 1, end));
+            }
+
+            let mut data = Cursor::new(Vec::new());
+            let mut writer = WavWriter::new(&mut data, spec)?;
+            for &sample in &samples[start * channels..end * channels] {
+                writer.write_sample(sample)?;
+            }
+            writer.finalize()?;
+
+            chunks.push(AudioChunk {
+                offset: Duration::from_secs_f64(start as f64 / rate),
+                data: data.into_inner(),
+            });
+            start = end;
+        }
+
+        Ok(chunks)
+    }
+}
+
+/// A chunk of split audio, as a WAV file.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AudioChunk {
+    /// Where the chunk starts in the whole audio.
+    pub offset: Duration,
+
+    pub data: Vec<u8>,
+}
+
+/// Transcribes the audio file, splitting it into chunks transcribed concurrently if it's over the limit of the splitter.
+///
+/// Files within the limit are uploaded as they are, whatever their format.
+/// The transcriptions of the chunks are stitched together, see the [`module`](self) documentation.
+pub async fn transcribe_large(
+    client: &Client,
+    path: impl AsRef<Path>,
+    param: &TranscriptionParam,
+    splitter: &AudioSplitter,
+    concurrency: usize,
+) -> Result<Transcription> {
+    let path = path.as_ref();
+    if fs::metadata(path)?.len() as usize <= splitter.max_bytes {
+        return transcribe(client, path, param).await;
+    }
+
+    let chunks = splitter.split(&fs::read(path)?)?;
+    let transcriptions = futures::stream::iter(chunks.iter().enumerate())
+        .map(|(i, chunk)| {
+            let file = FileUpload::bytes(format!("chunk_{i}.wav"), chunk.data.clone());
+            transcribe(client, file, param)
+        })
+        .buffered(concurrency.max(1))
+        .try_collect::<Vec<_>>()
+        .await?;
+
+    let offsets = chunks.iter().map(|chunk| chunk.offset);
+    Ok(stitch(
+        offsets.zip(transcriptions).collect(),
+        param.response_format.unwrap_or_default(),
+    ))
+}
+
+/// Stitches the transcriptions of consecutive chunks starting at the given offsets.
+fn stitch(parts: Vec<(Duration, Transcription)>, format: TranscriptionFormat) -> Transcription {
+    let mut stitched = Transcription::default();
+    let mut texts = Vec::new();
+
+    for (offset, part) in parts {
+        let seconds = offset.as_secs_f64();
+        texts.push(part.text.trim().to_string());
+
+        stitched.language = stitched.language.or(part.language);
+        stitched.duration = part.duration.map(|duration| seconds + duration);
+
+        if let Some(segments) = part.segments {
+            let stitched = stitched.segments.get_or_insert_with(Vec::new);
+            for mut segment in segments {
+                segment.id = stitched.len() as u32;
+                segment.start += seconds;
+                segment.end += seconds;
+                stitched.push(segment);
+            }
+        }
+
+        if let Some(words) = part.words {
+            let stitched = stitched.words.get_or_insert_with(Vec::new);
+            stitched.extend(words.into_iter().map(|mut word| {
+                word.start += seconds;
+                word.end += seconds;
+                word
+            }));
+        }
+
+        if let Some(captions) = part.captions {
+            let stitched = stitched.captions.get_or_insert_with(Vec::new);
+            for caption in captions {
+                stitched.push(Caption {
+                    index: stitched.len() as u32 + 1,
+                    start: caption.start + offset,
+                    end: caption.end + offset,
+                    text: caption.text,
+                });
+            }
+        }
+    }
+
+    stitched.text = match (&stitched.captions, format) {
+        (Some(captions), TranscriptionFormat::Srt) => render(captions, ','),
+        (Some(captions), TranscriptionFormat::Vtt) => {
+            format!("WEBVTT\n\n{}", render(captions, '.'))
+        }
+        _ => texts.join(" "),
+    };
+
+    stitched
+}
+
+/// Renders the captions as SRT or WebVTT cues, depending on the separator of the milliseconds.
+fn render(captions: &[Caption], separator: char) -> String {
+    let timestamp = |time: Duration| {
+        let seconds = time.as_secs();
+        format!(
+            "{:02}:{:02}:{:02}{separator}{:03}",
+            seconds / 3600,
+            seconds / 60 % 60,
+            seconds % 60,
+            time.subsec_millis()
+        )
+    };
+
+    captions
+        .iter()
+        .map(|caption| {
+            format!(
+                "{}\n{} --> {}\n{}\n\n",
+                caption.index,
+                timestamp(caption.start),
+                timestamp(caption.end),
+                caption.text
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audio::TranscriptionSegment;
+
+    /// One second of a loud 16-bit mono signal at 1kHz, silent between 600 and 700ms.
+    fn wav() -> Vec<u8> {
+        let spec = WavSpec {
+            channels: 1,
+            sample_rate: 1000,
+            bits_per_sample: 16,
+            sample_format: hound::SampleFormat::Int,
+        };
+
+        let mut data = Cursor::new(Vec::new());
+        let mut writer = WavWriter::new(&mut data, spec).unwrap();
+        for i in 0..1000 {
+            let sample = if (600..700).contains(&i) { 0 } else { 10_000 };
+            writer.write_sample(sample as i16).unwrap();
+        }
+        writer.finalize().unwrap();
+
+        data.into_inner()
+    }
+
+    #[test]
+    fn test_split_fixed_windows() {
+        // Room for 400 frames of 2 bytes each.
+        let chunks = AudioSplitter::new(HEADER_BYTES + 800)
+            .split(&wav())
+            .unwrap();
+
+        let offsets = chunks
+            .iter()
+            .map(|chunk| chunk.offset.as_millis())
+            .collect::<Vec<_>>();
+        assert_eq!(offsets, [0, 400, 800]);
+
+        let frames = |chunk: &AudioChunk| WavReader::new(Cursor::new(&chunk.data)).unwrap().len();
+        assert_eq!(frames(&chunks[0]), 400);
+        assert_eq!(frames(&chunks[2]), 200);
+    }
+
+    #[test]
+    fn test_split_on_silence() {
+        let chunks = AudioSplitter::new(HEADER_BYTES + 1600)
+            .on_silence(Duration::from_millis(300))
+            .split(&wav())
+            .unwrap();
+
+        assert_eq!(chunks.len(), 2);
+        let cut = chunks[1].offset.as_millis();
+        assert!((600..700).contains(&cut), "cut at {cut}ms");
+    }
+
+    #[test]
+    fn test_stitch() {
+        let part = |text: &str| Transcription {
+            text: text.into(),
+            language: Some("english".into()),
+            duration: Some(2.0),
+            segments: Some(vec![TranscriptionSegment {
+                start: 0.5,
+                end: 1.5,
+                text: text.into(),
+                ..Default::default()
+            }]),
+            captions: Some(vec![Caption {
+                index: 1,
+                start: Duration::from_millis(500),
+                end: Duration::from_millis(1500),
+                text: text.into(),
+            }]),
+            ..Default::default()
+        };
+
+        let stitched = stitch(
+            vec![
+                (Duration::ZERO, part(" Hello.")),
+                (Duration::from_secs(2), part("Bye. ")),
+            ],
+            TranscriptionFormat::Json,
+        );
+        assert_eq!(stitched.text, "Hello. Bye.");
+        assert_eq!(stitched.duration, Some(4.0));
+
+        let segments = stitched.segments.unwrap();
+        assert_eq!((segments[1].id, segments[1].start), (1, 2.5));
+
+        let stitched = stitch(
+            vec![
+                (Duration::ZERO, part("Hello.")),
+                (Duration::from_secs(3661), part("Bye.")),
+            ],
+            TranscriptionFormat::Srt,
+        );
+        assert_eq!(
+            stitched.text,
+            "1\n00:00:00,500 --> 00:00:01,500\nHello.\n\n2\n01:01:01,500 --> 01:01:02,500\nBye.\n\n"
+        );
+        assert_eq!(Caption::parse(&stitched.text), stitched.captions.unwrap());
+    }
+}
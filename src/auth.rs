@@ -0,0 +1,107 @@
+//! Providers of the API key, fetching it on each request instead of it being fixed when the [`Client`](crate::Client) is built,
+//! like from a secrets manager, for the key to be rotated without rebuilding the client.
+//!
+//! A [`KeyProvider`] set on the client authorizes its requests, taking precedence over the API key of the [`Config`](crate::config::Config).
+//! It's called before each request, retries aside, caching the key being up to it.
+//! [`FileKeyProvider`] reads the key from a file, like a secret mounted by Kubernetes.
+//!
+//! ## Example
+//! ```no_run
+//! use std::sync::Arc;
+//! use fieri::{auth::{KeyFuture, KeyProvider}, Client};
+//!
+//! #[derive(Debug)]
+//! struct Vault;
+//!
+//! impl KeyProvider for Vault {
+//!     fn get_key(&self) -> KeyFuture<'_> {
+//!         Box::pin(async move {
+//!             // Fetched from the secrets manager.
+//!             Ok("sk-...".to_string())
+//!         })
+//!     }
+//! }
+//!
+//! let client = Client::new().key_provider(Arc::new(Vault));
+//! ```
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::PathBuf;
+
+#[cfg(not(target_arch = "wasm32"))]
+use crate::Error;
+use crate::Result;
+
+/// The future of an API key, `Send` but on wasm, whose futures are bound to the JavaScript thread.
+#[cfg(not(target_arch = "wasm32"))]
+pub type KeyFuture<'a> = futures::future::BoxFuture<'a, Result<String>>;
+/// The future of an API key, `Send` but on wasm, whose futures are bound to the JavaScript thread.
+#[cfg(target_arch = "wasm32")]
+pub type KeyFuture<'a> = futures::future::LocalBoxFuture<'a, Result<String>>;
+
+/// Provides the API key authorizing the requests of the client.
+///
+/// Failing to provide one fails the request, with [`Error::KeyProviderError`](crate::Error::KeyProviderError) for instance.
+pub trait KeyProvider: std::fmt::Debug + Send + Sync {
+    fn get_key(&self) -> KeyFuture<'_>;
+}
+
+/// Reads the API key from a file on each request, its surrounding whitespace trimmed.
+///
+/// Unavailable on wasm, which has no file system.
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Clone, Debug)]
+pub struct FileKeyProvider {
+    path: PathBuf,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl FileKeyProvider {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl KeyProvider for FileKeyProvider {
+    fn get_key(&self) -> KeyFuture<'_> {
+        Box::pin(async move {
+            let key = tokio::fs::read_to_string(&self.path).await?;
+            let key = key.trim();
+            if key.is_empty() {
+                return Err(Error::KeyProviderError(format!(
+                    "The key file {} is empty.",
+                    self.path.display()
+                )));
+            }
+
+            Ok(key.to_string())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_file_key_provider() {
+        let path = std::env::temp_dir().join(format!("fieri-key-{}", std::process::id()));
+        let provider = FileKeyProvider::new(&path);
+
+        tokio::fs::write(&path, "sk-first\n").await.unwrap();
+        assert_eq!(provider.get_key().await.unwrap(), "sk-first");
+
+        tokio::fs::write(&path, "sk-rotated").await.unwrap();
+        assert_eq!(provider.get_key().await.unwrap(), "sk-rotated");
+
+        tokio::fs::write(&path, " \n").await.unwrap();
+        assert!(matches!(
+            provider.get_key().await,
+            Err(Error::KeyProviderError(_))
+        ));
+
+        tokio::fs::remove_file(&path).await.unwrap();
+        assert!(matches!(provider.get_key().await, Err(Error::FileError(_))));
+    }
+}
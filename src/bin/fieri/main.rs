@@ -4,14 +4,15 @@ use std::path::PathBuf;
 use clap::Parser;
 
 use fieri::{
-    chat::chat,
-    types::{ChatParam, ChatRole},
+    chat::{create, ChatParam, ChatRole},
     Client,
 };
 use rustyline::{error::ReadlineError, DefaultEditor};
 
 mod version;
 
+use version::{LONG_VERSION, SHORT_VERSION};
+
 fn history_path() -> PathBuf {
     let mut path = PathBuf::from(env::var("HOME").unwrap());
     path.push(format!(".{}_history", clap::crate_name!()));
@@ -26,7 +27,7 @@ enum Commands {
 
     Chat {
         #[clap(flatten)]
-        param: ChatParam,
+        param: Box<ChatParam>,
 
         #[clap(short, long, default_value = "user")]
         role: ChatRole,
@@ -37,7 +38,7 @@ enum Commands {
 }
 
 #[derive(Parser, Debug, Clone)]
-#[clap(author, version, about="OpenAI command-line interface.", long_about = None)]
+#[clap(author, version = SHORT_VERSION, long_version = LONG_VERSION, about="OpenAI command-line interface.", long_about = None)]
 struct Cli {
     #[clap(subcommand)]
     command: Commands,
@@ -100,9 +101,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             param.messages.iter_mut().for_each(|m| {
                 m.role = role;
             });
-            let param = ChatParam { ..param };
+            let param = ChatParam { ..*param };
             println!("{:#?}", param);
-            let resp = chat(&client, &param).await?;
+            let resp = create(&client, &param).await?;
             println!("{:#?}", resp);
             //println!("{:#?}", resp.choices[0].message.content);
         }
@@ -113,7 +114,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
 /*
 async fn run_chat(client: &Client, param: &ChatParam) -> Result<()> {
-    let resp = chat(client, param).await?;
+    let resp = create(client, param).await?;
     println!("{:#?}", resp);
     Ok(())
 }
@@ -5,7 +5,7 @@ use clap::Parser;
 
 use version::LONG_VERSION;
 use fieri::{
-    chat::{chat, ChatMessageBuilder, ChatParamBuilder},
+    chat::{create, ChatMessageBuilder, ChatParamBuilder},
     Client,
 };
 use rustyline::{error::ReadlineError, DefaultEditor};
@@ -0,0 +1,114 @@
+//! Blocking variants of the [`chat`](crate::chat) endpoints.
+
+use crate::{
+    blocking::Client,
+    chat::{Chat, ChatList, ChatParam, ListChatParam},
+    types::Delete,
+    Result,
+};
+
+/// Creates a model response for the given chat conversation, see [`chat::create`](crate::chat::create).
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{blocking::{Client, chat::create}, chat::{ChatMessage, ChatParamBuilder}};
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let param = ChatParamBuilder::new(
+///         "gpt-4o-mini",
+///         vec![
+///             ChatMessage::system("You are a helpful assistant."),
+///             ChatMessage::user("Hello!"),
+///         ],
+///     )
+///     .build()?;
+///
+///     let resp = create(&client, &param)?;
+///     println!("{:#?}", resp.choices[0].message.content);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn create(client: &Client, param: &ChatParam) -> Result<Chat> {
+    client.create_chat(param)
+}
+
+/// Lists the stored chat completions, see [`chat::list`](crate::chat::list).
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{blocking::{Client, chat::list}, chat::ListChatParamBuilder};
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let resp = list(&client, &ListChatParamBuilder::default().limit(10u32).build()?)?;
+///     println!("{:?}", resp);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn list(client: &Client, param: &ListChatParam) -> Result<ChatList> {
+    client.list_chats(param)
+}
+
+/// Retrieves a stored chat completion, see [`chat::retrieve`](crate::chat::retrieve).
+///
+/// ## Example
+/// ```no_run
+/// use fieri::blocking::{Client, chat::retrieve};
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let resp = retrieve(&client, "chatcmpl-abc123")?;
+///     println!("{:?}", resp);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn retrieve(client: &Client, completion_id: impl Into<String>) -> Result<Chat> {
+    client.retrieve_chat(completion_id.into())
+}
+
+/// Deletes a stored chat completion, see [`chat::delete`](crate::chat::delete).
+///
+/// ## Example
+/// ```no_run
+/// use fieri::blocking::{Client, chat::delete};
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let resp = delete(&client, "chatcmpl-abc123")?;
+///     println!("{}", resp.deleted);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn delete(client: &Client, completion_id: impl Into<String>) -> Result<Delete> {
+    client.delete_chat(completion_id.into())
+}
+
+impl Client {
+    fn create_chat(&self, param: &ChatParam) -> Result<Chat> {
+        self.post::<ChatParam, Chat>("chat/completions", Some(&param.for_model_family()))
+    }
+
+    fn list_chats(&self, param: &ListChatParam) -> Result<ChatList> {
+        self.get::<ListChatParam, ChatList>("chat/completions", Some(param))
+    }
+
+    fn retrieve_chat(&self, completion_id: String) -> Result<Chat> {
+        self.get::<(), Chat>(&format!("chat/completions/{completion_id}"), None)
+    }
+
+    fn delete_chat(&self, completion_id: String) -> Result<Delete> {
+        self.delete::<(), Delete>(&format!("chat/completions/{completion_id}"), None)
+    }
+}
+
+#[cfg(test)]
+mod tests {}
@@ -0,0 +1,39 @@
+//! Blocking variants of the [`completion`](crate::completion) endpoints.
+
+use crate::{
+    blocking::Client,
+    completion::{Completion, CompletionParam},
+    Result,
+};
+
+/// Creates a completion for the provided prompt and parameters, see [`completion::create`](crate::completion::create).
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{blocking::{Client, completion::create}, completion::CompletionParamBuilder};
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let param = CompletionParamBuilder::new("gpt-3.5-turbo-instruct")
+///         .prompt("Say this is a test")
+///         .build()?;
+///
+///     let resp = create(&client, &param)?;
+///     println!("{:#?}", resp);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn create(client: &Client, param: &CompletionParam) -> Result<Completion> {
+    client.create_completion(param)
+}
+
+impl Client {
+    fn create_completion(&self, param: &CompletionParam) -> Result<Completion> {
+        self.post::<CompletionParam, Completion>("completions", Some(param))
+    }
+}
+
+#[cfg(test)]
+mod tests {}
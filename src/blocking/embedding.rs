@@ -0,0 +1,37 @@
+//! Blocking variants of the [`embedding`](crate::embedding) endpoints.
+
+use crate::{
+    blocking::Client,
+    embedding::{Embedding, EmbeddingParam},
+    Result,
+};
+
+/// Creates an embedding vector representing the input text, see [`embedding::create`](crate::embedding::create).
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{blocking::{Client, embedding::create}, embedding::EmbeddingParamBuilder};
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let param = EmbeddingParamBuilder::new("text-embedding-3-small", "Hello world!").build()?;
+///
+///     let resp = create(&client, &param)?;
+///     println!("{:?}", resp);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn create(client: &Client, param: &EmbeddingParam) -> Result<Embedding> {
+    client.create_embeddings(param)
+}
+
+impl Client {
+    fn create_embeddings(&self, param: &EmbeddingParam) -> Result<Embedding> {
+        self.post::<EmbeddingParam, Embedding>("embeddings", Some(param))
+    }
+}
+
+#[cfg(test)]
+mod tests {}
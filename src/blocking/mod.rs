@@ -0,0 +1,287 @@
+//! A blocking client, for CLIs and scripts that don't run an async runtime, built on [`reqwest::blocking`].
+//!
+//! Requires the `blocking` feature. Only a subset of the endpoints of the async modules is covered: the chat, completion,
+//! embedding, model, moderation and responses ones, called without `.await`, their parameters and responses being the same types.
+//! The other JSON endpoints can be reached through [`Client::get`], [`Client::post`] and [`Client::delete`],
+//! while streaming and the multipart uploads, like of files, images and audio, are left to the async client.
+//!
+//! Like the async [`Client`](crate::Client), requests are authorized by the [`Config`] and retried per its [`RetryPolicy`],
+//! the rate limiter, metrics, transports and key providers of the async client aren't supported though.
+//!
+//! ## Example
+//! ```no_run
+//! use fieri::{blocking::{Client, chat::create}, chat::{ChatMessage, ChatParamBuilder}};
+//!
+//! fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let client = Client::new();
+//!
+//!     let param = ChatParamBuilder::new("gpt-4o-mini", vec![ChatMessage::user("Hello!")]).build()?;
+//!     let resp = create(&client, &param)?;
+//!     println!("{:#?}", resp.choices[0].message.content);
+//!
+//!     Ok(())
+//! }
+//! ```
+
+pub mod chat;
+pub mod completion;
+pub mod embedding;
+pub mod model;
+pub mod moderation;
+pub mod responses;
+
+use std::time::Duration;
+
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{
+    client::{parse, ResponseMeta},
+    config::{AzureConfig, Config, RetryPolicy},
+    error::RequestError,
+    Error, Result,
+};
+
+/// The blocking client used to interact with the OpenAI API.
+///
+/// Like [`reqwest::blocking::Client`], it must not be created nor used from within an async runtime.
+#[derive(Clone, Debug)]
+pub struct Client {
+    /// Configuration needed to authorize against the API.
+    config: Config,
+
+    /// The HTTP client that'll execute requests.
+    handler: reqwest::blocking::Client,
+}
+
+impl Default for Client {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Client {
+    /// Creates a new instance of the Client.
+    /// The API key is read from the `OPENAI_API_KEY` environment variable, the organization from `OPENAI_ORGANIZATION`
+    /// and the project from `OPENAI_PROJECT_ID`.
+    pub fn new() -> Self {
+        let api_key = std::env::var("OPENAI_API_KEY").unwrap_or_default();
+        let organization = std::env::var("OPENAI_ORGANIZATION").unwrap_or_default();
+        let project = std::env::var("OPENAI_PROJECT_ID").unwrap_or_default();
+
+        Self::with_config(
+            Config::new(api_key)
+                .organization(organization)
+                .project(project),
+        )
+    }
+
+    /// Creates a client configured by the given configuration.
+    pub fn with_config(config: Config) -> Self {
+        Self {
+            handler: handler(&config),
+            config,
+        }
+    }
+
+    /// Creates a client sending its requests through the given HTTP client, configured by the given configuration.
+    pub fn with_http_client(http: reqwest::blocking::Client, config: Config) -> Self {
+        Self {
+            config,
+            handler: http,
+        }
+    }
+
+    /// Explicitly specify the api key, instead of reading it from the `OPENAI_API_KEY` environment variable.
+    pub fn api_key<T: Into<String>>(mut self, api_key: T) -> Self {
+        self.config.api_key = api_key.into();
+
+        self
+    }
+
+    /// Explicitly specify the [Organization](https://platform.openai.com/docs/api-reference/requesting-organization) of the requests.
+    pub fn organization<T: Into<String>>(mut self, organization: T) -> Self {
+        self.config.organization = organization.into();
+
+        self
+    }
+
+    /// Explicitly specify the [Project](https://platform.openai.com/docs/api-reference/authentication) of the requests.
+    pub fn project<T: Into<String>>(mut self, project: T) -> Self {
+        self.config.project = project.into();
+
+        self
+    }
+
+    /// Sends the requests to the Azure OpenAI resource, authorized by its key, see [`AzureConfig`].
+    pub fn azure(mut self, azure: AzureConfig) -> Self {
+        self.config.azure = Some(azure);
+
+        self
+    }
+
+    /// Sets the timeout of each request, `None` waiting for the responses indefinitely.
+    pub fn timeout(mut self, timeout: impl Into<Option<Duration>>) -> Self {
+        self.config.timeout = timeout.into();
+
+        self
+    }
+
+    /// Sets how failed requests are retried, see [`RetryPolicy`].
+    pub fn retry(mut self, retry: RetryPolicy) -> Self {
+        self.config.retry = retry;
+
+        self
+    }
+
+    // Sends the request like the async client does, sleeping the thread between the attempts.
+    fn send(
+        &self,
+        mut request: reqwest::blocking::RequestBuilder,
+    ) -> Result<reqwest::blocking::Response> {
+        request = request.headers(self.config.request_headers());
+        if let Some(timeout) = self.config.timeout {
+            request = request.timeout(timeout);
+        }
+
+        let retry = &self.config.retry;
+        let mut attempt = 1;
+
+        loop {
+            let next = if attempt < retry.max_attempts {
+                request.try_clone()
+            } else {
+                None
+            };
+            let resp = request.send().map_err(Error::from);
+            let Some(next) = next else {
+                return resp;
+            };
+
+            let outcome = resp.as_ref().map(|resp| (resp.status(), resp.headers()));
+            let Some(delay) = retry.delay(attempt, outcome) else {
+                return resp;
+            };
+            log::debug!("Retrying request in {delay:?}, after {attempt} failed attempts");
+
+            std::thread::sleep(delay);
+            request = next;
+            attempt += 1;
+        }
+    }
+
+    // Parses the JSON body of the response, either the expected one or an error.
+    fn json<Y: DeserializeOwned>(&self, resp: reqwest::blocking::Response) -> Result<Y> {
+        let status = resp.status();
+        let meta = ResponseMeta::from_headers(resp.headers());
+        let body = resp.bytes()?;
+        if !status.is_success() {
+            return Err(Error::APIError(
+                RequestError::from_body(status, &body).meta(meta),
+            ));
+        }
+
+        parse(&body, self.config.compatible)
+    }
+
+    pub fn get<X, Y>(&self, identifier: &str, param: Option<&X>) -> Result<Y>
+    where
+        X: Serialize,
+        Y: DeserializeOwned,
+    {
+        let resp = self.send(
+            self.handler
+                .get(self.config.endpoint(identifier)?)
+                .query(&param),
+        )?;
+
+        self.json(resp)
+    }
+
+    pub fn post<X, Y>(&self, identifier: &str, param: Option<&X>) -> Result<Y>
+    where
+        X: Serialize,
+        Y: DeserializeOwned,
+    {
+        let resp = self.send(
+            self.handler
+                .post(self.config.endpoint(identifier)?)
+                .json(&param),
+        )?;
+
+        self.json(resp)
+    }
+
+    pub fn delete<X, Y>(&self, identifier: &str, param: Option<&X>) -> Result<Y>
+    where
+        X: Serialize,
+        Y: DeserializeOwned,
+    {
+        let resp = self.send(
+            self.handler
+                .delete(self.config.endpoint(identifier)?)
+                .query(&param),
+        )?;
+
+        self.json(resp)
+    }
+}
+
+// The HTTP client sending the requests, with the connect timeout and the proxy of the configuration.
+//
+// Its own timeout is disabled, each request being given the one of the configuration.
+fn handler(config: &Config) -> reqwest::blocking::Client {
+    let mut builder = reqwest::blocking::Client::builder().timeout(None);
+    if let Some(timeout) = config.connect_timeout {
+        builder = builder.connect_timeout(timeout);
+    }
+    if let Some(proxy) = &config.proxy {
+        builder = builder.proxy(proxy.clone());
+    }
+
+    builder.build().expect("Err creating request handler.")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        io::{Read, Write},
+        net::TcpListener,
+    };
+
+    const UNAVAILABLE: &str = "HTTP/1.1 503 Service Unavailable\r\ncontent-type: application/json\r\ncontent-length: 89\r\nconnection: close\r\n\r\n{\"error\": {\"message\": \"Overloaded\", \"type\": \"server_error\", \"param\": null, \"code\": null}}";
+    const MODELS: &str = "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: 46\r\nconnection: close\r\n\r\n{\"object\": \"list\", \"data\": [{\"id\": \"gpt-4o\"}]}";
+
+    #[test]
+    fn test_blocking_client() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/v1/", listener.local_addr().unwrap());
+        let server = std::thread::spawn(move || {
+            let mut requests = Vec::new();
+            for response in [UNAVAILABLE, MODELS] {
+                let (mut socket, _) = listener.accept().unwrap();
+                let mut buffer = [0; 4096];
+                let read = socket.read(&mut buffer).unwrap();
+                requests.push(String::from_utf8_lossy(&buffer[..read]).to_string());
+                socket.write_all(response.as_bytes()).unwrap();
+            }
+            requests
+        });
+
+        let mut config = Config::new("sk-test").retry(RetryPolicy {
+            initial_backoff: Duration::from_millis(1),
+            ..RetryPolicy::default()
+        });
+        config.url = url::Url::parse(&url).unwrap();
+        let client = Client::with_config(config);
+
+        let models = model::list(&client).unwrap();
+        assert_eq!(models.data[0].id, "gpt-4o");
+
+        let requests = server.join().unwrap();
+        assert!(requests[1].starts_with("GET /v1/models HTTP/1.1"));
+        assert!(requests[1]
+            .to_lowercase()
+            .contains("authorization: bearer sk-test"));
+    }
+}
@@ -0,0 +1,57 @@
+//! Blocking variants of the [`model`](crate::model) endpoints.
+
+use crate::{
+    blocking::Client,
+    model::{Model, Models},
+    Result,
+};
+
+/// Retrieves a model instance, see [`model::retrieve`](crate::model::retrieve).
+///
+/// ## Example
+/// ```no_run
+/// use fieri::blocking::{Client, model::retrieve};
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///     let resp = retrieve(&client, "gpt-4o-mini")?;
+///     println!("{:?}", resp.owned_by);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn retrieve(client: &Client, model: impl Into<String>) -> Result<Model> {
+    client.retrieve(model.into())
+}
+
+/// Lists the currently available models, see [`model::list`](crate::model::list).
+///
+/// ## Example
+/// ```no_run
+/// use fieri::blocking::{Client, model::list};
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///     for model in list(&client)?.data {
+///         println!("{}", model.id);
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+pub fn list(client: &Client) -> Result<Models> {
+    client.list()
+}
+
+impl Client {
+    fn retrieve(&self, model: String) -> Result<Model> {
+        self.get::<(), Model>(&format!("models/{model}"), None)
+    }
+
+    fn list(&self) -> Result<Models> {
+        self.get::<(), Models>("models", None)
+    }
+}
+
+#[cfg(test)]
+mod tests {}
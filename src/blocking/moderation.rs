@@ -0,0 +1,37 @@
+//! Blocking variants of the [`moderation`](crate::moderation) endpoints.
+
+use crate::{
+    blocking::Client,
+    moderation::{Moderation, ModerationParam},
+    Result,
+};
+
+/// Classifies if text violates OpenAI's Content Policy, see [`moderation::create`](crate::moderation::create).
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{blocking::{Client, moderation::create}, moderation::ModerationParamBuilder};
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let param = ModerationParamBuilder::new("I want to kill them.").build()?;
+///
+///     let resp = create(&client, &param)?;
+///     println!("{}", resp.any_flagged());
+///
+///     Ok(())
+/// }
+/// ```
+pub fn create(client: &Client, param: &ModerationParam) -> Result<Moderation> {
+    client.create_moderation(param)
+}
+
+impl Client {
+    fn create_moderation(&self, param: &ModerationParam) -> Result<Moderation> {
+        self.post::<ModerationParam, Moderation>("moderations", Some(param))
+    }
+}
+
+#[cfg(test)]
+mod tests {}
@@ -0,0 +1,84 @@
+//! Blocking variants of the [`responses`](crate::responses) endpoints.
+
+use crate::{
+    blocking::Client,
+    responses::{Delete, Response, ResponseParam},
+    Result,
+};
+
+/// Creates a response of the model to the given input, see [`responses::create`](crate::responses::create).
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{blocking::{Client, responses::create}, responses::ResponseParamBuilder};
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let param = ResponseParamBuilder::new("gpt-4o", "Tell me a three sentence bedtime story about a unicorn.")
+///         .build()?;
+///
+///     let resp = create(&client, &param)?;
+///     println!("{}", resp.output_text());
+///
+///     Ok(())
+/// }
+/// ```
+pub fn create(client: &Client, param: &ResponseParam) -> Result<Response> {
+    client.create_response(param)
+}
+
+/// Retrieves a stored response, see [`responses::retrieve`](crate::responses::retrieve).
+///
+/// ## Example
+/// ```no_run
+/// use fieri::blocking::{Client, responses::retrieve};
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let resp = retrieve(&client, "resp_abc123")?;
+///     println!("{}", resp.output_text());
+///
+///     Ok(())
+/// }
+/// ```
+pub fn retrieve(client: &Client, response_id: impl Into<String>) -> Result<Response> {
+    client.retrieve_response(response_id.into())
+}
+
+/// Deletes a stored response, see [`responses::delete`](crate::responses::delete).
+///
+/// ## Example
+/// ```no_run
+/// use fieri::blocking::{Client, responses::delete};
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let resp = delete(&client, "resp_abc123")?;
+///     println!("{}", resp.deleted);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn delete(client: &Client, response_id: impl Into<String>) -> Result<Delete> {
+    client.delete_response(response_id.into())
+}
+
+impl Client {
+    fn create_response(&self, param: &ResponseParam) -> Result<Response> {
+        self.post::<ResponseParam, Response>("responses", Some(param))
+    }
+
+    fn retrieve_response(&self, response_id: String) -> Result<Response> {
+        self.get::<(), Response>(&format!("responses/{response_id}"), None)
+    }
+
+    fn delete_response(&self, response_id: String) -> Result<Delete> {
+        self.delete::<(), Delete>(&format!("responses/{response_id}"), None)
+    }
+}
+
+#[cfg(test)]
+mod tests {}
@@ -0,0 +1,208 @@
+//! Splitting of long documents into overlapping chunks by token count, like before embedding them.
+//!
+//! Chunks end at sentence boundaries whenever possible, only sentences longer than a whole chunk are split mid-sentence,
+//! on character boundaries. The tokens of each chunk are counted as a whole, joined sentences not always adding up
+//! to the tokens of each.
+//!
+//! Requires the `tokenizer` feature.
+
+use crate::{tokenizer::Tokenizer, Result};
+
+/// Splits text into chunks of at most `max_tokens` tokens, each starting with up to `overlap` tokens of the previous one.
+///
+/// ## Example
+/// ```no_run
+/// use fieri::chunking::Chunker;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let chunker = Chunker::new("text-embedding-3-small", 512, 64)?;
+///
+///     for chunk in chunker.chunk(&std::fs::read_to_string("book.txt")?) {
+///         println!("{chunk}");
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Chunker {
+    tokenizer: Tokenizer,
+    max_tokens: usize,
+    overlap: usize,
+}
+
+impl Chunker {
+    /// Creates a chunker counting tokens like the given model.
+    ///
+    /// The overlap is capped below `max_tokens`, so that each chunk makes progress through the text.
+    pub fn new(model: &str, max_tokens: usize, overlap: usize) -> Result<Self> {
+        let max_tokens = max_tokens.max(1);
+
+        Ok(Self {
+            tokenizer: Tokenizer::for_model(model)?,
+            max_tokens,
+            overlap: overlap.min(max_tokens - 1),
+        })
+    }
+
+    /// Splits the text into chunks, in the order they appear in the text.
+    pub fn chunk(&self, text: &str) -> Vec<String> {
+        let mut chunks = Vec::new();
+        // The sentences of the current chunk.
+        let mut current: Vec<&str> = Vec::new();
+
+        for sentence in sentences(text) {
+            for piece in self.split_long(sentence) {
+                current.push(piece);
+
+                if current.len() > 1 && self.count(&current) > self.max_tokens {
+                    current.pop();
+                    chunks.push(join(&current));
+                    current = self.overlapping(&current, piece);
+                    current.push(piece);
+                }
+            }
+        }
+
+        if !current.is_empty() {
+            chunks.push(join(&current));
+        }
+
+        chunks.retain(|chunk| !chunk.is_empty());
+        chunks
+    }
+
+    /// Splits a sentence longer than a chunk into pieces of at most `max_tokens` tokens, on character boundaries.
+    ///
+    /// Each piece is the longest run of characters within the limit, of at least one character to make progress.
+    fn split_long<'a>(&self, sentence: &'a str) -> Vec<&'a str> {
+        let mut pieces = Vec::new();
+        let mut rest = sentence;
+
+        while self.tokenizer.count(rest) > self.max_tokens {
+            let ends = rest
+                .char_indices()
+                .skip(1)
+                .map(|(i, _)| i)
+                .chain([rest.len()])
+                .collect::<Vec<_>>();
+            // The ends past which the piece exceeds the limit, the token count growing along with the characters.
+            let fitting =
+                ends.partition_point(|&end| self.tokenizer.count(&rest[..end]) <= self.max_tokens);
+            let end = ends[fitting.saturating_sub(1)];
+
+            pieces.push(&rest[..end]);
+            rest = &rest[end..];
+        }
+
+        if !rest.is_empty() {
+            pieces.push(rest);
+        }
+        pieces
+    }
+
+    /// The trailing sentences of the previous chunk to repeat in the next one, leaving room for the next sentence.
+    fn overlapping<'a>(&self, previous: &[&'a str], next: &'a str) -> Vec<&'a str> {
+        let mut start = previous.len();
+
+        while start > 0 {
+            let kept = &previous[start - 1..];
+            let chunk = [kept, &[next]].concat();
+            if self.count(kept) > self.overlap || self.count(&chunk) > self.max_tokens {
+                break;
+            }
+
+            start -= 1;
+        }
+
+        previous[start..].to_vec()
+    }
+
+    /// The number of tokens of the sentences once joined into a chunk.
+    fn count(&self, sentences: &[&str]) -> usize {
+        self.tokenizer.count(&join(sentences))
+    }
+}
+
+/// Splits text after each sentence terminator or newline, keeping the trailing whitespace with the sentence.
+fn sentences(text: &str) -> Vec<&str> {
+    let mut sentences = Vec::new();
+    let mut start = 0;
+    let mut chars = text.char_indices().peekable();
+
+    while let Some((_, c)) = chars.next() {
+        let boundary = match c {
+            '\n' => true,
+            '.' | '!' | '?' => chars.peek().map_or(true, |&(_, next)| next.is_whitespace()),
+            _ => false,
+        };
+
+        if boundary {
+            while let Some(&(_, next)) = chars.peek() {
+                if !next.is_whitespace() {
+                    break;
+                }
+                chars.next();
+            }
+
+            let end = chars.peek().map_or(text.len(), |&(i, _)| i);
+            sentences.push(&text[start..end]);
+            start = end;
+        }
+    }
+
+    if start < text.len() {
+        sentences.push(&text[start..]);
+    }
+
+    sentences
+}
+
+fn join(sentences: &[&str]) -> String {
+    sentences.concat().trim().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chunking() {
+        assert_eq!(
+            sentences("One. Two? Three 3.5!\nFour"),
+            ["One. ", "Two? ", "Three 3.5!\n", "Four"]
+        );
+
+        // The first two sentences are 8 and 5 tokens long along with their trailing space, but 11 once joined.
+        let chunker = Chunker::new("text-embedding-3-small", 12, 6).unwrap();
+        let text = "The cat sat on the mat. It was happy. The dog barked. Then it left.";
+        assert_eq!(
+            chunker.chunk(text),
+            [
+                "The cat sat on the mat. It was happy.",
+                "It was happy. The dog barked.",
+                "The dog barked. Then it left."
+            ]
+        );
+
+        let chunks = chunker.chunk(&"word ".repeat(20));
+        assert_eq!(chunks.len(), 2);
+        assert!(chunks
+            .iter()
+            .all(|chunk| chunker.tokenizer.count(chunk) <= 12));
+
+        // Characters spanning several tokens are never split.
+        let chunker = Chunker::new("text-embedding-3-small", 3, 0).unwrap();
+        let text = "🦀🦀🦀🦀héllo wörld";
+        let pieces = chunker.split_long(text);
+        assert!(pieces.len() > 1);
+        assert_eq!(pieces.concat(), text);
+        assert!(pieces
+            .iter()
+            .all(|piece| chunker.tokenizer.count(piece) <= 3));
+
+        // A single character exceeding the limit makes a piece on its own.
+        let chunker = Chunker::new("text-embedding-3-small", 1, 0).unwrap();
+        assert_eq!(chunker.split_long("🦀🦀"), ["🦀", "🦀"]);
+    }
+}
@@ -1,5 +1,7 @@
 //! A composite error type for errors that can occur while interacting with OpenAI.
 
+use crate::client::ResponseMeta;
+
 /// A set of errors that can occur during interaction with OpenAI.
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
@@ -18,12 +20,81 @@ pub enum Error {
     #[error("{0}")]
     UrlError(#[from] url::ParseError),
 
+    #[error("Invalid header value. {0}")]
+    HeaderError(#[from] reqwest::header::InvalidHeaderValue),
+
     #[error("{0}")]
     SerdeError(#[from] serde_json::Error),
 
+    #[error("{0}")]
+    Base64Error(#[from] base64::DecodeError),
+
+    #[error("The image was returned as a URL, to be downloaded instead.")]
+    ImageUrlError,
+
+    #[error("{0}")]
+    TokenizerError(String),
+
+    #[error("Unable to get the API key. {0}")]
+    KeyProviderError(String),
+
+    #[cfg(feature = "ndarray")]
+    #[error("{0}")]
+    ShapeError(#[from] ndarray::ShapeError),
+
+    #[cfg(feature = "arrow")]
+    #[error("{0}")]
+    ArrowError(#[from] arrow_schema::ArrowError),
+
+    #[cfg(feature = "image")]
+    #[error("{0}")]
+    ImageError(#[from] image::ImageError),
+
+    #[cfg(feature = "audio-splitting")]
+    #[error("{0}")]
+    WavError(#[from] hound::Error),
+
+    // Boxed, as the WebSocket errors are much larger than the others.
+    #[cfg(feature = "realtime")]
+    #[error("{0}")]
+    WebSocketError(#[from] Box<tokio_tungstenite::tungstenite::Error>),
+
     #[error("{0}")]
     FieldError(#[from] derive_builder::UninitializedFieldError),
 
+    #[error("The model refused to respond. {0}")]
+    RefusalError(String),
+
+    #[error("Invalid training example {example}. {source}")]
+    TrainingExampleError {
+        example: usize,
+        source: crate::training::TrainingExampleError,
+    },
+
+    #[error("Invalid batch request {custom_id}. {source}")]
+    BatchRequestError {
+        custom_id: String,
+        source: crate::batch::BatchRequestError,
+    },
+
+    #[error("The rate limit of {model} is exhausted, retry in {retry_after:?}.")]
+    RateLimitExceeded {
+        model: String,
+        retry_after: std::time::Duration,
+    },
+
+    #[error("Unexpected embeddings returned. {0}")]
+    EmbeddingMismatch(String),
+
+    #[error("Expected a vector of {expected} dimensions, got {received}.")]
+    DimensionMismatch { expected: usize, received: usize },
+
+    #[error("Unable to parse the output of the model. {source}. Output: {content}")]
+    OutputParseError {
+        source: serde_json::Error,
+        content: String,
+    },
+
     #[error("Invalid values provided. {0}")]
     CompletionParamBuilderError(#[from] crate::types::CompletionParamBuilderError),
 
@@ -33,6 +104,9 @@ pub enum Error {
     #[error("Invalid values provided. {0}")]
     EmbeddingParamBuilderError(#[from] crate::types::EmbeddingParamBuilderError),
 
+    #[error("Invalid values provided. {0}")]
+    EmbedManyOptionsBuilderError(#[from] crate::types::EmbedManyOptionsBuilderError),
+
     #[error("Invalid values provided. {0}")]
     FineTuneParamBuilderError(#[from] crate::types::CreateFineTuneParamBuilderError),
 
@@ -42,17 +116,163 @@ pub enum Error {
     #[error("Invalid values provided. {0}")]
     GenerateImageParamBuilderError(#[from] crate::types::GenerateImageParamBuilderError),
 
+    #[error("Invalid values provided. {0}")]
+    EditImageParamBuilderError(#[from] crate::types::EditImageParamBuilderError),
+
+    #[error("Invalid values provided. {0}")]
+    VariateImageParamBuilderError(#[from] crate::types::VariateImageParamBuilderError),
+
     #[error("Invalid values provided. {0}")]
     ChatParamBuilderError(#[from] crate::types::ChatParamBuilderError),
 
     #[error("Invalid values provided. {0}")]
     ChatMessageBuilderError(#[from] crate::types::ChatMessageBuilderError),
+
+    #[error("Invalid values provided. {0}")]
+    ListChatParamBuilderError(#[from] crate::types::ListChatParamBuilderError),
+
+    #[error("Invalid values provided. {0}")]
+    FunctionSpecBuilderError(#[from] crate::types::FunctionSpecBuilderError),
+
+    #[error("Invalid values provided. {0}")]
+    TranscriptionParamBuilderError(#[from] crate::types::TranscriptionParamBuilderError),
+
+    #[error("Invalid values provided. {0}")]
+    TranslationParamBuilderError(#[from] crate::types::TranslationParamBuilderError),
+
+    #[error("Invalid values provided. {0}")]
+    SpeechParamBuilderError(#[from] crate::types::SpeechParamBuilderError),
+
+    #[error("Invalid values provided. {0}")]
+    ListFilesParamBuilderError(#[from] crate::types::ListFilesParamBuilderError),
+
+    #[error("Invalid values provided. {0}")]
+    CreateUploadParamBuilderError(#[from] crate::types::CreateUploadParamBuilderError),
+
+    #[error("Invalid values provided. {0}")]
+    AssistantParamBuilderError(#[from] crate::types::AssistantParamBuilderError),
+
+    #[error("Invalid values provided. {0}")]
+    ListParamBuilderError(#[from] crate::types::ListParamBuilderError),
+
+    #[error("Invalid values provided. {0}")]
+    ThreadParamBuilderError(#[from] crate::types::ThreadParamBuilderError),
+
+    #[error("Invalid values provided. {0}")]
+    CreateMessageParamBuilderError(#[from] crate::types::CreateMessageParamBuilderError),
+
+    #[error("Invalid values provided. {0}")]
+    ListMessagesParamBuilderError(#[from] crate::types::ListMessagesParamBuilderError),
+
+    #[error("Invalid values provided. {0}")]
+    RunParamBuilderError(#[from] crate::types::RunParamBuilderError),
+
+    #[error("Invalid values provided. {0}")]
+    PollOptionsBuilderError(#[from] crate::types::PollOptionsBuilderError),
+
+    #[error("Invalid values provided. {0}")]
+    CreateVectorStoreFileParamBuilderError(
+        #[from] crate::types::CreateVectorStoreFileParamBuilderError,
+    ),
+
+    #[error("Invalid values provided. {0}")]
+    ListVectorStoreFilesParamBuilderError(
+        #[from] crate::types::ListVectorStoreFilesParamBuilderError,
+    ),
+
+    #[error("Invalid values provided. {0}")]
+    CreateFileBatchParamBuilderError(#[from] crate::types::CreateFileBatchParamBuilderError),
+
+    #[error("Invalid values provided. {0}")]
+    ResponseParamBuilderError(#[from] crate::types::ResponseParamBuilderError),
+
+    #[error("Invalid values provided. {0}")]
+    CreateBatchParamBuilderError(#[from] crate::types::CreateBatchParamBuilderError),
+
+    #[error("Invalid values provided. {0}")]
+    ListBatchesParamBuilderError(#[from] crate::types::ListBatchesParamBuilderError),
+
+    #[error("Invalid values provided. {0}")]
+    CreateFineTuningJobParamBuilderError(
+        #[from] crate::types::CreateFineTuningJobParamBuilderError,
+    ),
+
+    #[error("Invalid values provided. {0}")]
+    ListFineTuningJobsParamBuilderError(#[from] crate::types::ListFineTuningJobsParamBuilderError),
+
+    #[error("Invalid values provided. {0}")]
+    ListFineTuningJobEventsParamBuilderError(
+        #[from] crate::types::ListFineTuningJobEventsParamBuilderError,
+    ),
+}
+
+impl Error {
+    /// The `x-request-id` of the response the error was returned with, if any.
+    pub fn request_id(&self) -> Option<&str> {
+        match self {
+            Self::APIError(err) => err.meta.request_id.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// The code of the error returned by the API, if any, see [`ApiErrorCode`].
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use fieri::{chat::{create, ChatMessage, ChatParamBuilder}, error::ApiErrorCode, Client, Error};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Error> {
+    ///     let client = Client::new();
+    ///     let param = ChatParamBuilder::new("gpt-4o", vec![ChatMessage::user("Hello!")]).build()?;
+    ///
+    ///     match create(&client, &param).await {
+    ///         Ok(resp) => println!("{:#?}", resp.choices[0].message.content),
+    ///         Err(err) if err.error_code() == Some(ApiErrorCode::InsufficientQuota) => println!("Out of credits."),
+    ///         Err(err) => return Err(err),
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn error_code(&self) -> Option<ApiErrorCode> {
+        match self {
+            Self::APIError(err) => err.error.error_code(),
+            _ => None,
+        }
+    }
+
+    // Whether the request failed without reaching the API or timed out, or the API was overloaded or rate limited,
+    // for the request to be worth sending again.
+    pub(crate) fn is_transient(&self) -> bool {
+        match self {
+            Self::Reqwest(err) => crate::client::is_transient(err),
+            Self::APIError(err) => {
+                err.error.r#type == "server_error"
+                    || err.error.error_code() == Some(ApiErrorCode::RateLimitExceeded)
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(feature = "realtime")]
+impl From<tokio_tungstenite::tungstenite::Error> for Error {
+    fn from(err: tokio_tungstenite::tungstenite::Error) -> Self {
+        Self::WebSocketError(Box::new(err))
+    }
 }
 
 /// Possible Errors returned by responses from OpenAI.
 #[derive(Clone, Debug, serde::Deserialize)]
 pub struct RequestError {
     pub error: ErrorMessage,
+
+    /// The identifiers of the response, like its `x-request-id`, empty for the errors of streamed events.
+    ///
+    /// Boxed, not to grow all the results of the crate.
+    #[serde(skip)]
+    pub meta: Box<ResponseMeta>,
 }
 
 #[derive(Clone, Debug, std::default::Default, serde::Deserialize)]
@@ -64,3 +284,249 @@ pub struct ErrorMessage {
     pub param: serde_json::Value,
     pub code: serde_json::Value,
 }
+
+impl ErrorMessage {
+    /// The [`code`](ErrorMessage::code) of the error, or its type for the errors whose type is a known code,
+    /// like `insufficient_quota`. `None` when the API returned neither.
+    pub fn error_code(&self) -> Option<ApiErrorCode> {
+        if let Some(code) = self.code.as_str().filter(|code| !code.is_empty()) {
+            return Some(ApiErrorCode::from(code));
+        }
+
+        match ApiErrorCode::from(self.r#type.as_str()) {
+            ApiErrorCode::Other(_) => None,
+            code => Some(code),
+        }
+    }
+}
+
+/// The codes of the [errors](https://platform.openai.com/docs/guides/error-codes) returned by the API,
+/// the ones callers most often handle being named, the others kept as is.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ApiErrorCode {
+    /// The API key is wrong or revoked.
+    InvalidApiKey,
+
+    /// The credits of the account are exhausted, or its monthly budget reached.
+    InsufficientQuota,
+
+    /// The hard limit of the billing of the account is reached.
+    BillingHardLimitReached,
+
+    /// Too many requests or tokens were sent in a given time, to be retried later.
+    RateLimitExceeded,
+
+    /// The messages and the tokens to generate don't fit in the context window of the model.
+    ContextLengthExceeded,
+
+    /// The model doesn't exist, or the account has no access to it.
+    ModelNotFound,
+
+    /// The prompt or the generated content was rejected by the safety system.
+    ContentPolicyViolation,
+
+    /// The API isn't available in the country the request was sent from.
+    UnsupportedCountryRegionTerritory,
+
+    /// Any other code.
+    Other(String),
+}
+
+impl ApiErrorCode {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::InvalidApiKey => "invalid_api_key",
+            Self::InsufficientQuota => "insufficient_quota",
+            Self::BillingHardLimitReached => "billing_hard_limit_reached",
+            Self::RateLimitExceeded => "rate_limit_exceeded",
+            Self::ContextLengthExceeded => "context_length_exceeded",
+            Self::ModelNotFound => "model_not_found",
+            Self::ContentPolicyViolation => "content_policy_violation",
+            Self::UnsupportedCountryRegionTerritory => "unsupported_country_region_territory",
+            Self::Other(code) => code,
+        }
+    }
+}
+
+impl From<&str> for ApiErrorCode {
+    fn from(code: &str) -> Self {
+        match code {
+            "invalid_api_key" => Self::InvalidApiKey,
+            "insufficient_quota" => Self::InsufficientQuota,
+            "billing_hard_limit_reached" => Self::BillingHardLimitReached,
+            "rate_limit_exceeded" => Self::RateLimitExceeded,
+            "context_length_exceeded" => Self::ContextLengthExceeded,
+            "model_not_found" => Self::ModelNotFound,
+            "content_policy_violation" => Self::ContentPolicyViolation,
+            "unsupported_country_region_territory" => Self::UnsupportedCountryRegionTerritory,
+            code => Self::Other(code.to_string()),
+        }
+    }
+}
+
+impl std::fmt::Display for ApiErrorCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl RequestError {
+    // Parses the body of a failed response, like the API's `{"error": {...}}`, or the ones of OpenAI-compatible servers,
+    // like `{"error": "..."}`, `{"message": "...", "type": "..."}`, `{"detail": "..."}` or plain text.
+    //
+    // The type of the errors lacking one is derived from the status.
+    pub(crate) fn from_body(status: reqwest::StatusCode, body: &[u8]) -> Self {
+        match serde_json::from_slice::<serde_json::Value>(body) {
+            Ok(value) if value.is_object() || value.is_string() => {
+                Self::from_value(value, Some(status))
+            }
+            _ => Self::from_value(
+                serde_json::Value::String(String::from_utf8_lossy(body).trim().to_string()),
+                Some(status),
+            ),
+        }
+    }
+
+    // Extracts the error out of its JSON, either wrapped in an `error` field or not.
+    pub(crate) fn from_value(
+        mut error: serde_json::Value,
+        status: Option<reqwest::StatusCode>,
+    ) -> Self {
+        if let Some(inner) = error.get_mut("error") {
+            error = inner.take();
+        }
+        if !error.is_object() {
+            error = serde_json::json!({ "message": error });
+        }
+
+        let mut field = |name: &str| {
+            error
+                .get_mut(name)
+                .map(serde_json::Value::take)
+                .unwrap_or_default()
+        };
+        let text = |value: serde_json::Value| match value {
+            serde_json::Value::String(text) => text,
+            serde_json::Value::Null => String::new(),
+            value => value.to_string(),
+        };
+
+        let mut message = text(field("message"));
+        if message.is_empty() {
+            message = text(field("detail"));
+        }
+        if message.is_empty() {
+            message = status
+                .and_then(|status| status.canonical_reason())
+                .unwrap_or_default()
+                .to_string();
+        }
+
+        let mut r#type = text(field("type"));
+        if r#type.is_empty() {
+            r#type = match status {
+                Some(status) if status.is_server_error() => "server_error",
+                Some(status) if status.is_client_error() => "invalid_request_error",
+                _ => "",
+            }
+            .to_string();
+        }
+
+        Self {
+            error: ErrorMessage {
+                message,
+                r#type,
+                param: field("param"),
+                code: field("code"),
+            },
+            meta: Box::default(),
+        }
+    }
+
+    // Sets the identifiers of the response the error was returned with.
+    pub(crate) fn meta(mut self, meta: ResponseMeta) -> Self {
+        self.meta = Box::new(meta);
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::StatusCode;
+
+    #[test]
+    fn test_error_bodies() {
+        let error =
+            |body: &str| RequestError::from_body(StatusCode::NOT_FOUND, body.as_bytes()).error;
+
+        let openai = error(
+            r#"{"error": {"message": "No such model", "type": "invalid_request_error", "param": "model", "code": "model_not_found"}}"#,
+        );
+        assert_eq!(openai.message, "No such model");
+        assert_eq!(openai.param, "model");
+        assert_eq!(openai.code, "model_not_found");
+
+        let ollama = error(r#"{"error": "model 'llama3' not found"}"#);
+        assert_eq!(ollama.message, "model 'llama3' not found");
+        assert_eq!(ollama.r#type, "invalid_request_error");
+
+        let vllm = error(
+            r#"{"object": "error", "message": "The model does not exist.", "type": "NotFoundError", "param": null, "code": 404}"#,
+        );
+        assert_eq!(vllm.message, "The model does not exist.");
+        assert_eq!(vllm.r#type, "NotFoundError");
+        assert_eq!(vllm.code, 404);
+
+        assert_eq!(error(r#"{"detail": "Not Found"}"#).message, "Not Found");
+        assert_eq!(error("404 page not found\n").message, "404 page not found");
+        assert_eq!(error("").message, "Not Found");
+
+        let server =
+            RequestError::from_body(StatusCode::BAD_GATEWAY, b"<html>Bad Gateway</html>").error;
+        assert_eq!(server.message, "<html>Bad Gateway</html>");
+        assert_eq!(server.r#type, "server_error");
+    }
+
+    #[test]
+    fn test_error_codes() {
+        let code = |body: &str| {
+            Error::APIError(RequestError::from_body(
+                StatusCode::BAD_REQUEST,
+                body.as_bytes(),
+            ))
+            .error_code()
+        };
+
+        assert_eq!(
+            code(
+                r#"{"error": {"message": "This model's maximum context length is 128000 tokens.", "type": "invalid_request_error", "param": "messages", "code": "context_length_exceeded"}}"#
+            ),
+            Some(ApiErrorCode::ContextLengthExceeded)
+        );
+        assert_eq!(
+            code(
+                r#"{"error": {"message": "You exceeded your current quota.", "type": "insufficient_quota", "param": null, "code": null}}"#
+            ),
+            Some(ApiErrorCode::InsufficientQuota)
+        );
+        assert_eq!(
+            code(
+                r#"{"error": {"message": "Invalid value.", "type": "invalid_request_error", "param": null, "code": "invalid_value"}}"#
+            ),
+            Some(ApiErrorCode::Other("invalid_value".to_string()))
+        );
+        assert_eq!(
+            code(
+                r#"{"error": {"message": "Invalid value.", "type": "invalid_request_error", "param": null, "code": null}}"#
+            ),
+            None
+        );
+        assert_eq!(
+            ApiErrorCode::RateLimitExceeded.to_string(),
+            "rate_limit_exceeded"
+        );
+        assert_eq!(Error::TokenizerError(String::new()).error_code(), None);
+    }
+}
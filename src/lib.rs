@@ -2,15 +2,34 @@
 #![deny(missing_debug_implementations, rust_2018_idioms)]
 
 pub mod api_resources;
+#[cfg(feature = "arrow")]
+pub mod arrow;
+#[cfg(feature = "audio-splitting")]
+pub mod audio_splitting;
+pub mod auth;
+#[cfg(feature = "blocking")]
+pub mod blocking;
+#[cfg(feature = "tokenizer")]
+pub mod chunking;
 pub mod client;
-mod config;
+pub mod config;
 pub mod error;
+#[cfg(feature = "image")]
+pub mod mask;
+pub mod metrics;
+pub mod rate_limit;
+#[cfg(feature = "tokenizer")]
+pub mod tokenizer;
+pub mod training;
+pub mod transport;
 pub mod types;
 mod utils;
+pub mod vector;
 
 #[doc(inline)]
 pub use api_resources::{
-    chat, completion, edit, embedding, file, fine_tune, image, model, moderation,
+    assistant, audio, batch, chat, completion, edit, embedding, file, fine_tune, fine_tuning,
+    image, model, moderation, realtime, responses, run, thread, upload, vector_store,
 };
 
 #[doc(inline)]
@@ -0,0 +1,200 @@
+//! Construction of masks for [`image edits`](crate::image::edit).
+//!
+//! A mask is a PNG image with the same dimensions as the edited image, whose fully transparent areas indicate where the image should be edited.
+//! A [`Mask`] starts fully opaque, areas are then erased out of it from shapes or from the pixels of an image.
+//!
+//! Requires the `image` feature.
+
+use std::io::Cursor;
+
+use ::image::{ImageFormat, Rgba, RgbaImage};
+
+use crate::{types::FileUpload, Result};
+
+const OPAQUE: Rgba<u8> = Rgba([0, 0, 0, 255]);
+const TRANSPARENT: Rgba<u8> = Rgba([0, 0, 0, 0]);
+
+/// A mask for image edits, transparent where the image should be edited.
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{Client, image::{edit, EditImageParamBuilder}, mask::Mask};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let mask = Mask::new(1024, 1024)
+///         .rect(0, 0, 512, 1024)
+///         .polygon(&[(600.0, 100.0), (900.0, 100.0), (750.0, 400.0)]);
+///
+///     let param = EditImageParamBuilder::new("Add a sunset on the left and a bird on the right.")
+///         .mask(mask)
+///         .build()?;
+///
+///     let resp = edit(&client, "path-to-image.png", &param).await?;
+///     println!("{:#?}", resp);
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Mask {
+    image: RgbaImage,
+}
+
+impl Mask {
+    /// A fully opaque mask of the given dimensions, leaving the whole image as it is.
+    pub fn new(width: u32, height: u32) -> Self {
+        Self {
+            image: RgbaImage::from_pixel(width, height, OPAQUE),
+        }
+    }
+
+    /// A mask keeping the pixels of the image, made transparent wherever the predicate holds.
+    ///
+    /// The predicate receives the coordinates and the color of each pixel.
+    /// Masking the image to edit itself, for example to edit its white background:
+    /// ```no_run
+    /// use fieri::mask::Mask;
+    ///
+    /// fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let image = image::open("path-to-image.png")?.to_rgba8();
+    ///     let mask = Mask::from_image(image, |_, _, pixel| pixel.0[..3] == [255, 255, 255]);
+    ///     std::fs::write("mask.png", mask.to_png()?)?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn from_image<F>(mut image: RgbaImage, erase: F) -> Self
+    where
+        F: Fn(u32, u32, &Rgba<u8>) -> bool,
+    {
+        for (x, y, pixel) in image.enumerate_pixels_mut() {
+            if erase(x, y, pixel) {
+                pixel.0[3] = 0;
+            }
+        }
+
+        Self { image }
+    }
+
+    /// Erases the rectangle with its top left corner at `x` and `y`, clipped to the mask.
+    pub fn rect(self, x: u32, y: u32, width: u32, height: u32) -> Self {
+        self.erase(|px, py| {
+            (x..x.saturating_add(width)).contains(&px)
+                && (y..y.saturating_add(height)).contains(&py)
+        })
+    }
+
+    /// Erases the polygon with the given vertices, in order.
+    ///
+    /// A pixel is inside the polygon if its center is, following the even-odd rule.
+    pub fn polygon(self, points: &[(f32, f32)]) -> Self {
+        self.erase(|x, y| contains(points, x as f32 + 0.5, y as f32 + 0.5))
+    }
+
+    /// Erases every pixel for which the predicate holds, given its coordinates.
+    pub fn erase<F>(mut self, erase: F) -> Self
+    where
+        F: Fn(u32, u32) -> bool,
+    {
+        for (x, y, pixel) in self.image.enumerate_pixels_mut() {
+            if erase(x, y) {
+                *pixel = TRANSPARENT;
+            }
+        }
+
+        self
+    }
+
+    /// Whether the pixel is transparent, meaning it will be edited.
+    ///
+    /// Pixels outside of the mask are never transparent.
+    pub fn is_transparent(&self, x: u32, y: u32) -> bool {
+        self.image
+            .get_pixel_checked(x, y)
+            .is_some_and(|pixel| pixel.0[3] == 0)
+    }
+
+    /// The mask encoded as a PNG image.
+    pub fn to_png(&self) -> Result<Vec<u8>> {
+        let mut png = Cursor::new(Vec::new());
+        self.image.write_to(&mut png, ImageFormat::Png)?;
+
+        Ok(png.into_inner())
+    }
+
+    pub fn into_image(self) -> RgbaImage {
+        self.image
+    }
+}
+
+impl From<Mask> for FileUpload {
+    fn from(mask: Mask) -> Self {
+        let png = mask
+            .to_png()
+            .expect("Unable to encode the mask as a PNG image.");
+
+        FileUpload::bytes("mask.png", png)
+    }
+}
+
+/// Whether the point is inside the polygon, following the even-odd rule.
+fn contains(points: &[(f32, f32)], x: f32, y: f32) -> bool {
+    let mut inside = false;
+
+    for (i, &(x1, y1)) in points.iter().enumerate() {
+        let (x2, y2) = points[(i + 1) % points.len()];
+
+        if (y1 > y) != (y2 > y) && x < x1 + (y - y1) * (x2 - x1) / (y2 - y1) {
+            inside = !inside;
+        }
+    }
+
+    inside
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mask_shapes() {
+        let mask =
+            Mask::new(8, 8)
+                .rect(6, 6, 10, 10)
+                .polygon(&[(0.0, 0.0), (4.0, 0.0), (0.0, 4.0)]);
+
+        assert!(mask.is_transparent(7, 7));
+        assert!(!mask.is_transparent(5, 7));
+        assert!(mask.is_transparent(0, 0));
+        assert!(mask.is_transparent(1, 1));
+        assert!(!mask.is_transparent(3, 3));
+        assert!(!mask.is_transparent(8, 8));
+
+        let image = mask.clone().into_image();
+        assert_eq!(image.dimensions(), (8, 8));
+        assert_eq!(image.get_pixel(4, 4), &OPAQUE);
+
+        let png = mask.to_png().unwrap();
+        let decoded = ::image::load_from_memory(&png).unwrap().to_rgba8();
+        assert_eq!(Mask { image: decoded }, mask);
+    }
+
+    #[test]
+    fn test_mask_from_image() {
+        let mut image = RgbaImage::from_pixel(2, 1, Rgba([255, 255, 255, 255]));
+        image.put_pixel(1, 0, Rgba([10, 20, 30, 255]));
+
+        let mask = Mask::from_image(image, |_, _, pixel| pixel.0[..3] == [255, 255, 255]);
+        assert!(mask.is_transparent(0, 0));
+        assert!(!mask.is_transparent(1, 0));
+        assert_eq!(mask.image.get_pixel(1, 0), &Rgba([10, 20, 30, 255]));
+
+        assert!(matches!(
+            FileUpload::from(mask),
+            FileUpload::Bytes { file_name, .. } if file_name == "mask.png"
+        ));
+    }
+}
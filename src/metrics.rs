@@ -0,0 +1,284 @@
+//! Hooks collecting metrics of the requests to the API, like their latency, retries, bytes and tokens,
+//! for services to export along with their own.
+//!
+//! A [`Metrics`] implementation set on the [`Client`](crate::Client) is called along each request, none by default.
+//! [`PrometheusMetrics`] keeps them in memory, rendered in the Prometheus text format to be scraped.
+//!
+//! ## Example
+//! ```no_run
+//! use std::sync::Arc;
+//! use fieri::{Client, metrics::PrometheusMetrics};
+//!
+//! let metrics = Arc::new(PrometheusMetrics::default());
+//! let client = Client::new().metrics(metrics.clone());
+//!
+//! // Served on the `/metrics` endpoint of the service.
+//! println!("{}", metrics.render());
+//! ```
+
+use std::{collections::BTreeMap, fmt::Write, sync::Mutex, time::Duration};
+
+use reqwest::StatusCode;
+use url::Url;
+
+/// Receives the events of the requests sent by the client, each one doing nothing by default.
+///
+/// Endpoints are the paths of the requests relative to the API URL, their IDs replaced with `{id}`,
+/// like `chat/completions` or `files/{id}/content`.
+pub trait Metrics: std::fmt::Debug + Send + Sync {
+    /// An attempt of a request is about to be sent.
+    fn request_started(&self, _endpoint: &str) {}
+
+    /// An attempt of a request finished, with the status of its response, or without one if it failed to be sent.
+    fn request_finished(&self, _endpoint: &str, _status: Option<StatusCode>, _elapsed: Duration) {}
+
+    /// A request is retried, after the given number of failed attempts.
+    fn request_retried(&self, _endpoint: &str, _attempt: u32) {}
+
+    /// The body of a request was sent, streamed ones, like uploaded files, being left out.
+    fn bytes_sent(&self, _endpoint: &str, _bytes: u64) {}
+
+    /// A part of the body of a response was read by the client.
+    fn bytes_received(&self, _endpoint: &str, _bytes: u64) {}
+
+    /// A response reported the tokens it used, streamed ones being left out.
+    fn tokens_used(&self, _model: &str, _input: u64, _output: u64) {}
+}
+
+/// Discards all the metrics.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct NoopMetrics;
+
+impl Metrics for NoopMetrics {}
+
+// The metrics exported by `PrometheusMetrics`, along with their type and description.
+const FAMILIES: [(&str, &str, &str); 8] = [
+    (
+        "fieri_requests_total",
+        "counter",
+        "Requests sent to the OpenAI API, each attempt counted.",
+    ),
+    (
+        "fieri_requests_in_flight",
+        "gauge",
+        "Requests waiting for their response.",
+    ),
+    (
+        "fieri_request_duration_seconds_sum",
+        "counter",
+        "Total time spent waiting for responses.",
+    ),
+    (
+        "fieri_request_duration_seconds_count",
+        "counter",
+        "Responses waited for.",
+    ),
+    (
+        "fieri_retries_total",
+        "counter",
+        "Requests retried after failing.",
+    ),
+    (
+        "fieri_sent_bytes_total",
+        "counter",
+        "Bytes of the bodies of the requests.",
+    ),
+    (
+        "fieri_received_bytes_total",
+        "counter",
+        "Bytes of the bodies of the responses.",
+    ),
+    (
+        "fieri_tokens_total",
+        "counter",
+        "Tokens used by the responses, per model and kind.",
+    ),
+];
+
+/// Keeps the metrics in memory, rendered in the Prometheus text exposition format by [`render`](Self::render).
+#[derive(Debug, Default)]
+pub struct PrometheusMetrics {
+    // The value of each series, by metric and labels.
+    series: Mutex<BTreeMap<(&'static str, String), f64>>,
+}
+
+impl PrometheusMetrics {
+    /// Renders all the series in the Prometheus text exposition format.
+    pub fn render(&self) -> String {
+        let series = self.series.lock().expect("The metrics lock is poisoned.");
+
+        let mut out = String::new();
+        for (name, kind, help) in FAMILIES {
+            let _ = writeln!(out, "# HELP {name} {help}");
+            let _ = writeln!(out, "# TYPE {name} {kind}");
+            for ((_, labels), value) in series
+                .range((name, String::new())..)
+                .take_while(|((metric, _), _)| *metric == name)
+            {
+                let _ = writeln!(out, "{name}{{{labels}}} {value}");
+            }
+        }
+
+        out
+    }
+
+    /// The value of the series of the metric with the given labels, as rendered, like `endpoint="chat/completions"`.
+    pub fn value(&self, name: &str, labels: &str) -> Option<f64> {
+        self.series
+            .lock()
+            .expect("The metrics lock is poisoned.")
+            .iter()
+            .find(|((metric, series), _)| *metric == name && series == labels)
+            .map(|(_, value)| *value)
+    }
+
+    fn add(&self, name: &'static str, labels: &[(&str, &str)], value: f64) {
+        let labels = labels
+            .iter()
+            .map(|(label, value)| format!("{label}=\"{}\"", escape(value)))
+            .collect::<Vec<_>>()
+            .join(",");
+
+        *self
+            .series
+            .lock()
+            .expect("The metrics lock is poisoned.")
+            .entry((name, labels))
+            .or_default() += value;
+    }
+}
+
+impl Metrics for PrometheusMetrics {
+    fn request_started(&self, endpoint: &str) {
+        self.add("fieri_requests_in_flight", &[("endpoint", endpoint)], 1.0);
+    }
+
+    fn request_finished(&self, endpoint: &str, status: Option<StatusCode>, elapsed: Duration) {
+        let status =
+            status.map_or_else(|| "error".to_string(), |status| status.as_u16().to_string());
+
+        self.add("fieri_requests_in_flight", &[("endpoint", endpoint)], -1.0);
+        self.add(
+            "fieri_requests_total",
+            &[("endpoint", endpoint), ("status", &status)],
+            1.0,
+        );
+        self.add(
+            "fieri_request_duration_seconds_sum",
+            &[("endpoint", endpoint)],
+            elapsed.as_secs_f64(),
+        );
+        self.add(
+            "fieri_request_duration_seconds_count",
+            &[("endpoint", endpoint)],
+            1.0,
+        );
+    }
+
+    fn request_retried(&self, endpoint: &str, _attempt: u32) {
+        self.add("fieri_retries_total", &[("endpoint", endpoint)], 1.0);
+    }
+
+    fn bytes_sent(&self, endpoint: &str, bytes: u64) {
+        self.add(
+            "fieri_sent_bytes_total",
+            &[("endpoint", endpoint)],
+            bytes as f64,
+        );
+    }
+
+    fn bytes_received(&self, endpoint: &str, bytes: u64) {
+        self.add(
+            "fieri_received_bytes_total",
+            &[("endpoint", endpoint)],
+            bytes as f64,
+        );
+    }
+
+    fn tokens_used(&self, model: &str, input: u64, output: u64) {
+        self.add(
+            "fieri_tokens_total",
+            &[("model", model), ("kind", "input")],
+            input as f64,
+        );
+        self.add(
+            "fieri_tokens_total",
+            &[("model", model), ("kind", "output")],
+            output as f64,
+        );
+    }
+}
+
+// Escapes a label value, per the Prometheus text format.
+fn escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+/// The endpoint of a request, as its path relative to the API URL, with the segments holding digits,
+/// like IDs, replaced with `{id}` to keep the number of series bounded.
+pub(crate) fn endpoint(base: &Url, url: &Url) -> String {
+    let path = url.path();
+    let path = path.strip_prefix(base.path()).unwrap_or(path);
+
+    path.trim_matches('/')
+        .split('/')
+        .map(|segment| {
+            if segment.bytes().any(|byte| byte.is_ascii_digit()) {
+                "{id}"
+            } else {
+                segment
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prometheus_metrics() {
+        let base = Url::parse("https://api.openai.com/v1/").unwrap();
+        let url = |path: &str| base.join(path).unwrap();
+        assert_eq!(
+            endpoint(&base, &url("chat/completions")),
+            "chat/completions"
+        );
+        assert_eq!(
+            endpoint(&base, &url("files/file-abc123/content")),
+            "files/{id}/content"
+        );
+        assert_eq!(endpoint(&base, &url("vector_stores")), "vector_stores");
+
+        let metrics = PrometheusMetrics::default();
+        metrics.request_started("chat/completions");
+        metrics.request_finished(
+            "chat/completions",
+            Some(StatusCode::OK),
+            Duration::from_millis(250),
+        );
+        metrics.request_started("chat/completions");
+        metrics.request_finished("chat/completions", None, Duration::from_millis(250));
+        metrics.tokens_used("gpt-4o", 10, 5);
+        metrics.tokens_used("gpt-4o", 10, 5);
+
+        let rendered = metrics.render();
+        assert!(rendered.contains("# TYPE fieri_requests_total counter\n"));
+        assert!(rendered
+            .contains("fieri_requests_total{endpoint=\"chat/completions\",status=\"200\"} 1\n"));
+        assert!(rendered
+            .contains("fieri_requests_total{endpoint=\"chat/completions\",status=\"error\"} 1\n"));
+        assert!(rendered
+            .contains("fieri_request_duration_seconds_sum{endpoint=\"chat/completions\"} 0.5\n"));
+        assert!(rendered.contains("fieri_requests_in_flight{endpoint=\"chat/completions\"} 0\n"));
+        assert!(rendered.contains("fieri_tokens_total{model=\"gpt-4o\",kind=\"input\"} 20\n"));
+        assert_eq!(
+            metrics.value("fieri_tokens_total", "model=\"gpt-4o\",kind=\"output\""),
+            Some(10.0)
+        );
+    }
+}
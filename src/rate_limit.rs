@@ -0,0 +1,364 @@
+//! Client-side rate limiting of the requests to the API, with budgets of requests and tokens per minute per model.
+//!
+//! Keeping the requests within the limits of the organization avoids the storm of rate limited responses a batch job
+//! otherwise runs into, whose retries only make it worse.
+//! Each budget refills continuously, a request exhausting it either waits for it to refill,
+//! or fails with [`Error::RateLimitExceeded`], depending on the [`RateLimitMode`].
+//!
+//! The tokens of a request are estimated before it's sent, out of the length of its body
+//! and the maximum number of tokens it may generate.
+//!
+//! The limits reported by the API itself, in the `x-ratelimit-*` headers of its responses,
+//! are available as [`RateLimitInfo`] from [`Client::rate_limit_info`](crate::Client::rate_limit_info).
+//!
+//! ## Example
+//! ```no_run
+//! use fieri::{Client, rate_limit::{RateLimit, RateLimitMode, RateLimiter}};
+//!
+//! let limiter = RateLimiter::new(RateLimitMode::Queue)
+//!     .limit("gpt-4o", RateLimit::new(500, 30_000))
+//!     .limit("text-embedding-3-small", RateLimit::requests(3_000));
+//!
+//! let client = Client::new().rate_limiter(limiter);
+//! ```
+
+use std::{collections::HashMap, sync::Mutex, time::Duration};
+
+use reqwest::header::HeaderMap;
+use serde::Serialize;
+
+use crate::{
+    config::parse_duration,
+    utils::{sleep, Instant},
+    Error, Result,
+};
+
+/// The budgets of a model, per minute.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RateLimit {
+    pub requests_per_minute: Option<u32>,
+    pub tokens_per_minute: Option<u32>,
+}
+
+impl RateLimit {
+    pub fn new(requests_per_minute: u32, tokens_per_minute: u32) -> Self {
+        Self {
+            requests_per_minute: Some(requests_per_minute),
+            tokens_per_minute: Some(tokens_per_minute),
+        }
+    }
+
+    /// A budget of requests only.
+    pub fn requests(requests_per_minute: u32) -> Self {
+        Self {
+            requests_per_minute: Some(requests_per_minute),
+            tokens_per_minute: None,
+        }
+    }
+
+    /// A budget of tokens only.
+    pub fn tokens(tokens_per_minute: u32) -> Self {
+        Self {
+            requests_per_minute: None,
+            tokens_per_minute: Some(tokens_per_minute),
+        }
+    }
+}
+
+/// What happens to a request exhausting the budget of its model.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RateLimitMode {
+    /// The request waits for the budget to refill.
+    #[default]
+    Queue,
+
+    /// The request fails right away with [`Error::RateLimitExceeded`].
+    FailFast,
+}
+
+/// Limits the requests to the limits of their model, shared by all the clones of the [`Client`](crate::Client) it's set on.
+///
+/// The requests to models without a limit aren't limited.
+#[derive(Debug, Default)]
+pub struct RateLimiter {
+    limits: HashMap<String, RateLimit>,
+    mode: RateLimitMode,
+    budgets: Mutex<HashMap<String, Budget>>,
+}
+
+impl RateLimiter {
+    pub fn new(mode: RateLimitMode) -> Self {
+        Self {
+            mode,
+            ..Self::default()
+        }
+    }
+
+    /// Sets the limit of the model.
+    pub fn limit(mut self, model: impl Into<String>, limit: RateLimit) -> Self {
+        self.limits.insert(model.into(), limit);
+        self
+    }
+
+    /// Takes a request and the tokens from the budget of the model, waiting for it to refill
+    /// or failing with [`Error::RateLimitExceeded`] if it's exhausted, depending on the mode.
+    ///
+    /// A request needing more tokens than the whole budget waits for all of it.
+    /// A zero limit never refills, its requests failing right away whatever the mode.
+    pub async fn acquire(&self, model: &str, tokens: u32) -> Result<()> {
+        let Some(limit) = self.limits.get(model) else {
+            return Ok(());
+        };
+        if limit.requests_per_minute == Some(0) || limit.tokens_per_minute == Some(0) {
+            return Err(Error::RateLimitExceeded {
+                model: model.to_string(),
+                retry_after: Duration::from_secs(60),
+            });
+        }
+
+        loop {
+            let wait = self
+                .budgets
+                .lock()
+                .expect("The rate limiter lock is poisoned.")
+                .entry(model.to_string())
+                .or_insert_with(|| Budget::new(limit))
+                .take(limit, tokens, Instant::now());
+
+            match wait {
+                None => return Ok(()),
+                Some(retry_after) if self.mode == RateLimitMode::FailFast => {
+                    return Err(Error::RateLimitExceeded {
+                        model: model.to_string(),
+                        retry_after,
+                    })
+                }
+                Some(wait) => sleep(wait).await,
+            }
+        }
+    }
+
+    // Acquires the budget of a request.
+    pub(crate) async fn acquire_for(&self, cost: &Cost) -> Result<()> {
+        self.acquire(&cost.model, cost.tokens).await
+    }
+}
+
+// The budget a request takes from the one of its model.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Cost {
+    model: String,
+    tokens: u32,
+}
+
+impl Cost {
+    // The cost of a request to the model, its tokens estimated from its parameters.
+    pub(crate) fn new<X: Serialize>(model: &str, param: &X) -> Self {
+        let tokens = serde_json::to_value(param)
+            .map(|body| estimate_tokens(&body))
+            .unwrap_or_default();
+
+        Self {
+            model: model.to_string(),
+            tokens,
+        }
+    }
+
+    // The cost of a request with the given JSON body, if it names its model.
+    pub(crate) fn of(body: &[u8]) -> Option<Self> {
+        let body = serde_json::from_slice::<serde_json::Value>(body).ok()?;
+        let model = body.get("model")?.as_str()?;
+
+        Some(Self {
+            model: model.to_string(),
+            tokens: estimate_tokens(&body),
+        })
+    }
+}
+
+/// The rate limits of the organization for a model, as reported by the `x-ratelimit-*` headers of a response.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RateLimitInfo {
+    /// The maximum number of requests per minute.
+    pub limit_requests: Option<u32>,
+
+    /// The number of requests left before the limit is reached.
+    pub remaining_requests: Option<u32>,
+
+    /// The maximum number of tokens per minute.
+    pub limit_tokens: Option<u32>,
+
+    /// The number of tokens left before the limit is reached.
+    pub remaining_tokens: Option<u32>,
+
+    /// The time until the budget of requests is fully reset.
+    pub reset_requests: Option<Duration>,
+
+    /// The time until the budget of tokens is fully reset.
+    pub reset_tokens: Option<Duration>,
+}
+
+impl RateLimitInfo {
+    /// Parses the rate limits out of the headers of a response, if any of them is present.
+    pub fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        let header = |name: &str| {
+            headers
+                .get(format!("x-ratelimit-{name}"))
+                .and_then(|value| value.to_str().ok())
+                .map(str::trim)
+        };
+        let number = |name: &str| header(name).and_then(|value| value.parse().ok());
+
+        let info = Self {
+            limit_requests: number("limit-requests"),
+            remaining_requests: number("remaining-requests"),
+            limit_tokens: number("limit-tokens"),
+            remaining_tokens: number("remaining-tokens"),
+            reset_requests: header("reset-requests").and_then(parse_duration),
+            reset_tokens: header("reset-tokens").and_then(parse_duration),
+        };
+
+        (info != Self::default()).then_some(info)
+    }
+}
+
+// Estimates the tokens of a request, as roughly 4 characters a token of its body, along with the tokens it may generate.
+fn estimate_tokens(body: &serde_json::Value) -> u32 {
+    let prompt = body.to_string().len() / 4;
+    let completion = ["max_tokens", "max_completion_tokens", "max_output_tokens"]
+        .iter()
+        .find_map(|field| body.get(field).and_then(serde_json::Value::as_u64))
+        .unwrap_or_default();
+
+    (prompt as u64 + completion).min(u64::from(u32::MAX)) as u32
+}
+
+// The budgets of a model left, refilled continuously up to its limits.
+#[derive(Debug)]
+struct Budget {
+    requests: f64,
+    tokens: f64,
+    refilled: Instant,
+}
+
+impl Budget {
+    fn new(limit: &RateLimit) -> Self {
+        Self {
+            requests: f64::from(limit.requests_per_minute.unwrap_or_default()),
+            tokens: f64::from(limit.tokens_per_minute.unwrap_or_default()),
+            refilled: Instant::now(),
+        }
+    }
+
+    // Takes a request and the tokens if the budget allows it, returning how long to wait for it otherwise.
+    fn take(&mut self, limit: &RateLimit, tokens: u32, now: Instant) -> Option<Duration> {
+        let elapsed = now.saturating_duration_since(self.refilled).as_secs_f64() / 60.0;
+        self.refilled = now;
+
+        let mut wait: f64 = 0.0;
+        if let Some(rpm) = limit.requests_per_minute.map(f64::from) {
+            self.requests = (self.requests + elapsed * rpm).min(rpm);
+            if self.requests < 1.0 {
+                wait = wait.max((1.0 - self.requests) / rpm * 60.0);
+            }
+        }
+        if let Some(tpm) = limit.tokens_per_minute.map(f64::from) {
+            let tokens = f64::from(tokens).min(tpm);
+            self.tokens = (self.tokens + elapsed * tpm).min(tpm);
+            if self.tokens < tokens {
+                wait = wait.max((tokens - self.tokens) / tpm * 60.0);
+            }
+        }
+
+        if wait > 0.0 {
+            return Some(Duration::from_secs_f64(wait));
+        }
+
+        if limit.requests_per_minute.is_some() {
+            self.requests -= 1.0;
+        }
+        if let Some(tpm) = limit.tokens_per_minute {
+            self.tokens -= f64::from(tokens.min(tpm));
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_rate_limiter() {
+        let limiter = RateLimiter::new(RateLimitMode::FailFast)
+            .limit("gpt-4o", RateLimit::requests(2))
+            .limit("gpt-4o-mini", RateLimit::tokens(100));
+
+        limiter.acquire("gpt-4o", 1_000).await.unwrap();
+        limiter.acquire("gpt-4o", 1_000).await.unwrap();
+        assert!(matches!(
+            limiter.acquire("gpt-4o", 1_000).await,
+            Err(Error::RateLimitExceeded { model, retry_after })
+                if model == "gpt-4o" && retry_after > Duration::from_secs(29)
+        ));
+
+        limiter.acquire("gpt-4o-mini", 80).await.unwrap();
+        assert!(limiter.acquire("gpt-4o-mini", 30).await.is_err());
+        limiter.acquire("o1", u32::MAX).await.unwrap();
+
+        let limiter = RateLimiter::new(RateLimitMode::Queue)
+            .limit("gpt-4o", RateLimit::requests(0))
+            .limit("gpt-4o-mini", RateLimit::new(10, 0));
+        for model in ["gpt-4o", "gpt-4o-mini"] {
+            assert!(matches!(
+                limiter.acquire(model, 0).await,
+                Err(Error::RateLimitExceeded { retry_after, .. }) if retry_after == Duration::from_secs(60)
+            ));
+        }
+
+        let limiter =
+            RateLimiter::new(RateLimitMode::Queue).limit("gpt-4o", RateLimit::requests(600));
+        let start = Instant::now();
+        for _ in 0..602 {
+            limiter.acquire("gpt-4o", 0).await.unwrap();
+        }
+        assert!(start.elapsed() >= Duration::from_millis(150));
+    }
+
+    #[test]
+    fn test_rate_limit_info_headers() {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-ratelimit-remaining-requests", "0".parse().unwrap());
+        headers.insert(
+            "x-ratelimit-reset-requests",
+            "99999999999999999999999h".parse().unwrap(),
+        );
+        headers.insert("x-ratelimit-reset-tokens", "6m0s".parse().unwrap());
+
+        assert_eq!(
+            RateLimitInfo::from_headers(&headers),
+            Some(RateLimitInfo {
+                remaining_requests: Some(0),
+                reset_tokens: Some(Duration::from_secs(360)),
+                ..RateLimitInfo::default()
+            })
+        );
+    }
+
+    #[test]
+    fn test_token_estimate() {
+        let body = serde_json::json!({
+            "model": "gpt-4o",
+            "messages": [{"role": "user", "content": "a".repeat(400)}],
+            "max_completion_tokens": 50,
+        });
+
+        let tokens = estimate_tokens(&body);
+        assert!((150..200).contains(&tokens));
+
+        let cost = Cost::of(body.to_string().as_bytes()).unwrap();
+        assert_eq!(cost, Cost::new("gpt-4o", &body));
+        assert_eq!(cost.tokens, tokens);
+        assert_eq!(Cost::of(b"{\"input\": \"text\"}"), None);
+    }
+}
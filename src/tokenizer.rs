@@ -0,0 +1,111 @@
+//! Tokenization of text for the OpenAI models, based on [`tiktoken`](https://github.com/openai/tiktoken).
+//!
+//! Models see text as tokens, common sequences of characters. Knowing the tokens of a text allows to
+//! count how many of them a prompt uses, or to bias the likelihood of specific tokens appearing in the completion.
+//!
+//! Requires the `tokenizer` feature.
+
+use std::{collections::HashMap, fmt::Debug};
+
+use tiktoken_rs::CoreBPE;
+
+use crate::{Error, Result};
+
+/// The tokenizer used by a specific model.
+pub struct Tokenizer {
+    bpe: CoreBPE,
+}
+
+impl Debug for Tokenizer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Tokenizer").finish_non_exhaustive()
+    }
+}
+
+impl Tokenizer {
+    /// Returns the tokenizer used by the given model, like `cl100k_base` for `gpt-4` or `o200k_base` for `gpt-4o`.
+    pub fn for_model(model: &str) -> Result<Self> {
+        let bpe = tiktoken_rs::get_bpe_from_model(model)
+            .map_err(|err| Error::TokenizerError(err.to_string()))?;
+
+        Ok(Self { bpe })
+    }
+
+    /// Splits the text into token ids.
+    pub fn encode(&self, text: &str) -> Vec<u32> {
+        self.bpe
+            .encode_with_special_tokens(text)
+            .into_iter()
+            .map(|token| token as u32)
+            .collect()
+    }
+
+    /// Turns token ids back into text.
+    pub fn decode(&self, tokens: &[u32]) -> Result<String> {
+        self.bpe
+            .decode(tokens.iter().map(|&token| token as usize).collect())
+            .map_err(|err| Error::TokenizerError(err.to_string()))
+    }
+
+    /// The number of tokens in the text.
+    pub fn count(&self, text: &str) -> usize {
+        self.bpe.encode_with_special_tokens(text).len()
+    }
+}
+
+/// Builds a `logit_bias` map out of words, applying the bias to every token of each word.
+///
+/// Note that a word preceded by a space is usually a different token, e.g. `"time"` and `" time"`.
+///
+/// ## Example
+/// ```no_run
+/// use fieri::tokenizer::logit_bias;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     // Ban the word " Paris" and make " London" more likely.
+///     let bias = logit_bias("gpt-4o", [(" Paris", -100), (" London", 5)])?;
+///     println!("{:?}", bias);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn logit_bias<I, S>(model: &str, biases: I) -> Result<HashMap<u32, i32>>
+where
+    I: IntoIterator<Item = (S, i32)>,
+    S: AsRef<str>,
+{
+    let tokenizer = Tokenizer::for_model(model)?;
+
+    Ok(biases
+        .into_iter()
+        .flat_map(|(word, bias)| {
+            tokenizer
+                .encode(word.as_ref())
+                .into_iter()
+                .map(move |token| (token, bias))
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_logit_bias() {
+        let tokenizer = Tokenizer::for_model("gpt-4").unwrap();
+        let tokens = tokenizer.encode("Hello world");
+        assert_eq!(tokens, vec![9906, 1917]);
+        assert_eq!(tokenizer.decode(&tokens).unwrap(), "Hello world");
+
+        let bias = logit_bias("gpt-4", [("Hello world", -100), (" time", 10)]).unwrap();
+        assert_eq!(bias.len(), 3);
+        assert_eq!(bias[&9906], -100);
+        assert_eq!(bias[&tokenizer.encode(" time")[0]], 10);
+
+        assert!(matches!(
+            Tokenizer::for_model("unknown-model"),
+            Err(Error::TokenizerError(_))
+        ));
+    }
+}
@@ -0,0 +1,348 @@
+//! Construction of JSONL training files for [`fine-tuning`](crate::fine_tuning) chat models, out of typed conversations.
+//!
+//! Each [`TrainingExample`] is validated against the format rules of fine-tuning before it's written,
+//! so that malformed examples are caught before the file is uploaded, rather than once the job fails.
+//!
+//! ## Example
+//! ```no_run
+//! use fieri::{Client, chat::ChatMessage, file::{upload, Purpose}, training::{TrainingExample, TrainingFileWriter}};
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let client = Client::new();
+//!
+//!     let mut writer = TrainingFileWriter::in_memory();
+//!     for (question, answer) in [("Hi!", "Hello, how can I help?"), ("Thanks!", "You're welcome.")] {
+//!         writer.write(&TrainingExample::new(vec![
+//!             ChatMessage::system("You are a friendly support agent."),
+//!             ChatMessage::user(question),
+//!             ChatMessage::assistant(answer),
+//!         ]))?;
+//!     }
+//!
+//!     let resp = upload(&client, writer.into_upload("train.jsonl")?, Purpose::FineTune).await?;
+//!     println!("{}", resp.id);
+//!
+//!     Ok(())
+//! }
+//! ```
+
+use std::{
+    collections::HashSet,
+    fs,
+    io::{BufWriter, Write},
+    path::Path,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    types::{ChatContent, ChatMessage, ChatRole, FileUpload, Tool},
+    Error, Result,
+};
+
+/// A single conversation to train the model on, the model learning to respond like its assistant messages.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct TrainingExample {
+    pub messages: Vec<ChatMessage>,
+
+    /// The tools available to the model, required for examples calling tools.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tools: Option<Vec<Tool>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parallel_tool_calls: Option<bool>,
+}
+
+impl TrainingExample {
+    pub fn new(messages: Vec<ChatMessage>) -> Self {
+        Self {
+            messages,
+            ..Self::default()
+        }
+    }
+
+    /// Sets the tools available to the model, for examples calling tools.
+    pub fn tools(mut self, tools: Vec<Tool>) -> Self {
+        self.tools = Some(tools);
+        self
+    }
+
+    /// Checks the example against the format rules of fine-tuning.
+    ///
+    /// The messages are referred to by their position in the conversation.
+    pub fn validate(&self) -> std::result::Result<(), TrainingExampleError> {
+        if self.messages.is_empty() {
+            return Err(TrainingExampleError::NoMessages);
+        }
+        if !self
+            .messages
+            .iter()
+            .any(|message| message.role == ChatRole::Assistant)
+        {
+            return Err(TrainingExampleError::NoAssistantMessage);
+        }
+
+        let functions = self.tools.as_ref().map(|tools| {
+            tools
+                .iter()
+                .map(|tool| tool.function.name.as_str())
+                .collect::<HashSet<_>>()
+        });
+        let mut calls = HashSet::new();
+
+        for (index, message) in self.messages.iter().enumerate() {
+            let tool_calls = message.tool_calls.as_deref().unwrap_or_default();
+            let has_content = !is_empty(&message.content);
+
+            match message.role {
+                ChatRole::Assistant => {
+                    if !has_content && tool_calls.is_empty() && message.function_call.is_none() {
+                        return Err(TrainingExampleError::EmptyMessage { message: index });
+                    }
+
+                    for call in tool_calls {
+                        if let Some(functions) = &functions {
+                            if !functions.contains(call.function.name.as_str()) {
+                                return Err(TrainingExampleError::UndefinedFunction {
+                                    message: index,
+                                    name: call.function.name.clone(),
+                                });
+                            }
+                        }
+                        calls.insert(call.id.as_str());
+                    }
+                    if !tool_calls.is_empty() && functions.is_none() {
+                        return Err(TrainingExampleError::MissingTools { message: index });
+                    }
+                }
+                ChatRole::Tool => match &message.tool_call_id {
+                    None => return Err(TrainingExampleError::MissingToolCallId { message: index }),
+                    Some(id) if !calls.contains(id.as_str()) => {
+                        return Err(TrainingExampleError::UnknownToolCall {
+                            message: index,
+                            id: id.clone(),
+                        })
+                    }
+                    Some(_) => {}
+                },
+                _ if !has_content => {
+                    return Err(TrainingExampleError::EmptyMessage { message: index })
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn is_empty(content: &ChatContent) -> bool {
+    match content {
+        ChatContent::Text(text) => text.trim().is_empty(),
+        ChatContent::Parts(parts) => parts.is_empty(),
+    }
+}
+
+/// The reasons why a training example breaks the format rules of fine-tuning.
+#[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum TrainingExampleError {
+    #[error("The example has no messages.")]
+    NoMessages,
+
+    #[error("The example has no assistant message to learn from.")]
+    NoAssistantMessage,
+
+    #[error("Message {message} has no content.")]
+    EmptyMessage { message: usize },
+
+    #[error("Message {message} calls tools, but the example defines none.")]
+    MissingTools { message: usize },
+
+    #[error(
+        "Message {message} calls the function {name}, which isn't one of the tools of the example."
+    )]
+    UndefinedFunction { message: usize, name: String },
+
+    #[error("Message {message} is a tool message without a tool call id.")]
+    MissingToolCallId { message: usize },
+
+    #[error("Message {message} responds to the tool call {id}, which no previous message made.")]
+    UnknownToolCall { message: usize, id: String },
+}
+
+/// Writes validated training examples as JSONL, one example per line.
+#[derive(Debug)]
+pub struct TrainingFileWriter<W: Write> {
+    writer: W,
+    examples: usize,
+}
+
+impl<W: Write> TrainingFileWriter<W> {
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            examples: 0,
+        }
+    }
+
+    /// Validates the example and writes it, failing with [`Error::TrainingExampleError`] if it's invalid.
+    pub fn write(&mut self, example: &TrainingExample) -> Result<()> {
+        example
+            .validate()
+            .map_err(|source| Error::TrainingExampleError {
+                example: self.examples,
+                source,
+            })?;
+
+        serde_json::to_writer(&mut self.writer, example)?;
+        self.writer.write_all(b"\n")?;
+        self.examples += 1;
+
+        Ok(())
+    }
+
+    /// The number of examples written so far.
+    pub fn len(&self) -> usize {
+        self.examples
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.examples == 0
+    }
+
+    /// Flushes the examples, returning the underlying writer.
+    pub fn into_inner(mut self) -> Result<W> {
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+}
+
+impl TrainingFileWriter<Vec<u8>> {
+    /// A writer keeping the training file in memory.
+    pub fn in_memory() -> Self {
+        Self::new(Vec::new())
+    }
+
+    /// Turns the training file into an upload for the [`Files`](crate::file::upload) API, under the given name.
+    pub fn into_upload(self, file_name: impl Into<String>) -> Result<FileUpload> {
+        Ok(FileUpload::bytes(file_name, self.into_inner()?))
+    }
+}
+
+impl TrainingFileWriter<BufWriter<fs::File>> {
+    /// A writer creating the training file at the given path, or truncating it if it exists.
+    pub fn create(path: impl AsRef<Path>) -> Result<Self> {
+        Ok(Self::new(BufWriter::new(fs::File::create(path)?)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{FunctionCall, FunctionSpec, ToolCall, ToolType};
+
+    fn weather_call() -> ToolCall {
+        ToolCall {
+            id: "call_1".into(),
+            r#type: ToolType::Function,
+            function: FunctionCall {
+                name: "get_weather".into(),
+                arguments: r#"{"city": "Paris"}"#.into(),
+            },
+        }
+    }
+
+    #[test]
+    fn test_training_example_validation() {
+        assert_eq!(
+            TrainingExample::new(vec![]).validate(),
+            Err(TrainingExampleError::NoMessages)
+        );
+        assert_eq!(
+            TrainingExample::new(vec![ChatMessage::user("Hi!")]).validate(),
+            Err(TrainingExampleError::NoAssistantMessage)
+        );
+        assert_eq!(
+            TrainingExample::new(vec![ChatMessage::user(" "), ChatMessage::assistant("Hi!")])
+                .validate(),
+            Err(TrainingExampleError::EmptyMessage { message: 0 })
+        );
+
+        let call = ChatMessage {
+            tool_calls: Some(vec![weather_call()]),
+            ..ChatMessage::assistant("")
+        };
+        let messages = vec![
+            ChatMessage::user("What's the weather in Paris?"),
+            call,
+            ChatMessage::tool("call_1", "Sunny, 24°C"),
+            ChatMessage::assistant("It's sunny and 24°C in Paris."),
+        ];
+
+        assert_eq!(
+            TrainingExample::new(messages.clone()).validate(),
+            Err(TrainingExampleError::MissingTools { message: 1 })
+        );
+
+        let tools = vec![Tool::function(FunctionSpec {
+            name: "get_weather".into(),
+            ..Default::default()
+        })];
+        assert_eq!(
+            TrainingExample::new(messages.clone())
+                .tools(tools.clone())
+                .validate(),
+            Ok(())
+        );
+
+        let mut unknown = messages.clone();
+        unknown[2] = ChatMessage::tool("call_2", "Sunny, 24°C");
+        assert_eq!(
+            TrainingExample::new(unknown).tools(tools).validate(),
+            Err(TrainingExampleError::UnknownToolCall {
+                message: 2,
+                id: "call_2".into()
+            })
+        );
+
+        let other = vec![Tool::function(FunctionSpec {
+            name: "get_time".into(),
+            ..Default::default()
+        })];
+        assert_eq!(
+            TrainingExample::new(messages).tools(other).validate(),
+            Err(TrainingExampleError::UndefinedFunction {
+                message: 1,
+                name: "get_weather".into()
+            })
+        );
+    }
+
+    #[test]
+    fn test_training_file_writer() {
+        let mut writer = TrainingFileWriter::in_memory();
+        writer
+            .write(&TrainingExample::new(vec![
+                ChatMessage::user("Hi!"),
+                ChatMessage::assistant("Hello!"),
+            ]))
+            .unwrap();
+
+        assert!(matches!(
+            writer.write(&TrainingExample::new(vec![ChatMessage::user("Hi!")])),
+            Err(Error::TrainingExampleError {
+                example: 1,
+                source: TrainingExampleError::NoAssistantMessage
+            })
+        ));
+        assert_eq!(writer.len(), 1);
+
+        let jsonl = String::from_utf8(writer.into_inner().unwrap()).unwrap();
+        assert_eq!(jsonl.lines().count(), 1);
+
+        let example: TrainingExample = serde_json::from_str(jsonl.trim_end()).unwrap();
+        assert_eq!(example.messages.len(), 2);
+        assert!(example.tools.is_none());
+    }
+}
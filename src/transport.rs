@@ -0,0 +1,256 @@
+//! The HTTP layer the requests to the API go through, replaceable to test code built on the [`Client`](crate::Client)
+//! without hitting the network.
+//!
+//! Requests are built, authorized and retried by the client as usual, a [`Transport`] only sending them.
+//! [`MockTransport`] answers them with canned responses instead, recording them to be asserted on.
+//!
+//! ## Example
+//! ```
+//! use std::sync::Arc;
+//! use fieri::{Client, transport::MockTransport};
+//! use reqwest::Method;
+//! use serde_json::json;
+//!
+//! // The application code under test.
+//! async fn count_models(client: &Client) -> Result<usize, fieri::Error> {
+//!     Ok(fieri::model::list(client).await?.data.len())
+//! }
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let mock = Arc::new(MockTransport::new().respond(
+//!         Method::GET,
+//!         "models",
+//!         json!({"object": "list", "data": [{"id": "gpt-4o", "object": "model", "created": 1715367049, "owned_by": "system"}]}),
+//!     ));
+//!     let client = Client::new().transport(mock.clone());
+//!
+//!     assert_eq!(count_models(&client).await?, 1);
+//!     assert_eq!(mock.requests()[0].path, "models");
+//!
+//!     Ok(())
+//! }
+//! ```
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::{collections::VecDeque, sync::Mutex};
+
+#[cfg(not(target_arch = "wasm32"))]
+use reqwest::{Method, StatusCode};
+#[cfg(not(target_arch = "wasm32"))]
+use serde::Serialize;
+
+use crate::Result;
+
+/// The future of a response, `Send` but on wasm, whose requests are bound to the JavaScript thread.
+#[cfg(not(target_arch = "wasm32"))]
+pub type TransportFuture<'a> = futures::future::BoxFuture<'a, Result<reqwest::Response>>;
+/// The future of a response, `Send` but on wasm, whose requests are bound to the JavaScript thread.
+#[cfg(target_arch = "wasm32")]
+pub type TransportFuture<'a> = futures::future::LocalBoxFuture<'a, Result<reqwest::Response>>;
+
+/// Sends the requests of the client, returning their responses.
+pub trait Transport: std::fmt::Debug + Send + Sync {
+    fn execute(&self, request: reqwest::Request) -> TransportFuture<'_>;
+}
+
+impl Transport for reqwest::Client {
+    fn execute(&self, request: reqwest::Request) -> TransportFuture<'_> {
+        Box::pin(async move { Ok(reqwest::Client::execute(self, request).await?) })
+    }
+}
+
+/// A request received by a [`MockTransport`].
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct MockRequest {
+    pub method: Method,
+
+    /// The path of the request, without its `/v1` prefix, like `chat/completions`.
+    pub path: String,
+
+    /// The JSON body of the request, if any.
+    pub body: Option<serde_json::Value>,
+}
+
+// The responses of a route, the last one answering all the following requests.
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Debug)]
+struct MockRoute {
+    method: Method,
+    path: String,
+    responses: VecDeque<(StatusCode, String)>,
+}
+
+/// Answers the requests with the responses set for their method and path, recording them.
+///
+/// The responses set for the same route are returned in order, the last one repeating.
+/// Requests without a response fail with a `404` error of the API.
+///
+/// Unavailable on wasm, whose responses can't be built out of thin air.
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Debug, Default)]
+pub struct MockTransport {
+    routes: Mutex<Vec<MockRoute>>,
+    requests: Mutex<Vec<MockRequest>>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl MockTransport {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Answers the requests to the path, like `chat/completions`, with the given JSON.
+    pub fn respond(self, method: Method, path: &str, body: impl Serialize) -> Self {
+        self.respond_with(method, path, StatusCode::OK, body)
+    }
+
+    /// Answers the requests to the path with the given status and JSON, like an error.
+    pub fn respond_with(
+        self,
+        method: Method,
+        path: &str,
+        status: StatusCode,
+        body: impl Serialize,
+    ) -> Self {
+        let body = serde_json::to_string(&body).expect("Unable to serialize the mock response.");
+        let path = path.trim_matches('/').to_string();
+
+        {
+            let mut routes = self.routes.lock().expect("The mock lock is poisoned.");
+            match routes
+                .iter_mut()
+                .find(|route| route.method == method && route.path == path)
+            {
+                Some(route) => route.responses.push_back((status, body)),
+                None => routes.push(MockRoute {
+                    method,
+                    path,
+                    responses: VecDeque::from([(status, body)]),
+                }),
+            }
+        }
+
+        self
+    }
+
+    /// The requests received so far, in order.
+    pub fn requests(&self) -> Vec<MockRequest> {
+        self.requests
+            .lock()
+            .expect("The mock lock is poisoned.")
+            .clone()
+    }
+
+    // Takes the next response of the route of the request.
+    fn response(&self, method: &Method, path: &str) -> Option<(StatusCode, String)> {
+        let mut routes = self.routes.lock().expect("The mock lock is poisoned.");
+        let route = routes.iter_mut().find(|route| {
+            route.method == method
+                && (path == route.path || path.ends_with(&format!("/{}", route.path)))
+        })?;
+
+        if route.responses.len() > 1 {
+            route.responses.pop_front()
+        } else {
+            route.responses.front().cloned()
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Transport for MockTransport {
+    fn execute(&self, request: reqwest::Request) -> TransportFuture<'_> {
+        let path = request.url().path().trim_matches('/').to_string();
+        let path = path
+            .split_once('/')
+            .filter(|(version, _)| *version == "v1")
+            .map_or(path.clone(), |(_, path)| path.to_string());
+        let body = request
+            .body()
+            .and_then(reqwest::Body::as_bytes)
+            .and_then(|body| serde_json::from_slice(body).ok());
+
+        let (status, response) = self.response(request.method(), &path).unwrap_or_else(|| {
+            let error = serde_json::json!({"error": {
+                "message": format!("No mock response for {} {path}.", request.method()),
+                "type": "invalid_request_error",
+                "param": null,
+                "code": null,
+            }});
+            (StatusCode::NOT_FOUND, error.to_string())
+        });
+
+        self.requests
+            .lock()
+            .expect("The mock lock is poisoned.")
+            .push(MockRequest {
+                method: request.method().clone(),
+                path,
+                body,
+            });
+
+        let response = http::Response::builder()
+            .status(status)
+            .header("content-type", "application/json")
+            .body(response)
+            .expect("Unable to build the mock response.");
+        Box::pin(async move { Ok(response.into()) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Client, Error};
+    use serde_json::json;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn test_mock_transport() {
+        let mock = Arc::new(
+            MockTransport::new()
+                .respond_with(
+                    Method::POST,
+                    "chat/completions",
+                    StatusCode::BAD_REQUEST,
+                    json!({"error": {"message": "Invalid model", "type": "invalid_request_error", "param": "model", "code": null}}),
+                )
+                .respond(Method::POST, "chat/completions", json!({"id": "chatcmpl-1"})),
+        );
+        let client = Client::new().transport(mock.clone());
+
+        let param = json!({"model": "gpt-4o", "messages": []});
+        assert!(matches!(
+            client
+                .post::<_, serde_json::Value>("chat/completions", Some(&param))
+                .await,
+            Err(Error::APIError(err)) if err.error.message == "Invalid model"
+        ));
+        for _ in 0..2 {
+            let resp = client
+                .post::<_, serde_json::Value>("chat/completions", Some(&param))
+                .await
+                .unwrap();
+            assert_eq!(resp["id"], "chatcmpl-1");
+        }
+
+        assert!(matches!(
+            client.get::<(), serde_json::Value>("models", None).await,
+            Err(Error::APIError(err)) if err.error.message == "No mock response for GET models."
+        ));
+
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 4);
+        assert_eq!(
+            requests[0],
+            MockRequest {
+                method: Method::POST,
+                path: "chat/completions".to_string(),
+                body: Some(param),
+            }
+        );
+        assert_eq!(requests[3].body, None);
+    }
+}
@@ -2,23 +2,26 @@
 
 use std::{
     borrow::Cow,
+    collections::HashMap,
     fmt::Display,
     fs,
     io::{copy, Cursor},
-    path::Path,
+    path::{Path, PathBuf},
     str::FromStr,
+    sync::{Arc, Mutex},
+    time::Duration,
 };
 
-use clap::{builder, Parser};
+use clap::Parser;
 use derive_builder::Builder;
-use reqwest::{
-    get,
-    multipart::{Form, Part},
-};
+use reqwest::get;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
-use crate::{utils::is_false, Client, Result};
+use crate::{
+    utils::{float_or_base64, is_false, null_as_default},
+    Error, Result,
+};
 
 /// Tokens used for the requested action from OpenAI.
 #[derive(Clone, Debug, std::default::Default, serde::Deserialize, serde::Serialize)]
@@ -27,6 +30,42 @@ pub struct TokenUsage {
     pub prompt_tokens: u32,
     pub completion_tokens: u32,
     pub total_tokens: u32,
+
+    /// Breakdown of the tokens used in the prompt.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prompt_tokens_details: Option<PromptTokensDetails>,
+
+    /// Breakdown of the tokens used in the completion.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completion_tokens_details: Option<CompletionTokensDetails>,
+}
+
+/// Breakdown of the tokens used in a prompt.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct PromptTokensDetails {
+    /// Cached tokens present in the prompt.
+    pub cached_tokens: u32,
+
+    /// Audio input tokens present in the prompt.
+    pub audio_tokens: u32,
+}
+
+/// Breakdown of the tokens used in a completion.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct CompletionTokensDetails {
+    /// Tokens generated by the model for reasoning.
+    pub reasoning_tokens: u32,
+
+    /// Audio tokens generated by the model.
+    pub audio_tokens: u32,
+
+    /// Tokens of the prediction that appeared in the completion, when using Predicted Outputs.
+    pub accepted_prediction_tokens: u32,
+
+    /// Tokens of the prediction that did not appear in the completion, when using Predicted Outputs.
+    pub rejected_prediction_tokens: u32,
 }
 
 #[derive(Clone, Debug, std::default::Default, serde::Deserialize, serde::Serialize)]
@@ -41,7 +80,23 @@ pub struct Choices {
     pub finish_reason: Option<String>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub logprobs: Option<f32>,
+    pub logprobs: Option<CompletionLogprobs>,
+}
+
+/// Log probability information of a completion choice, as requested with `logprobs`.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct CompletionLogprobs {
+    pub tokens: Vec<String>,
+
+    /// The log probability of each of the `tokens`.
+    pub token_logprobs: Vec<Option<f64>>,
+
+    /// The most likely tokens and their log probability, at each token position.
+    pub top_logprobs: Vec<Option<HashMap<String, f64>>>,
+
+    /// The character offset of each of the `tokens` in the text.
+    pub text_offset: Vec<u32>,
 }
 
 /// Information from requests wishing for a resource to be deleted, like [`Delete File`](crate::file::delete) and [`Delete Fine-tune`](crate::fine_tune::delete).
@@ -68,12 +123,20 @@ pub struct File {
     pub purpose: String,
     pub status: String,
 
+    /// The Unix timestamp (in seconds) at which the file expires, if it does.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<i64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_details: Option<String>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub token_usage: Option<TokenUsage>,
 }
 
 type Files = Vec<File>;
 
+/// Parameters for [`Create Chat Completion`](crate::chat::create) request.
 #[skip_serializing_none]
 #[derive(Builder, Clone, Debug, Default, Deserialize, Serialize, Parser)]
 #[builder(default, setter(into, strip_option))]
@@ -86,28 +149,108 @@ pub struct ChatParam {
     #[clap(long, default_value = "gpt-3.5-turbo")]
     pub model: String,
 
+    /// Parameters for audio output. Required when audio output is requested with `modalities: ["audio"]`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(skip)]
+    pub audio: Option<ChatAudioParam>,
+
     /// Positive values penalize new tokens based on their existing frequency in the text so far,
     /// decreasing the model's likelihood to repeat the same line verbatim.
     #[serde(skip_serializing_if = "Option::is_none")]
     #[clap(long)]
     pub frequency_penalty: Option<f32>,
 
+    /// Deprecated in favor of `tool_choice`.
+    ///
+    /// Controls how the model responds to function calls.
+    /// `none` is the default when no functions are present, `auto` is the default if functions are present.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(skip)]
+    pub function_call: Option<FunctionChoice>,
+
+    /// Deprecated in favor of `tools`.
+    ///
+    /// A list of functions the model may generate JSON inputs for.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(skip)]
+    pub functions: Option<Vec<FunctionSpec>>,
+
+    /// Modify the likelihood of specified tokens appearing in the completion.
+    ///
+    /// Maps token ids to a bias value from -100 to 100, see [`logit_bias_words`](ChatParamBuilder::logit_bias_words)
+    /// for building it out of words instead, with the `tokenizer` feature.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(skip)]
+    pub logit_bias: Option<HashMap<u32, i32>>,
+
+    /// Whether to return log probabilities of the output tokens or not.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long)]
+    pub logprobs: Option<bool>,
+
+    /// An upper bound for the number of tokens that can be generated for a completion, including visible output tokens and reasoning tokens.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long)]
+    pub max_completion_tokens: Option<u32>,
+
     /// The maximum number of tokens to generate in the chat completion.
+    ///
+    /// Deprecated in favor of `max_completion_tokens` and rejected by reasoning models, like the o-series,
+    /// for which it is sent as `max_completion_tokens` instead.
     #[serde(skip_serializing_if = "Option::is_none")]
     #[clap(long)]
     pub max_tokens: Option<u32>,
 
+    /// Developer-defined tags and values used for filtering completions in the dashboard, when `store` is set.
+    ///
+    /// Up to 16 key-value pairs, with keys up to 64 characters and values up to 512 characters.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(skip)]
+    pub metadata: Option<HashMap<String, String>>,
+
+    /// Output types that you would like the model to generate. Defaults to `["text"]`.
+    ///
+    /// Models with audio capabilities can generate audio, by requesting `["text", "audio"]`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(skip)]
+    pub modalities: Option<Vec<Modality>>,
+
     /// How many chat completion choices to generate for each input message.
     #[serde(skip_serializing_if = "Option::is_none")]
     #[clap(long)]
     pub n: Option<u32>,
 
+    /// Whether to enable parallel function calling during tool use.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(skip)]
+    pub parallel_tool_calls: Option<bool>,
+
+    /// Configuration for Predicted Outputs, which can greatly improve response times
+    /// when large parts of the model response are known ahead of time, like when regenerating a file with minor changes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(skip)]
+    pub prediction: Option<Prediction>,
+
     /// Positive values penalize new tokens based on whether they appear in the text so far,
     /// increasing the model's likelihood to talk about new topics.
     #[serde(skip_serializing_if = "Option::is_none")]
     #[clap(long)]
     pub presence_penalty: Option<f32>,
 
+    /// Constrains the effort on reasoning for reasoning models, like the o-series.
+    ///
+    /// Reducing the effort can result in faster responses and fewer tokens used on reasoning.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long, value_enum)]
+    pub reasoning_effort: Option<ReasoningEffort>,
+
+    /// An object specifying the format that the model must output.
+    ///
+    /// Setting it to [`ResponseFormat::JsonSchema`] enables Structured Outputs, which ensures the model will match the supplied JSON schema.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(skip)]
+    pub response_format: Option<ResponseFormat>,
+
     /// This feature is in Beta.
     ///
     /// If specified, our system will make a best effort to sample deterministically,
@@ -117,16 +260,35 @@ pub struct ChatParam {
     #[clap(long)]
     pub seed: Option<u64>,
 
+    /// The latency tier to use for processing the request, relevant for customers subscribed to the scale tier service.
+    ///
+    /// The tier actually used is echoed back in the [`service_tier`](Chat::service_tier) of the response.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long, value_enum)]
+    pub service_tier: Option<ServiceTier>,
+
     /// Up to 4 sequences where the API will stop generating further tokens.
     #[serde(skip_serializing_if = "Option::is_none")]
     #[clap(long)]
-    pub stop: Option<String>,
+    pub stop: Option<Stop>,
+
+    /// Whether or not to store the output of this chat completion request, for use in model distillation or evals.
+    ///
+    /// Stored completions can be managed with [`list`](crate::chat::list), [`retrieve`](crate::chat::retrieve) and [`delete`](crate::chat::delete).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long)]
+    pub store: Option<bool>,
 
     /// If set, partial message deltas will be sent, like in ChatGPT.
-    #[serde(skip_serializing_if = "is_false")]
+    #[serde(default, skip_serializing_if = "is_false")]
     #[clap(long)]
     pub stream: bool,
 
+    /// Options for the streamed response, only set along with `stream`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(skip)]
+    pub stream_options: Option<StreamOptions>,
+
     /// What sampling temperature to use, between 0 and 2.
     /// Higher values like 0.8 will make the output more random,
     /// while lower values like 0.2 will make it more focused and deterministic.
@@ -134,6 +296,24 @@ pub struct ChatParam {
     #[clap(long)]
     pub temperature: Option<f32>,
 
+    /// Controls which (if any) tool is called by the model.
+    ///
+    /// `none` is the default when no tools are present, `auto` is the default if tools are present.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(skip)]
+    pub tool_choice: Option<ToolChoice>,
+
+    /// A list of tools the model may call. Currently, only functions are supported as a tool.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(skip)]
+    pub tools: Option<Vec<Tool>>,
+
+    /// An integer between 0 and 20 specifying the number of most likely tokens to return at each token position,
+    /// each with an associated log probability. `logprobs` must be set to `true` if this parameter is used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[clap(long)]
+    pub top_logprobs: Option<u8>,
+
     /// An alternative to sampling with temperature, called nucleus sampling,
     /// where the model considers the results of the tokens with top_p probability mass.
     /// So 0.1 means only the tokens comprising the top 10% probability mass are considered.
@@ -147,6 +327,110 @@ pub struct ChatParam {
     pub user: Option<String>,
 }
 
+/// Sequences where the API will stop generating further tokens, either a single one or a list of up to 4.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum Stop {
+    Single(String),
+    Many(Vec<String>),
+}
+
+impl From<&str> for Stop {
+    fn from(value: &str) -> Self {
+        Self::Single(value.to_string())
+    }
+}
+
+impl From<String> for Stop {
+    fn from(value: String) -> Self {
+        Self::Single(value)
+    }
+}
+
+impl From<Vec<&str>> for Stop {
+    fn from(value: Vec<&str>) -> Self {
+        Self::Many(value.into_iter().map(String::from).collect())
+    }
+}
+
+impl From<Vec<String>> for Stop {
+    fn from(value: Vec<String>) -> Self {
+        Self::Many(value)
+    }
+}
+
+/// Static predicted output content, such as the content of a text file that is being regenerated.
+///
+/// How much of the prediction was used is reported in the [`CompletionTokensDetails`] of the usage.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Prediction {
+    /// The content that should be matched when generating a model response.
+    Content { content: ChatContent },
+}
+
+impl Prediction {
+    pub fn content(content: impl Into<ChatContent>) -> Self {
+        Self::Content {
+            content: content.into(),
+        }
+    }
+}
+
+/// The effort a reasoning model spends on reasoning before responding. Defaults to `medium`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize, clap::ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum ReasoningEffort {
+    Low,
+    Medium,
+    High,
+}
+
+/// The latency tier used for processing a request.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize, clap::ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum ServiceTier {
+    /// Uses scale tier credits until they are exhausted if the project is scale tier enabled, the default tier otherwise.
+    Auto,
+
+    /// The default service tier, with a lower uptime SLA and no latency guarantee.
+    Default,
+
+    /// Flex processing, with lower prices in exchange for slower responses and occasional resource unavailability.
+    Flex,
+
+    /// The scale tier, using the scale tier credits of the project.
+    Scale,
+
+    /// Priority processing, with faster responses at a premium.
+    Priority,
+
+    /// Any other tier returned by the API, unknown to this version of the crate.
+    #[serde(other)]
+    #[value(skip)]
+    Other,
+}
+
+/// Options for a streamed response.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct StreamOptions {
+    /// If set, an additional chunk is streamed before the end of the stream, carrying the token usage of the entire request.
+    ///
+    /// The `choices` of that chunk are always empty.
+    pub include_usage: bool,
+}
+
+impl StreamOptions {
+    /// Stream options requesting the token usage of the request.
+    pub fn include_usage() -> Self {
+        Self {
+            include_usage: true,
+        }
+    }
+}
+
+/// A single message of a chat conversation.
 #[skip_serializing_none]
 #[derive(Builder, Clone, Debug, Default, Deserialize, Serialize)]
 #[builder(default, setter(into, strip_option))]
@@ -154,35 +438,66 @@ pub struct ChatMessage {
     /// The role of the author of this message. One of system, user, or assistant.
     pub role: ChatRole,
 
-    /// The contents of the message.
-    pub content: String,
+    /// The contents of the message, either text or a list of [`content parts`](ContentPart).
+    ///
+    /// Empty for assistant messages that only contain a function call.
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub content: ChatContent,
 
     /// The name of the author of this message. May contain a-z, A-Z, 0-9, and underscores, with a maximum length of 64 characters.
+    ///
+    /// Required for messages with the `function` role, where it's the name of the function whose result is in the content.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
+
+    /// The name and arguments of a function that should be called, as generated by the model.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub function_call: Option<FunctionCall>,
+
+    /// The tool calls generated by the model, such as function calls.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool_calls: Option<Vec<ToolCall>>,
+
+    /// Tool call that this message is responding to, required for messages with the `tool` role.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool_call_id: Option<String>,
+
+    /// The refusal message generated by the model, when it refuses to respond with Structured Outputs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refusal: Option<String>,
+
+    /// The audio response of the model, when audio output was requested.
+    ///
+    /// When sent back in an assistant message, only the `id` is needed to refer to a previous audio response.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub audio: Option<ChatAudio>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// The role of the author of a [`ChatMessage`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
 pub enum ChatRole {
     System,
+    #[default]
     User,
     Assistant,
     Function,
+    Tool,
 }
 
-impl Default for ChatRole {
-    fn default() -> Self {
-        Self::User
+impl From<String> for ChatRole {
+    fn from(s: String) -> Self {
+        Self::from(s.as_str())
     }
 }
 
-impl From<String> for ChatRole {
-    fn from(s: String) -> Self {
-        match s.as_str() {
+impl From<&str> for ChatRole {
+    fn from(s: &str) -> Self {
+        match s {
             "system" => Self::System,
             "user" => Self::User,
             "assistant" => Self::Assistant,
             "function" => Self::Function,
+            "tool" => Self::Tool,
             _ => Self::User,
         }
     }
@@ -195,6 +510,7 @@ impl Display for ChatRole {
             ChatRole::User => "user",
             ChatRole::Assistant => "assistant",
             ChatRole::Function => "function",
+            ChatRole::Tool => "tool",
         };
         write!(f, "{}", s)
     }
@@ -218,831 +534,10229 @@ impl<'de> Deserialize<'de> for ChatRole {
     }
 }
 
-impl ChatMessageBuilder {
-    pub fn new(role: impl Into<ChatRole>, content: impl Into<String>) -> Self {
-        Self {
-            role: Some(role.into()),
-            content: Some(content.into()),
-            ..Self::default()
-        }
+/// The contents of a [`ChatMessage`].
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum ChatContent {
+    Text(String),
+
+    /// A list of content parts, used to supply images alongside the text.
+    Parts(Vec<ContentPart>),
+}
+
+impl Default for ChatContent {
+    fn default() -> Self {
+        Self::Text(String::new())
     }
 }
 
-impl From<String> for ChatMessage {
-    fn from(s: String) -> Self {
-        Self {
-            role: ChatRole::default(),
-            content: s,
-            name: Some("rand".to_string()),
+impl ChatContent {
+    /// Appends text to the content, as done while accumulating a streamed message.
+    pub fn push_str(&mut self, text: &str) {
+        match self {
+            ChatContent::Text(content) => content.push_str(text),
+            ChatContent::Parts(parts) => match parts.last_mut() {
+                Some(ContentPart::Text { text: content }) => content.push_str(text),
+                _ => parts.push(ContentPart::text(text)),
+            },
         }
     }
-}
 
-impl ChatParamBuilder {
-    pub fn new(model: impl Into<String>, messages: Vec<ChatMessage>) -> Self {
-        Self {
-            model: Some(model.into()),
-            messages: Some(messages),
-            ..Self::default()
+    pub fn is_empty(&self) -> bool {
+        match self {
+            ChatContent::Text(content) => content.is_empty(),
+            ChatContent::Parts(parts) => parts.is_empty(),
         }
     }
 }
 
-#[derive(Builder, Clone, Debug, Default, Deserialize, Serialize)]
-pub struct ChatChoice {
-    pub index: u32,
-    pub message: ChatMessage,
-    pub finish_reason: Option<String>,
+impl Display for ChatContent {
+    /// Writes the text of the content, joining the text parts by a new line.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ChatContent::Text(content) => write!(f, "{content}"),
+            ChatContent::Parts(parts) => {
+                let texts = parts
+                    .iter()
+                    .filter_map(|part| match part {
+                        ContentPart::Text { text } => Some(text.as_str()),
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>();
+
+                write!(f, "{}", texts.join("\n"))
+            }
+        }
+    }
 }
 
-#[derive(Builder, Clone, Debug, Default, Deserialize, Serialize)]
-pub struct Chat {
-    id: String,
-    object: String,
-    created: i64,
-    pub choices: Vec<ChatChoice>,
+impl PartialEq<&str> for ChatContent {
+    fn eq(&self, other: &&str) -> bool {
+        matches!(self, ChatContent::Text(content) if content == other)
+    }
+}
 
-    pub usage: TokenUsage,
+impl From<String> for ChatContent {
+    fn from(s: String) -> Self {
+        Self::Text(s)
+    }
 }
 
-/// Parameters for [`Create Completion`](create) request.
-#[skip_serializing_none]
-#[derive(Builder, Clone, Debug, Default, Deserialize, Serialize)]
-#[builder(default, setter(into, strip_option))]
-pub struct CompletionParam {
-    /// The model to use for the completion request.
-    model: String,
+impl From<&str> for ChatContent {
+    fn from(s: &str) -> Self {
+        Self::Text(s.to_string())
+    }
+}
 
-    /// The prompt(s) to generate completions for.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    prompt: Option<Vec<String>>,
+impl From<Vec<ContentPart>> for ChatContent {
+    fn from(parts: Vec<ContentPart>) -> Self {
+        Self::Parts(parts)
+    }
+}
 
-    /// The suffix that comes after a completion of inserted text.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    suffix: Option<String>,
+/// A part of the contents of a [`ChatMessage`].
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ContentPart {
+    Text { text: String },
+    ImageUrl { image_url: ImageUrl },
+    InputAudio { input_audio: InputAudio },
+}
 
-    /// The maximum number of tokens to generate in the completion.
-    ///
-    /// The token count of your prompt plus `max_tokens` cannot exceed the model's context length.
-    /// Most models have a context length of 2048 tokens (except for the newest models, which support 4096).
-    #[serde(skip_serializing_if = "Option::is_none")]
-    max_tokens: Option<i32>,
+impl ContentPart {
+    /// Creates a text part.
+    pub fn text(text: impl Into<String>) -> Self {
+        Self::Text { text: text.into() }
+    }
 
-    /// What sampling temperature to use, between 0 and 2. Higher values means the model will take more risks.
-    ///
-    /// Try 0.9 for more creative applications, and 0 (argmax sampling) for ones with a well-defined answer.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    temperature: Option<f32>,
+    /// Creates an image part from the URL of an image, or a base64 encoded data URL.
+    pub fn image_url(url: impl Into<String>) -> Self {
+        Self::ImageUrl {
+            image_url: ImageUrl {
+                url: url.into(),
+                detail: None,
+            },
+        }
+    }
 
-    /// An alternative to sampling with temperature, called nucleus sampling, where the model considers the results of the tokens with top_p probability mass.
-    /// So 0.1 means only the tokens comprising the top 10% probability mass are considered.
-    ///
-    /// It's generally recommended to alter this or `temperature` but not both.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    top_p: Option<f32>,
+    /// Creates an image part from a local image, embedded as a base64 encoded data URL.
+    pub fn image_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        use base64::Engine;
 
-    /// How many completions to generate for each prompt.
-    ///
-    /// Note: Because this parameter generates many completions, it can quickly consume your token quota.
-    /// Use carefully and ensure that you have reasonable settings for `max_tokens` and `stop`.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    n: Option<u32>,
+        let data = fs::read(path.as_ref())?;
+        let mime = mime_guess::from_path(path.as_ref()).first_or_octet_stream();
+        let encoded = base64::engine::general_purpose::STANDARD.encode(data);
 
-    // Whether to stream back partial progress.
-    #[serde(skip_serializing_if = "is_false")]
-    stream: bool,
+        Ok(Self::image_url(format!("data:{mime};base64,{encoded}")))
+    }
 
-    /// Include the log probabilities on the `logprobs` most likely tokens, as well the chosen tokens.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    logprobs: Option<f32>,
+    /// Creates an image part from either a URL or a path to a local image.
+    pub fn image(path_or_url: impl AsRef<str>) -> Result<Self> {
+        let path_or_url = path_or_url.as_ref();
+        if ["http://", "https://", "data:"]
+            .iter()
+            .any(|scheme| path_or_url.starts_with(scheme))
+        {
+            Ok(Self::image_url(path_or_url))
+        } else {
+            Self::image_file(path_or_url)
+        }
+    }
 
-    /// Echo back the prompt in addition to the completion
-    #[serde(skip_serializing_if = "is_false")]
-    echo: bool,
+    /// Creates an audio part from base64 encoded audio data.
+    pub fn input_audio(data: impl Into<String>, format: InputAudioFormat) -> Self {
+        Self::InputAudio {
+            input_audio: InputAudio {
+                data: data.into(),
+                format,
+            },
+        }
+    }
 
-    /// Up to 4 sequences where the API will stop generating further tokens.
-    ///
-    /// The returned text will not contain the stop sequence.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    stop: Option<String>,
+    /// Creates an audio part from a local `wav` or `mp3` file, the format is derived from the extension.
+    pub fn input_audio_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        use base64::Engine;
 
-    /// Number between -2.0 and 2.0.
-    ///
-    /// Positive values penalize new tokens based on whether they appear in the text so far, increasing the model's likelihood to talk about new topics.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    presence_penalty: Option<f32>,
+        let format = match path.as_ref().extension().and_then(|ext| ext.to_str()) {
+            Some("mp3") => InputAudioFormat::Mp3,
+            _ => InputAudioFormat::Wav,
+        };
+        let data = fs::read(path.as_ref())?;
 
-    /// Number between -2.0 and 2.0.
-    ///
-    /// Positive values penalize new tokens based on their existing frequency in the text so far, decreasing the model's likelihood to repeat the same line verbatim.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    frequency_penalty: Option<f32>,
+        Ok(Self::input_audio(
+            base64::engine::general_purpose::STANDARD.encode(data),
+            format,
+        ))
+    }
 
-    /// Generates best_of completions server-side and returns the "best" (the one with the highest log probability per token).
-    ///
-    /// Results cannot be streamed.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    best_of: Option<u16>,
+    /// Sets the detail level of an image part. Has no effect on text parts.
+    pub fn detail(mut self, level: ImageDetail) -> Self {
+        if let Self::ImageUrl { image_url } = &mut self {
+            image_url.detail = Some(level);
+        }
 
-    /// A unique identifier representing your end-user, which can help OpenAI to monitor and detect abuse.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    user: Option<String>,
+        self
+    }
 }
 
-impl CompletionParamBuilder {
-    pub fn new(model: impl Into<String>) -> Self {
+/// An image supplied as a part of a message.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+pub struct ImageUrl {
+    /// Either a URL of the image or the base64 encoded image data.
+    pub url: String,
+
+    /// Specifies the detail level of the image.
+    pub detail: Option<ImageDetail>,
+}
+
+/// Audio supplied as a part of a message.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct InputAudio {
+    /// Base64 encoded audio data.
+    pub data: String,
+    pub format: InputAudioFormat,
+}
+
+/// The format of the audio supplied in a message.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InputAudioFormat {
+    #[default]
+    Wav,
+    Mp3,
+}
+
+/// Output types the model can generate.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Modality {
+    Text,
+    Audio,
+}
+
+/// Parameters for the audio output of the chat completion.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+pub struct ChatAudioParam {
+    /// The voice the model uses to respond, like `alloy`, `echo` or `shimmer`.
+    pub voice: String,
+
+    /// Specifies the output audio format.
+    pub format: ChatAudioFormat,
+}
+
+impl ChatAudioParam {
+    pub fn new(voice: impl Into<String>, format: ChatAudioFormat) -> Self {
         Self {
-            model: Some(model.into()),
-            ..Self::default()
+            voice: voice.into(),
+            format,
         }
     }
 }
 
-/// Response from [`Create completion`](create) request.
-#[derive(Debug, Default, Deserialize, Serialize)]
+/// The format of the audio generated by the model.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChatAudioFormat {
+    #[default]
+    Wav,
+    Mp3,
+    Flac,
+    Opus,
+    Pcm16,
+}
+
+/// The audio response of the model.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
 #[serde(default)]
-pub struct Completion {
+pub struct ChatAudio {
+    /// Unique identifier for the audio response, used to refer to it in follow-up messages.
     pub id: String,
-    pub object: String,
-    pub created: u64,
-    pub model: String,
-    pub choices: Vec<Choices>,
 
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub usage: Option<TokenUsage>,
-}
+    /// The Unix timestamp (in seconds) after which the audio response is no longer accessible on the server.
+    pub expires_at: Option<u64>,
 
-/// Parameters for [`Create Edit`](create) request.
-#[skip_serializing_none]
-#[derive(Builder, Debug, Default, Deserialize, Serialize)]
-#[builder(default, setter(into, strip_option))]
-pub struct EditParam {
-    /// The model to use for the edit request.
-    model: String,
+    /// Base64 encoded audio bytes generated by the model, in the format specified in the request.
+    pub data: Option<String>,
 
-    /// The instruction that tells the model how to edit the prompt.
-    instruction: String,
+    /// Transcript of the audio generated by the model.
+    pub transcript: Option<String>,
+}
 
-    /// The input text to use as a starting point for the edit.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    input: Option<String>,
+impl ChatAudio {
+    /// Refers to a previous audio response by its id, for use in a follow-up assistant message.
+    pub fn reference(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            ..Self::default()
+        }
+    }
 
-    /// How many edits to generate for the input and instruction.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    n: Option<u32>,
+    /// Decodes the base64 encoded audio data.
+    pub fn decode(&self) -> Result<Vec<u8>> {
+        use base64::Engine;
 
-    /// What sampling temperature to use. Higher values means the model will take more risks. Try 0.9 for more creative applications, and 0 (argmax sampling) for ones with a well-defined answer.
-    ///
-    /// It's recommended to alter this or `top_p` but not both.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    temperature: Option<f32>,
+        let data = self.data.as_deref().unwrap_or_default();
+        Ok(base64::engine::general_purpose::STANDARD.decode(data)?)
+    }
+}
 
-    /// An alternative to sampling with temperature, called nucleus sampling, where the model considers the results of the tokens with top_p probability mass. So 0.1 means only the tokens comprising the top 10% probability mass are considered.
-    ///
-    /// It's recommended to alter this or `temperature` but not both.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    top_p: Option<f32>,
+/// The detail level of an image, controlling how the model processes it and generates its textual understanding.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ImageDetail {
+    #[default]
+    Auto,
+    Low,
+    High,
 }
 
-impl EditParamBuilder {
-    pub fn new(model: impl Into<String>, instruction: impl Into<String>) -> Self {
+impl ChatMessageBuilder {
+    pub fn new(role: impl Into<ChatRole>, content: impl Into<ChatContent>) -> Self {
         Self {
-            model: Some(model.into()),
-            instruction: Some(instruction.into()),
+            role: Some(role.into()),
+            content: Some(content.into()),
             ..Self::default()
         }
     }
 }
 
-/// Response from [`Create Edit`](create) request.
-#[derive(Debug, Default, Deserialize)]
-#[serde(default)]
-pub struct Edit {
-    pub object: String,
-    pub created: u64,
-    pub choices: Vec<Choices>,
+impl ChatMessage {
+    /// Creates a message with the given role and content.
+    pub fn new(role: impl Into<ChatRole>, content: impl Into<ChatContent>) -> Self {
+        Self {
+            role: role.into(),
+            content: content.into(),
+            ..Self::default()
+        }
+    }
 
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub usage: Option<TokenUsage>,
+    /// Creates a `system` message, used to set the behavior of the assistant.
+    pub fn system(content: impl Into<ChatContent>) -> Self {
+        Self::new(ChatRole::System, content)
+    }
+
+    /// Creates a `user` message.
+    pub fn user(content: impl Into<ChatContent>) -> Self {
+        Self::new(ChatRole::User, content)
+    }
+
+    /// Creates a `user` message containing the text, followed by an image.
+    ///
+    /// The image is either a URL, or a path to a local image which gets embedded as a base64 encoded data URL.
+    pub fn user_with_image(text: impl Into<String>, path_or_url: impl AsRef<str>) -> Result<Self> {
+        Ok(Self::user(vec![
+            ContentPart::text(text),
+            ContentPart::image(path_or_url)?,
+        ]))
+    }
+
+    /// Creates an `assistant` message, usually a previous response of the model.
+    pub fn assistant(content: impl Into<ChatContent>) -> Self {
+        Self::new(ChatRole::Assistant, content)
+    }
+
+    /// Creates a `function` message, containing the result of calling the function `name`.
+    pub fn function(name: impl Into<String>, content: impl Into<String>) -> Self {
+        Self {
+            name: Some(name.into()),
+            ..Self::new(ChatRole::Function, ChatContent::Text(content.into()))
+        }
+    }
+
+    /// Creates a `tool` message, containing the result of the tool call with the given id.
+    pub fn tool(tool_call_id: impl Into<String>, content: impl Into<String>) -> Self {
+        Self {
+            tool_call_id: Some(tool_call_id.into()),
+            ..Self::new(ChatRole::Tool, ChatContent::Text(content.into()))
+        }
+    }
 }
 
-/// Parameters for [`Create Embedding`](create) request.
+/// Description of a function the model may generate JSON inputs for.
 #[skip_serializing_none]
-#[derive(Builder, Debug, Default, Deserialize, Serialize)]
+#[derive(Builder, Clone, Debug, Default, Deserialize, Serialize)]
 #[builder(default, setter(into, strip_option))]
-pub struct EmbeddingParam {
-    /// The model to use for the embedding request.
-    model: String,
+pub struct FunctionSpec {
+    /// The name of the function to be called. Must be a-z, A-Z, 0-9, or contain underscores and dashes, with a maximum length of 64.
+    pub name: String,
 
-    /// Input text to get embeddings for, encoded as a string.
-    ///
-    /// Each input must not exceed 8192 tokens in length.
-    input: String,
+    /// A description of what the function does, used by the model to choose when and how to call the function.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
 
-    /// A unique identifier representing your end-user, which can help OpenAI to monitor and detect abuse.
-    user: Option<String>,
+    /// The parameters the function accepts, described as a JSON Schema object.
+    pub parameters: serde_json::Value,
 }
 
-impl EmbeddingParamBuilder {
-    pub fn new(model: impl Into<String>, input: impl Into<String>) -> Self {
+impl FunctionSpecBuilder {
+    pub fn new(name: impl Into<String>, parameters: serde_json::Value) -> Self {
         Self {
-            model: Some(model.into()),
-            input: Some(input.into()),
+            name: Some(name.into()),
+            parameters: Some(parameters),
             ..Self::default()
         }
     }
 }
 
-/// Response from [`Create Embedding`](create) request.
-#[derive(Clone, Debug, Default, Deserialize, Serialize)]
-#[serde(default)]
-pub struct Embedding {
-    pub object: String,
-    pub data: Vec<EmbeddingData>,
-    pub mode: String,
+/// Controls how the model responds to function calls.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FunctionChoice {
+    /// The model can pick between an end-user or calling a function.
+    Auto,
 
-    pub usage: Option<TokenUsage>,
+    /// The model doesn't call a function and responds to the end-user.
+    None,
+
+    /// Forces the model to call the function with the given name.
+    Function(String),
 }
 
-/// The distance between two vectors measures their relatedness. Small distances suggest high relatedness and large distances suggest low relatedness.
-#[derive(Clone, Debug, Deserialize, Serialize)]
-pub struct EmbeddingData {
-    pub object: String,
-    pub embedding: Embeddings,
-    pub index: u64,
+impl Serialize for FunctionChoice {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        match self {
+            FunctionChoice::Auto => serializer.serialize_str("auto"),
+            FunctionChoice::None => serializer.serialize_str("none"),
+            FunctionChoice::Function(name) => {
+                serde_json::json!({ "name": name }).serialize(serializer)
+            }
+        }
+    }
 }
 
-type Embeddings = Vec<f32>;
+impl<'de> Deserialize<'de> for FunctionChoice {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            Mode(String),
+            Function { name: String },
+        }
 
-/// Response from [`List File`](list) request.
-#[derive(Debug, Default, Deserialize, Serialize)]
+        match Repr::deserialize(deserializer)? {
+            Repr::Mode(mode) if mode == "auto" => Ok(Self::Auto),
+            Repr::Mode(mode) if mode == "none" => Ok(Self::None),
+            Repr::Mode(mode) => Err(serde::de::Error::unknown_variant(&mode, &["auto", "none"])),
+            Repr::Function { name } => Ok(Self::Function(name)),
+        }
+    }
+}
+
+/// The name and arguments of a function that should be called, as generated by the model.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 #[serde(default)]
-pub struct ListFiles {
-    pub data: Files,
-    pub object: String,
+pub struct FunctionCall {
+    pub name: String,
 
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub token_usage: Option<TokenUsage>,
+    /// The arguments to call the function with, as generated by the model in JSON format.
+    ///
+    /// Note that the model does not always generate valid JSON, and may hallucinate parameters not defined by your function schema.
+    pub arguments: String,
 }
 
-/// The Possible Purposes of the uploaded documents.
-#[derive(Debug, Default, Deserialize, Serialize)]
-pub enum Purpose {
+impl FunctionCall {
+    /// Deserializes the generated arguments into `T`.
+    pub fn parse_arguments<T: serde::de::DeserializeOwned>(&self) -> Result<T> {
+        Ok(serde_json::from_str(&self.arguments)?)
+    }
+}
+
+/// The type of a tool. Currently, only `function` is supported.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ToolType {
     #[default]
-    FineTune,
-    Answers,
-    Search,
-    Classifications,
+    Function,
 }
 
-impl std::fmt::Display for Purpose {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Purpose::FineTune => write!(f, "fine-tune"),
-            Purpose::Answers => write!(f, "answers"),
-            Purpose::Search => write!(f, "search"),
-            Purpose::Classifications => write!(f, "classifications"),
+/// A tool the model may call.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Tool {
+    pub r#type: ToolType,
+    pub function: FunctionSpec,
+}
+
+impl Tool {
+    /// Creates a tool for the given function.
+    pub fn function(function: FunctionSpec) -> Self {
+        Self {
+            r#type: ToolType::Function,
+            function,
         }
     }
 }
 
-/// Parameters for [`Create Fine-tune`](create) request.
-#[skip_serializing_none]
-#[derive(Builder, Debug, Default, Deserialize, Serialize)]
-#[builder(default, setter(into, strip_option))]
-pub struct CreateFineTuneParam {
-    /// The ID of an uploaded file that contains training data.
-    ///
-    /// See [upload](crate::file::upload) file for how to upload a file.
-    training_file: String,
+impl From<FunctionSpec> for Tool {
+    fn from(function: FunctionSpec) -> Self {
+        Self::function(function)
+    }
+}
 
-    /// The ID of an uploaded file that contains validation data.
-    ///
-    /// If you provide this file, the data is used to generate validation metrics periodically during fine-tuning. These metrics can be viewed in the fine-tuning results file.
-    /// Your train and validation data should be mutually exclusive.
-    // Note: Even though it's given as "optional" in the docs, it's required in the API.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    validation_file: Option<String>,
+/// Controls which (if any) tool is called by the model.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ToolChoice {
+    /// The model can pick between generating a message or calling one or more tools.
+    Auto,
 
-    /// The name of the base model to fine-tune. You can select one of "ada", "babbage", "curie", "davinci", or a fine-tuned model created after 2022-04-21.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    model: Option<String>,
+    /// The model will not call any tool and instead generates a message.
+    None,
 
-    /// The number of epochs to train the model for. An epoch refers to one full cycle through the training dataset.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    n_epochs: Option<i32>,
+    /// The model must call one or more tools.
+    Required,
 
-    /// The batch size to use for training. The batch size is the number of training examples used to train a single forward and backward pass.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    batch_size: Option<i32>,
+    /// Forces the model to call the function with the given name.
+    Function(String),
+}
 
-    /// The learning rate multiplier to use for training. The fine-tuning learning rate is the original learning rate used for pretraining multiplied by this value.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    learning_rate_multiplier: Option<f32>,
+impl Serialize for ToolChoice {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        match self {
+            ToolChoice::Auto => serializer.serialize_str("auto"),
+            ToolChoice::None => serializer.serialize_str("none"),
+            ToolChoice::Required => serializer.serialize_str("required"),
+            ToolChoice::Function(name) => {
+                serde_json::json!({ "type": "function", "function": { "name": name } })
+                    .serialize(serializer)
+            }
+        }
+    }
+}
 
-    /// The weight to use for loss on the prompt tokens.
-    ///
-    /// This controls how much the model tries to learn to generate the prompt (as compared to the completion which always has a weight of 1.0), and can add a stabilizing effect to training when completions are short.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    prompt_loss_weight: Option<f32>,
+impl<'de> Deserialize<'de> for ToolChoice {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        struct Name {
+            name: String,
+        }
 
-    /// If set, we calculate classification-specific metrics such as accuracy and F-1 score using the validation set at the end of every epoch.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    compute_classification_metrics: Option<bool>,
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            Mode(String),
+            Function { function: Name },
+        }
 
-    /// The number of classes in a classification task.
-    ///
-    /// This parameter is required for multiclass classification.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    classification_n_classes: Option<i32>,
+        match Repr::deserialize(deserializer)? {
+            Repr::Mode(mode) => match mode.as_str() {
+                "auto" => Ok(Self::Auto),
+                "none" => Ok(Self::None),
+                "required" => Ok(Self::Required),
+                _ => Err(serde::de::Error::unknown_variant(
+                    &mode,
+                    &["auto", "none", "required"],
+                )),
+            },
+            Repr::Function { function } => Ok(Self::Function(function.name)),
+        }
+    }
+}
 
-    /// The positive class in binary classification.
-    ///
-    /// This parameter is needed to generate precision, recall, and F1 metrics when doing binary classification.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    classification_positive_class: Option<String>,
+/// The format that the model must output.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ResponseFormat {
+    /// Plain text, the default.
+    Text,
 
-    /// If this is provided, we calculate F-beta scores at the specified beta values. The F-beta score is a generalization of F-1 score. This is only used for binary classification.
+    /// JSON mode, which ensures the message the model generates is valid JSON.
     ///
-    /// With a beta of 1 (i.e. the F-1 score), precision and recall are given the same weight. A larger beta score puts more weight on recall and less on precision. A smaller beta score puts more weight on precision and less on recall.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    classification_betas: Option<Vec<f32>>,
+    /// Note that the model should also be instructed to produce JSON via a system or user message.
+    JsonObject,
 
-    /// Suffix to be added to the model's name.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    suffix: Option<String>,
+    /// Structured Outputs, the model's output is guaranteed to match the given JSON schema.
+    JsonSchema {
+        /// The name of the response format. Must be a-z, A-Z, 0-9, or contain underscores and dashes, with a maximum length of 64.
+        name: String,
+
+        /// The schema for the response format, described as a JSON Schema object.
+        schema: serde_json::Value,
+
+        /// Whether to enable strict schema adherence when generating the output.
+        strict: Option<bool>,
+    },
 }
 
-impl CreateFineTuneParamBuilder {
-    pub fn new(training_file: impl Into<String>) -> Self {
-        Self {
-            training_file: Some(training_file.into()),
-            ..Self::default()
-        }
-    }
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum ResponseFormatRepr {
+    Text,
+    JsonObject,
+    JsonSchema { json_schema: JsonSchemaRepr },
 }
 
-/// Response from [`Create Fine-Tune`][create] request.
-#[derive(Debug, Default, Deserialize, Serialize)]
-#[serde(default)]
-pub struct FineTune {
-    pub id: String,
-    pub object: String,
-    pub model: String,
-    pub created_at: u64,
-    pub events: Events,
-
-    pub hyperparams: HyperParams,
-    pub organization_id: String,
-    pub result_files: Files,
-    pub validation_files: Files,
-    pub training_files: Files,
-    pub status: String,
-    pub updated_at: u64,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub token_usage: Option<TokenUsage>,
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize)]
+struct JsonSchemaRepr {
+    name: String,
+    schema: serde_json::Value,
+    strict: Option<bool>,
 }
 
-/// Hyper parameters for fine-tuning a model.
-#[derive(Debug, Default, Deserialize, Serialize)]
-#[serde(default)]
-pub struct HyperParams {
-    pub n_epochs: u32,
-    pub batch_size: u32,
-    pub learning_rate_multiplier: f32,
-    pub prompt_loss_weight: f32,
-    pub compute_classification_metrics: bool,
-    pub classification_n_classes: u32,
-    pub classification_positive_class: String,
-    pub classification_betas: Vec<f32>,
-}
+impl Serialize for ResponseFormat {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        let repr = match self.clone() {
+            ResponseFormat::Text => ResponseFormatRepr::Text,
+            ResponseFormat::JsonObject => ResponseFormatRepr::JsonObject,
+            ResponseFormat::JsonSchema {
+                name,
+                schema,
+                strict,
+            } => ResponseFormatRepr::JsonSchema {
+                json_schema: JsonSchemaRepr {
+                    name,
+                    schema,
+                    strict,
+                },
+            },
+        };
 
-/// Events occuring on Fine-tunes
-#[derive(Debug, Default, Deserialize, Serialize)]
-#[serde(default)]
-pub struct Event {
-    pub object: String,
-    pub created_at: u64,
-    pub level: String,
-    pub message: String,
+        repr.serialize(serializer)
+    }
 }
 
-type Events = Vec<Event>;
+impl<'de> Deserialize<'de> for ResponseFormat {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        Ok(match ResponseFormatRepr::deserialize(deserializer)? {
+            ResponseFormatRepr::Text => Self::Text,
+            ResponseFormatRepr::JsonObject => Self::JsonObject,
+            ResponseFormatRepr::JsonSchema { json_schema } => Self::JsonSchema {
+                name: json_schema.name,
+                schema: json_schema.schema,
+                strict: json_schema.strict,
+            },
+        })
+    }
+}
 
-#[derive(Debug, Default, Deserialize, Serialize)]
+/// A tool call generated by the model.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 #[serde(default)]
-pub struct ListEvents {
-    pub object: String,
-    pub data: Vec<Event>,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub token_usage: Option<TokenUsage>,
+pub struct ToolCall {
+    /// The ID of the tool call, used to match the result of the call in a [`tool message`](ChatMessage::tool).
+    pub id: String,
+    pub r#type: ToolType,
+    pub function: FunctionCall,
 }
 
-#[derive(Debug, Default, Deserialize, Serialize)]
+/// The part of a tool call generated since the previous chunk.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 #[serde(default)]
-pub struct ListFineTune {
-    pub object: String,
-    pub data: Vec<FineTune>,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub token_usage: Option<TokenUsage>,
+pub struct ToolCallDelta {
+    /// The position of the tool call in the message's `tool_calls`.
+    pub index: u32,
+    pub id: Option<String>,
+    pub r#type: Option<ToolType>,
+    pub function: Option<FunctionCall>,
 }
 
-/// The size of the generated images.
-///
-/// Must be one of 256x256, 512x512, or 1024x1024.
-#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
-pub enum ImageSize {
-    S256x256,
-    S512x512,
-    #[default]
-    S1024x1024,
+impl From<String> for ChatMessage {
+    fn from(s: String) -> Self {
+        Self::user(s)
+    }
 }
 
-impl std::fmt::Display for ImageSize {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            ImageSize::S256x256 => write!(f, "256x256"),
-            ImageSize::S512x512 => write!(f, "512x512"),
-            ImageSize::S1024x1024 => write!(f, "1024x1024"),
+impl ChatParam {
+    /// Adapts the parameters to the family of the model, as reasoning models reject `max_tokens`.
+    pub(crate) fn for_model_family(&self) -> Cow<'_, Self> {
+        if self.max_tokens.is_none() || !is_reasoning_model(&self.model) {
+            return Cow::Borrowed(self);
         }
+
+        Cow::Owned(Self {
+            max_completion_tokens: self.max_completion_tokens.or(self.max_tokens),
+            max_tokens: None,
+            ..self.clone()
+        })
     }
 }
 
-impl FromStr for ImageSize {
-    type Err = String;
+/// Whether the model is a reasoning model, like `o1-mini` or `o3`.
+fn is_reasoning_model(model: &str) -> bool {
+    let mut chars = model.chars();
 
-    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
-        match s {
-            "256x256" => Ok(ImageSize::S256x256),
-            "512x512" => Ok(ImageSize::S512x512),
-            "1024x1024" => Ok(ImageSize::S1024x1024),
-            _ => Err(format!("Invalid ImageSize: {}", s)),
+    chars.next() == Some('o') && chars.next().is_some_and(|c| c.is_ascii_digit())
+}
+
+impl ChatParamBuilder {
+    pub fn new(model: impl Into<String>, messages: Vec<ChatMessage>) -> Self {
+        Self {
+            model: Some(model.into()),
+            messages: Some(messages),
+            ..Self::default()
         }
     }
-}
 
-impl Serialize for ImageSize {
-    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    /// Sets the `logit_bias` out of `(word, bias)` pairs, tokenized for the model of the request.
+    ///
+    /// The bias is applied to every token of each word, see [`logit_bias`](crate::tokenizer::logit_bias).
+    #[cfg(feature = "tokenizer")]
+    pub fn logit_bias_words<I, S>(&mut self, biases: I) -> Result<&mut Self>
     where
-        S: serde::Serializer,
+        I: IntoIterator<Item = (S, i32)>,
+        S: AsRef<str>,
     {
-        serializer.serialize_str(&self.to_string())
+        let model = self.model.clone().unwrap_or_default();
+        self.logit_bias = Some(Some(crate::tokenizer::logit_bias(&model, biases)?));
+
+        Ok(self)
     }
 }
 
-/// Parameters for [`Generate Image`](generate) request.
-#[skip_serializing_none]
-#[derive(Builder, Debug, Default, Deserialize, Serialize)]
-#[builder(default, setter(into, strip_option))]
-pub struct GenerateImageParam {
-    /// A text description of the desired image(s). The maximum length is 1000 characters.
-    prompt: String,
+/// A chat completion choice generated by the model.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct ChatChoice {
+    pub index: u32,
+    pub message: ChatMessage,
 
-    /// The number of images to generate. Must be between 1 and 10.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    n: Option<u8>,
+    /// The reason the model stopped generating tokens.
+    ///
+    /// `stop` if the model hit a natural stop point or a provided stop sequence, `length` if the maximum number of tokens was reached.
+    pub finish_reason: Option<String>,
 
-    /// The size of the generated images.
+    /// Log probability information for the choice, when requested with `logprobs`.
     #[serde(skip_serializing_if = "Option::is_none")]
-    size: Option<ImageSize>,
+    pub logprobs: Option<ChatLogprobs>,
+}
 
-    /// A unique identifier representing your end-user.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    user: Option<String>,
+/// Log probability information of a chat completion choice.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct ChatLogprobs {
+    /// The message content tokens with their log probabilities.
+    #[serde(deserialize_with = "null_as_default")]
+    pub content: Vec<TokenLogprob>,
+
+    /// The message refusal tokens with their log probabilities.
+    #[serde(deserialize_with = "null_as_default")]
+    pub refusal: Vec<TokenLogprob>,
 }
 
-impl GenerateImageParamBuilder {
-    pub fn new(prompt: impl Into<String>) -> Self {
-        Self {
-            prompt: Some(prompt.into()),
-            ..Default::default()
-        }
+/// A token, along with its log probability and the most likely alternatives at its position.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct TokenLogprob {
+    pub token: String,
+
+    /// The log probability of this token, if it is within the top 20 most likely tokens. Otherwise, `-9999.0` is used.
+    pub logprob: f64,
+
+    /// The UTF-8 bytes representation of the token, useful when characters are represented by multiple tokens.
+    pub bytes: Option<Vec<u8>>,
+
+    /// The most likely tokens and their log probability, at this token position.
+    pub top_logprobs: Vec<TopLogprob>,
+}
+
+/// One of the most likely tokens at a position, as requested with `top_logprobs`.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct TopLogprob {
+    pub token: String,
+    pub logprob: f64,
+    pub bytes: Option<Vec<u8>>,
+}
+
+impl TokenLogprob {
+    /// The probability of the token, between 0 and 1.
+    pub fn probability(&self) -> f64 {
+        self.logprob.exp()
     }
 }
 
-/// Response from [Generate](generate), [Edit](edit) & [Variation](variate) requests.
-#[derive(Debug, Deserialize, Serialize)]
-pub struct Image {
+/// Response from [`Create Chat Completion`](crate::chat::create) request.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct Chat {
+    pub id: String,
+    pub object: String,
+    pub created: u64,
+    pub model: String,
+    pub choices: Vec<ChatChoice>,
+
+    /// Represents the backend configuration that the model runs with.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub created: Option<u64>,
+    pub system_fingerprint: Option<String>,
+
+    pub usage: TokenUsage,
 
+    /// The service tier used for processing the request, if `service_tier` was specified.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub data: Option<Links>,
+    pub service_tier: Option<ServiceTier>,
 
+    /// The metadata of the request, set for stored chat completions.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub token_usage: Option<TokenUsage>,
+    pub metadata: Option<HashMap<String, String>>,
 }
 
-impl Image {
-    /// Save the image(s) to the given directory.
-    /// The images will be saved as based on the generated image id.
-    ///
-    /// For example, a generated image with url `https://oaidalleapiprodscus.blob.core.windows.net/private/org-123/user-456/img-789.png`
-    /// Will be saved with a name of `img-789.png` in the given directory.
-    ///
-    ///
-    /// ## Example
-    /// ```no_run
-    /// // Generate an image based on a prompt and save it locally.
-    /// use fieri::{Client, image::{ImageSize, GenerateImageParamBuilder, generate}};
-    ///
-    /// #[tokio::main]
-    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    ///     let client = Client::new();
-    ///
-    ///     let param = GenerateImageParamBuilder::new("A cat")
-    ///         .size(ImageSize::S256x256)
-    ///         .n(1)
-    ///         .build()?;
-    ///
-    ///     let image = generate(&client, &param)
-    ///         .await?
-    ///         .save("/tmp/")
-    ///         .await?;
-    ///
-    ///     Ok(())
-    /// }
+impl Chat {
+    /// Deserializes the content of the first choice into `T`.
     ///
-    /// ```
-    pub async fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
-        if let Some(data) = &self.data {
-            for (i, link) in data.iter().enumerate() {
-                let resp = get(&link.url).await?;
-
-                let def_img_name = format!("image_{i}.png");
-                let fname = resp
-                    .url()
-                    .path_segments()
-                    .and_then(|segments| segments.last())
-                    .unwrap_or(def_img_name.as_str());
+    /// Intended to be used with [`ResponseFormat::JsonObject`] or [`ResponseFormat::JsonSchema`].
+    /// On failure, the returned [`OutputParseError`](Error::OutputParseError) contains the raw output of the model.
+    pub fn parse_json<T: serde::de::DeserializeOwned>(&self) -> Result<T> {
+        let message = self.choices.first().map(|choice| &choice.message);
 
-                let full_path = Path::new(path.as_ref()).join(fname);
-                let mut file = fs::File::create(full_path)?;
-                let mut content = Cursor::new(resp.bytes().await?);
-                copy(&mut content, &mut file)?;
-            }
+        if let Some(refusal) = message.and_then(|message| message.refusal.as_ref()) {
+            return Err(Error::RefusalError(refusal.clone()));
         }
 
-        Ok(())
+        let content = message.map_or(String::new(), |message| message.content.to_string());
+        serde_json::from_str(&content).map_err(|source| Error::OutputParseError { source, content })
     }
 }
 
-/// link to an image.
-#[derive(Debug, Deserialize, Serialize)]
-pub struct Link {
-    pub url: String,
+/// A multi-turn chat, keeping track of the message history.
+///
+/// Each [`send`](Conversation::send) appends the user message and the reply of the model to the history,
+/// so that the next turn is sent with the whole conversation so far.
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{Client, chat::Conversation};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let mut conversation = Conversation::new("gpt-4o").system("You are a helpful assistant.");
+///
+///     conversation.send(&client, "Who won the World Cup in 2022?").await?;
+///     let resp = conversation.send(&client, "Who was the captain?").await?;
+///     println!("{}", resp.choices[0].message.content);
+///
+///     println!("{:#?}", conversation.history());
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Conversation {
+    param: ChatParam,
 }
 
-type Links = Vec<Link>;
-
-/// Parameters for [`Edit Image`](edit) request.
-#[skip_serializing_none]
-#[derive(Builder, Debug, Deserialize, Serialize)]
-#[builder(default, setter(into, strip_option))]
-pub struct EditImageParam {
-    /// A text description of the desired image(s). The maximum length is 1000 characters.
-    pub prompt: String,
+impl Conversation {
+    /// Starts an empty conversation with the given model.
+    pub fn new(model: impl Into<String>) -> Self {
+        Self {
+            param: ChatParam {
+                model: model.into(),
+                ..ChatParam::default()
+            },
+        }
+    }
 
-    /// The number of images to generate. Must be between 1 and 10.
-    pub n: u8,
+    /// Starts a conversation from the given parameters, their messages are used as the initial history.
+    pub fn with_param(param: ChatParam) -> Self {
+        Self { param }
+    }
 
-    /// The size of the generated images.
-    pub size: ImageSize,
+    /// Appends a `system` message to the history.
+    pub fn system(mut self, content: impl Into<ChatContent>) -> Self {
+        self.param.messages.push(ChatMessage::system(content));
 
-    /// A unique identifier representing your end-user, which can help OpenAI to monitor and detect abuse.
-    pub user: String,
-}
+        self
+    }
 
-impl Default for EditImageParam {
-    fn default() -> Self {
-        Self {
-            prompt: String::new(),
-            n: 1,
-            size: ImageSize::S1024x1024,
-            user: String::new(),
-        }
+    /// Appends a message to the history, without sending it.
+    pub fn push(&mut self, message: ChatMessage) {
+        self.param.messages.push(message);
     }
-}
 
-impl EditImageParamBuilder {
-    pub fn new(prompt: impl Into<String>) -> Self {
-        Self {
-            prompt: Some(prompt.into()),
-            ..Default::default()
+    /// Sends a `user` message along with the history, appending both the message and the reply of the model to it.
+    ///
+    /// If the request fails, the history is left unchanged.
+    pub async fn send(
+        &mut self,
+        client: &crate::Client,
+        content: impl Into<ChatContent>,
+    ) -> Result<Chat> {
+        self.param.messages.push(ChatMessage::user(content));
+
+        match crate::chat::create(client, &self.param).await {
+            Ok(resp) => {
+                if let Some(choice) = resp.choices.first() {
+                    self.param.messages.push(choice.message.clone());
+                }
+
+                Ok(resp)
+            }
+            Err(err) => {
+                self.param.messages.pop();
+
+                Err(err)
+            }
         }
     }
+
+    /// The messages of the conversation so far.
+    pub fn history(&self) -> &[ChatMessage] {
+        &self.param.messages
+    }
+
+    /// The last message of the conversation, usually the latest reply of the model.
+    pub fn last(&self) -> Option<&ChatMessage> {
+        self.param.messages.last()
+    }
+
+    /// Consumes the conversation, returning its messages.
+    pub fn into_history(self) -> Vec<ChatMessage> {
+        self.param.messages
+    }
 }
 
-/// Parameters for [`Variate Image`](variate) request.
+/// Parameters for [`List Chat Completions`](crate::chat::list) request.
 #[skip_serializing_none]
-#[derive(Builder, Debug, Deserialize, Serialize)]
+#[derive(Builder, Clone, Debug, Default, Deserialize, Serialize)]
 #[builder(default, setter(into, strip_option))]
-pub struct VariateImageParam {
-    /// The number of images to generate. Must be between 1 and 10.
-    pub n: u8,
+pub struct ListChatParam {
+    /// Identifier for the last chat completion from the previous pagination request.
+    pub after: Option<String>,
 
-    /// The size of the generated images.
-    pub size: ImageSize,
+    /// Number of chat completions to retrieve, defaults to 20.
+    pub limit: Option<u32>,
 
-    /// A unique identifier representing your end-user, which can help OpenAI to monitor and detect abuse.
-    pub user: String,
+    /// The model used to generate the chat completions.
+    pub model: Option<String>,
+
+    /// Sort order for chat completions by timestamp, defaults to `asc`.
+    pub order: Option<Order>,
 }
 
-impl Default for VariateImageParam {
-    fn default() -> Self {
-        Self {
-            n: 1,
-            size: ImageSize::S1024x1024,
-            user: String::new(),
+/// The sort order of a listing.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Order {
+    Asc,
+    Desc,
+}
+
+/// Response from [`List Chat Completions`](crate::chat::list) request.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct ChatList {
+    pub object: String,
+    pub data: Vec<Chat>,
+    pub first_id: Option<String>,
+    pub last_id: Option<String>,
+    pub has_more: bool,
+}
+
+/// A single event from [`Create Chat Completion Stream`](crate::chat::create_stream).
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct ChatChunk {
+    pub id: String,
+    pub object: String,
+    pub created: u64,
+    pub model: String,
+    pub choices: Vec<ChatChunkChoice>,
+
+    /// Represents the backend configuration that the model runs with.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub system_fingerprint: Option<String>,
+
+    /// The service tier used for processing the request, if `service_tier` was specified.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service_tier: Option<ServiceTier>,
+
+    /// The token usage of the entire request, only set on the last chunk when requested through [`StreamOptions`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub usage: Option<TokenUsage>,
+}
+
+/// A chat completion choice, as found in a streamed [`ChatChunk`].
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct ChatChunkChoice {
+    pub index: u32,
+    pub delta: ChatDelta,
+
+    /// The reason the model stopped generating tokens, only set on the last chunk of the choice.
+    pub finish_reason: Option<String>,
+
+    /// Log probability information for the tokens of this chunk.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub logprobs: Option<ChatLogprobs>,
+}
+
+/// The part of a message generated since the previous chunk.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct ChatDelta {
+    /// The role of the author, only sent with the first chunk of a message.
+    pub role: Option<ChatRole>,
+
+    /// The newly generated part of the contents.
+    pub content: Option<String>,
+
+    /// The newly generated part of the function call.
+    pub function_call: Option<FunctionCall>,
+
+    /// The newly generated parts of the tool calls.
+    pub tool_calls: Option<Vec<ToolCallDelta>>,
+
+    /// The newly generated part of the audio response.
+    pub audio: Option<ChatAudio>,
+}
+
+impl ChatMessage {
+    /// Appends a streamed [`ChatDelta`] to the message.
+    ///
+    /// The deltas of tool calls past the 128th are dropped, rather than growing the calls up to their index.
+    pub fn apply_delta(&mut self, delta: &ChatDelta) {
+        if let Some(role) = delta.role {
+            self.role = role;
+        }
+
+        if let Some(content) = &delta.content {
+            self.content.push_str(content);
+        }
+
+        if let Some(call) = &delta.function_call {
+            let acc = self.function_call.get_or_insert_with(FunctionCall::default);
+            acc.name.push_str(&call.name);
+            acc.arguments.push_str(&call.arguments);
+        }
+
+        for call in delta.tool_calls.iter().flatten() {
+            let index = call.index as usize;
+            if index > MAX_DELTA_INDEX {
+                log::warn!(
+                    "Dropping the delta of the tool call {index}, past the last one accumulated."
+                );
+                continue;
+            }
+
+            let calls = self.tool_calls.get_or_insert_with(Vec::new);
+            if calls.len() <= index {
+                calls.resize_with(index + 1, ToolCall::default);
+            }
+
+            let acc = &mut calls[index];
+            if let Some(id) = &call.id {
+                acc.id.clone_from(id);
+            }
+            if let Some(r#type) = call.r#type {
+                acc.r#type = r#type;
+            }
+            if let Some(function) = &call.function {
+                acc.function.name.push_str(&function.name);
+                acc.function.arguments.push_str(&function.arguments);
+            }
+        }
+
+        if let Some(audio) = &delta.audio {
+            let acc = self.audio.get_or_insert_with(ChatAudio::default);
+            if !audio.id.is_empty() {
+                acc.id.clone_from(&audio.id);
+            }
+            if audio.expires_at.is_some() {
+                acc.expires_at = audio.expires_at;
+            }
+            for (acc, part) in [
+                (&mut acc.data, &audio.data),
+                (&mut acc.transcript, &audio.transcript),
+            ] {
+                if let Some(part) = part {
+                    acc.get_or_insert_with(String::new).push_str(part);
+                }
+            }
         }
     }
 }
 
-impl VariateImageParamBuilder {
-    pub fn new() -> Self {
-        Self::default()
+/// Folds the chunks of a [`chat stream`](crate::chat::create_stream) back into complete choices.
+///
+/// ## Example
+/// ```no_run
+/// use fieri::{Client, chat::{create_stream, ChatAccumulator, ChatMessage, ChatParamBuilder}};
+/// use futures::StreamExt;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = Client::new();
+///
+///     let param = ChatParamBuilder::new("gpt-3.5-turbo", vec![ChatMessage::user("Hello!")]).build()?;
+///
+///     let mut stream = create_stream(&client, &param).await?;
+///     let mut acc = ChatAccumulator::default();
+///
+///     while let Some(chunk) = stream.next().await {
+///         acc.push(&chunk?);
+///     }
+///
+///     println!("{:#?}", acc.into_message());
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ChatAccumulator {
+    choices: Vec<ChatChoice>,
+    usage: Option<TokenUsage>,
+}
+
+// The highest index of the choices and tool calls accumulated out of a stream, whose deltas past it are dropped
+// rather than growing the accumulated ones up to their index. The API generates at most 128 choices.
+const MAX_DELTA_INDEX: usize = 127;
+
+impl ChatAccumulator {
+    /// Appends the deltas of the chunk to their respective choices.
+    ///
+    /// The deltas of choices past the 128th are dropped, more than the API generates.
+    pub fn push(&mut self, chunk: &ChatChunk) {
+        if chunk.usage.is_some() {
+            self.usage.clone_from(&chunk.usage);
+        }
+
+        for choice in &chunk.choices {
+            let index = choice.index as usize;
+            if index > MAX_DELTA_INDEX {
+                log::warn!(
+                    "Dropping the delta of the choice {index}, past the last one accumulated."
+                );
+                continue;
+            }
+            while self.choices.len() <= index {
+                self.choices.push(ChatChoice {
+                    index: self.choices.len() as u32,
+                    message: ChatMessage::new(ChatRole::Assistant, ""),
+                    finish_reason: None,
+                    logprobs: None,
+                });
+            }
+
+            let acc = &mut self.choices[index];
+            acc.message.apply_delta(&choice.delta);
+            if choice.finish_reason.is_some() {
+                acc.finish_reason = choice.finish_reason.clone();
+            }
+            if let Some(logprobs) = &choice.logprobs {
+                let acc = acc.logprobs.get_or_insert_with(ChatLogprobs::default);
+                acc.content.extend_from_slice(&logprobs.content);
+                acc.refusal.extend_from_slice(&logprobs.refusal);
+            }
+        }
+    }
+
+    /// The choices accumulated so far.
+    pub fn choices(&self) -> &[ChatChoice] {
+        &self.choices
+    }
+
+    /// The token usage of the request, if it was [`included`](StreamOptions::include_usage) in the stream.
+    pub fn usage(&self) -> Option<&TokenUsage> {
+        self.usage.as_ref()
+    }
+
+    /// Consumes the accumulator, returning all accumulated choices.
+    pub fn into_choices(self) -> Vec<ChatChoice> {
+        self.choices
+    }
+
+    /// Consumes the accumulator, returning the message of the first choice.
+    pub fn into_message(self) -> Option<ChatMessage> {
+        self.choices.into_iter().next().map(|choice| choice.message)
     }
 }
 
-/// Response from [List Models](list) request.
-#[derive(Debug, Default, Deserialize, Serialize)]
-pub struct Models {
-    pub data: Vec<Model>,
+/// Parameters for [`Create Completion`](create) request.
+#[skip_serializing_none]
+#[derive(Builder, Clone, Debug, Default, Deserialize, Serialize)]
+#[builder(default, setter(into, strip_option))]
+pub struct CompletionParam {
+    /// The model to use for the completion request.
+    model: String,
+
+    /// The prompt(s) to generate completions for.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    prompt: Option<String>,
 
+    /// The suffix that comes after a completion of inserted text.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub token_usage: Option<TokenUsage>,
+    suffix: Option<String>,
+
+    /// The maximum number of tokens to generate in the completion.
+    ///
+    /// The token count of your prompt plus `max_tokens` cannot exceed the model's context length.
+    /// Most models have a context length of 2048 tokens (except for the newest models, which support 4096).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_tokens: Option<i32>,
+
+    /// What sampling temperature to use, between 0 and 2. Higher values means the model will take more risks.
+    ///
+    /// Try 0.9 for more creative applications, and 0 (argmax sampling) for ones with a well-defined answer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    temperature: Option<f32>,
+
+    /// An alternative to sampling with temperature, called nucleus sampling, where the model considers the results of the tokens with top_p probability mass.
+    /// So 0.1 means only the tokens comprising the top 10% probability mass are considered.
+    ///
+    /// It's generally recommended to alter this or `temperature` but not both.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    top_p: Option<f32>,
+
+    /// How many completions to generate for each prompt.
+    ///
+    /// Note: Because this parameter generates many completions, it can quickly consume your token quota.
+    /// Use carefully and ensure that you have reasonable settings for `max_tokens` and `stop`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    n: Option<u32>,
+
+    // Whether to stream back partial progress.
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub(crate) stream: bool,
+
+    /// Options for the streamed response, only used by [`create_stream`](crate::completion::create_stream).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stream_options: Option<StreamOptions>,
+
+    /// Include the log probabilities on the `logprobs` most likely tokens, as well the chosen tokens.
+    ///
+    /// The maximum value for `logprobs` is 5.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    logprobs: Option<u8>,
+
+    /// Echo back the prompt in addition to the completion
+    #[serde(default, skip_serializing_if = "is_false")]
+    echo: bool,
+
+    /// Up to 4 sequences where the API will stop generating further tokens.
+    ///
+    /// The returned text will not contain the stop sequence.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stop: Option<Stop>,
+
+    /// Modify the likelihood of specified tokens appearing in the completion.
+    ///
+    /// Maps token ids to a bias value from -100 to 100.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    logit_bias: Option<HashMap<u32, i32>>,
+
+    /// Number between -2.0 and 2.0.
+    ///
+    /// Positive values penalize new tokens based on whether they appear in the text so far, increasing the model's likelihood to talk about new topics.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    presence_penalty: Option<f32>,
+
+    /// Number between -2.0 and 2.0.
+    ///
+    /// Positive values penalize new tokens based on their existing frequency in the text so far, decreasing the model's likelihood to repeat the same line verbatim.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    frequency_penalty: Option<f32>,
+
+    /// Generates best_of completions server-side and returns the "best" (the one with the highest log probability per token).
+    ///
+    /// Results cannot be streamed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    best_of: Option<u16>,
+
+    /// The latency tier to use for processing the request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    service_tier: Option<ServiceTier>,
+
+    /// A unique identifier representing your end-user, which can help OpenAI to monitor and detect abuse.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    user: Option<String>,
 }
 
-/// Response from [Retrieve a Model](retrieve) request.
+impl CompletionParamBuilder {
+    pub fn new(model: impl Into<String>) -> Self {
+        Self {
+            model: Some(model.into()),
+            ..Self::default()
+        }
+    }
+
+    /// Sets the `logit_bias` out of `(word, bias)` pairs, tokenized for the model of the request.
+    ///
+    /// The bias is applied to every token of each word, see [`logit_bias`](crate::tokenizer::logit_bias).
+    #[cfg(feature = "tokenizer")]
+    pub fn logit_bias_words<I, S>(&mut self, biases: I) -> Result<&mut Self>
+    where
+        I: IntoIterator<Item = (S, i32)>,
+        S: AsRef<str>,
+    {
+        let model = self.model.clone().unwrap_or_default();
+        self.logit_bias = Some(Some(crate::tokenizer::logit_bias(&model, biases)?));
+
+        Ok(self)
+    }
+}
+
+/// Response from [`Create completion`](create) request.
 #[derive(Debug, Default, Deserialize, Serialize)]
-pub struct Model {
+#[serde(default)]
+pub struct Completion {
     pub id: String,
     pub object: String,
     pub created: u64,
-    pub owned_by: String,
-    pub permission: Vec<Permissions>,
-    pub root: String,
+    pub model: String,
+    pub choices: Vec<Choices>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub parent: Option<String>,
+    pub usage: Option<TokenUsage>,
 
+    /// The service tier used for processing the request, if `service_tier` was specified.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub token_usage: Option<TokenUsage>,
+    pub service_tier: Option<ServiceTier>,
 }
 
-/// Types of permissions that can be applied to a model.
-#[derive(Debug, Default, Deserialize, Serialize)]
-pub struct Permissions {
+/// A single event from [`Create Completion Stream`](crate::completion::create_stream).
+///
+/// Each chunk carries the newly generated part of the text in its choices.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct CompletionChunk {
     pub id: String,
     pub object: String,
     pub created: u64,
-    pub allow_create_engine: bool,
-    pub allow_sampling: bool,
-    pub allow_logprobs: bool,
-    pub allow_search_indices: bool,
-    pub allow_view: bool,
-    pub allow_fine_tuning: bool,
-    pub organization: String,
-    pub is_blocking: bool,
+    pub model: String,
+    pub choices: Vec<Choices>,
 
+    /// The token usage of the entire request, only set on the last chunk when requested through [`StreamOptions`].
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub group: Option<String>,
+    pub usage: Option<TokenUsage>,
 }
 
-/// Parameters for [`Create Moderation`](create) request.
+/// Parameters for [`Create Edit`](create) request.
 #[skip_serializing_none]
 #[derive(Builder, Debug, Default, Deserialize, Serialize)]
 #[builder(default, setter(into, strip_option))]
-pub struct ModerationParam {
-    /// The content moderations model to use for the request.
+pub struct EditParam {
+    /// The model to use for the edit request.
+    model: String,
+
+    /// The instruction that tells the model how to edit the prompt.
+    instruction: String,
+
+    /// The input text to use as a starting point for the edit.
     #[serde(skip_serializing_if = "Option::is_none")]
-    model: Option<String>,
+    input: Option<String>,
+
+    /// How many edits to generate for the input and instruction.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    n: Option<u32>,
+
+    /// What sampling temperature to use. Higher values means the model will take more risks. Try 0.9 for more creative applications, and 0 (argmax sampling) for ones with a well-defined answer.
+    ///
+    /// It's recommended to alter this or `top_p` but not both.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    temperature: Option<f32>,
+
+    /// An alternative to sampling with temperature, called nucleus sampling, where the model considers the results of the tokens with top_p probability mass. So 0.1 means only the tokens comprising the top 10% probability mass are considered.
+    ///
+    /// It's recommended to alter this or `temperature` but not both.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    top_p: Option<f32>,
+}
+
+impl EditParamBuilder {
+    pub fn new(model: impl Into<String>, instruction: impl Into<String>) -> Self {
+        Self {
+            model: Some(model.into()),
+            instruction: Some(instruction.into()),
+            ..Self::default()
+        }
+    }
+}
+
+/// Response from [`Create Edit`](create) request.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct Edit {
+    pub object: String,
+    pub created: u64,
+    pub choices: Vec<Choices>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub usage: Option<TokenUsage>,
+}
+
+/// Parameters for [`Create Embedding`](create) request.
+#[skip_serializing_none]
+#[derive(Builder, Clone, Debug, Default, Deserialize, Serialize)]
+#[builder(default, setter(into, strip_option))]
+pub struct EmbeddingParam {
+    /// The model to use for the embedding request.
+    model: String,
+
+    /// Input text to get embeddings for, encoded as a string or array of tokens.
+    ///
+    /// To get embeddings for multiple inputs in a single request, pass an array of strings or array of token arrays.
+    /// Each input must not exceed 8192 tokens in length.
+    input: EmbeddingInput,
+
+    /// The number of dimensions the resulting output embeddings should have.
+    ///
+    /// Only supported in `text-embedding-3` and later models, the embeddings are shortened without losing their concept-representing properties.
+    dimensions: Option<u32>,
+
+    /// The format to return the embeddings in, either `float` or `base64`.
+    ///
+    /// Base64 embeddings are decoded transparently, while being much smaller to transfer and faster to parse.
+    encoding_format: Option<EncodingFormat>,
+
+    /// A unique identifier representing your end-user, which can help OpenAI to monitor and detect abuse.
+    user: Option<String>,
+}
+
+impl EmbeddingParam {
+    /// The texts of the input, unless it's made of tokens.
+    pub(crate) fn texts(&self) -> Option<Vec<&str>> {
+        match &self.input {
+            EmbeddingInput::Text(text) => Some(vec![text]),
+            EmbeddingInput::Texts(texts) => Some(texts.iter().map(String::as_str).collect()),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn model(&self) -> &str {
+        &self.model
+    }
+
+    /// The key of the embedding of the text, created with these parameters.
+    pub(crate) fn cache_key(&self, text: &str) -> EmbeddingCacheKey {
+        EmbeddingCacheKey::new(&self.model, self.dimensions, text)
+    }
+
+    pub(crate) fn with_texts(&self, texts: Vec<String>) -> Self {
+        Self {
+            input: EmbeddingInput::Texts(texts),
+            ..self.clone()
+        }
+    }
+}
+
+impl EmbeddingParamBuilder {
+    pub fn new(model: impl Into<String>, input: impl Into<EmbeddingInput>) -> Self {
+        Self {
+            model: Some(model.into()),
+            input: Some(input.into()),
+            ..Self::default()
+        }
+    }
+}
+
+/// The input of an [`Create Embedding`](create) request, either text or tokens, single or batched.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum EmbeddingInput {
+    Text(String),
+    Texts(Vec<String>),
+    Tokens(Vec<u32>),
+    TokenArrays(Vec<Vec<u32>>),
+}
+
+impl Default for EmbeddingInput {
+    fn default() -> Self {
+        Self::Text(String::new())
+    }
+}
+
+impl From<&str> for EmbeddingInput {
+    fn from(value: &str) -> Self {
+        Self::Text(value.to_string())
+    }
+}
+
+impl From<String> for EmbeddingInput {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+
+impl From<Vec<&str>> for EmbeddingInput {
+    fn from(value: Vec<&str>) -> Self {
+        Self::Texts(value.into_iter().map(String::from).collect())
+    }
+}
+
+impl From<Vec<String>> for EmbeddingInput {
+    fn from(value: Vec<String>) -> Self {
+        Self::Texts(value)
+    }
+}
+
+impl From<Vec<u32>> for EmbeddingInput {
+    fn from(value: Vec<u32>) -> Self {
+        Self::Tokens(value)
+    }
+}
+
+impl From<Vec<Vec<u32>>> for EmbeddingInput {
+    fn from(value: Vec<Vec<u32>>) -> Self {
+        Self::TokenArrays(value)
+    }
+}
+
+/// The format of the embeddings returned by the API.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EncodingFormat {
+    Float,
+    Base64,
+}
+
+/// Response from [`Create Embedding`](create) request.
+///
+/// For batched inputs, each [`EmbeddingData`] carries the `index` of the input it was generated for.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct Embedding {
+    pub object: String,
+    pub data: Vec<EmbeddingData>,
+    pub model: String,
+
+    pub usage: Option<TokenUsage>,
+}
+
+/// The distance between two vectors measures their relatedness. Small distances suggest high relatedness and large distances suggest low relatedness.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct EmbeddingData {
+    pub object: String,
+
+    /// The embedding vector, decoded from base64 if requested through [`EncodingFormat::Base64`].
+    #[serde(deserialize_with = "float_or_base64")]
+    pub embedding: Embeddings,
+    pub index: u64,
+}
+
+#[cfg(feature = "ndarray")]
+impl Embedding {
+    /// Copies the first embedding into an [`Array1`](ndarray::Array1), as returned for a single input.
+    pub fn to_array1(&self) -> Option<ndarray::Array1<f32>> {
+        self.data.first().map(EmbeddingData::to_array1)
+    }
+
+    /// Copies all embeddings into an [`Array2`](ndarray::Array2), with one row per input, in the order of the inputs.
+    pub fn to_array2(&self) -> Result<ndarray::Array2<f32>> {
+        let mut data = self.data.iter().collect::<Vec<_>>();
+        data.sort_by_key(|data| data.index);
+
+        let cols = data.first().map_or(0, |data| data.embedding.len());
+        let values = data
+            .iter()
+            .flat_map(|data| data.embedding.iter().copied())
+            .collect();
+
+        Ok(ndarray::Array2::from_shape_vec((data.len(), cols), values)?)
+    }
+}
+
+impl EmbeddingData {
+    /// Copies the embedding into an [`Array1`](ndarray::Array1).
+    #[cfg(feature = "ndarray")]
+    pub fn to_array1(&self) -> ndarray::Array1<f32> {
+        ndarray::Array1::from_vec(self.embedding.clone())
+    }
+
+    /// The cosine similarity with another embedding, see [`vector::cosine_similarity`](crate::vector::cosine_similarity).
+    pub fn cosine_similarity(&self, other: &EmbeddingData) -> Option<f32> {
+        crate::vector::cosine_similarity(&self.embedding, &other.embedding)
+    }
+
+    /// The dot product with another embedding, see [`vector::dot`](crate::vector::dot).
+    pub fn dot(&self, other: &EmbeddingData) -> Option<f32> {
+        crate::vector::dot(&self.embedding, &other.embedding)
+    }
+
+    /// Normalizes the embedding to a length of 1, as needed after truncating it.
+    pub fn l2_normalize(&mut self) {
+        crate::vector::l2_normalize(&mut self.embedding)
+    }
+}
+
+type Embeddings = Vec<f32>;
+
+/// Identifies a cached embedding by the model and dimensions it was created with, and its whole input text.
+///
+/// Keyed on the text itself rather than a hash of it, so that two texts never share an embedding,
+/// the keys being serializable to be persisted by disk or remote caches, hashed there if need be.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct EmbeddingCacheKey {
+    pub model: String,
+    pub dimensions: Option<u32>,
+    pub input: String,
+}
+
+impl EmbeddingCacheKey {
+    pub fn new(
+        model: impl Into<String>,
+        dimensions: Option<u32>,
+        input: impl Into<String>,
+    ) -> Self {
+        Self {
+            model: model.into(),
+            dimensions,
+            input: input.into(),
+        }
+    }
+}
+
+/// The future of a cache operation, `Send` but on wasm, whose futures are bound to the JavaScript thread.
+#[cfg(not(target_arch = "wasm32"))]
+pub type CacheFuture<'a, T> = futures::future::BoxFuture<'a, T>;
+/// The future of a cache operation, `Send` but on wasm, whose futures are bound to the JavaScript thread.
+#[cfg(target_arch = "wasm32")]
+pub type CacheFuture<'a, T> = futures::future::LocalBoxFuture<'a, T>;
+
+/// Storage for embeddings, used by [`Create Cached Embedding`](crate::embedding::create_cached) to skip requests for inputs seen before.
+///
+/// Implement it to plug in a disk or remote backend, like redis, instead of the [`InMemoryEmbeddingCache`],
+/// the lookups of a request being awaited concurrently. A backend failing to answer should treat the key as missing.
+pub trait EmbeddingCache: Send + Sync {
+    /// The cached embedding of the key, if any.
+    fn get<'a>(&'a self, key: &'a EmbeddingCacheKey) -> CacheFuture<'a, Option<Vec<f32>>>;
+
+    /// Caches the embedding of the key.
+    fn insert(&self, key: EmbeddingCacheKey, embedding: Vec<f32>) -> CacheFuture<'_, ()>;
+}
+
+/// An [`EmbeddingCache`] keeping all embeddings in memory, for the lifetime of the cache.
+#[derive(Debug, Default)]
+pub struct InMemoryEmbeddingCache {
+    entries: Mutex<HashMap<EmbeddingCacheKey, Vec<f32>>>,
+}
+
+impl InMemoryEmbeddingCache {
+    /// The number of cached embeddings.
+    pub fn len(&self) -> usize {
+        self.entries.lock().unwrap().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Removes all cached embeddings.
+    pub fn clear(&self) {
+        self.entries.lock().unwrap().clear()
+    }
+}
+
+impl EmbeddingCache for InMemoryEmbeddingCache {
+    fn get<'a>(&'a self, key: &'a EmbeddingCacheKey) -> CacheFuture<'a, Option<Vec<f32>>> {
+        let embedding = self.entries.lock().unwrap().get(key).cloned();
+        Box::pin(async move { embedding })
+    }
+
+    fn insert(&self, key: EmbeddingCacheKey, embedding: Vec<f32>) -> CacheFuture<'_, ()> {
+        self.entries.lock().unwrap().insert(key, embedding);
+        Box::pin(async {})
+    }
+}
+
+/// Options for [`Embed Many`](crate::embedding::embed_many), embedding any number of inputs in batches.
+#[derive(Builder, Clone, Debug)]
+#[builder(default, setter(into))]
+pub struct EmbedManyOptions {
+    /// The model to use for the embedding requests.
+    pub model: String,
+
+    /// The number of inputs sent per request, at most 2048. Defaults to 2048.
+    pub batch_size: usize,
+
+    /// The maximum number of requests running at once. Defaults to 4.
+    pub concurrency: usize,
+
+    /// The number of dimensions the resulting output embeddings should have.
+    #[builder(setter(strip_option))]
+    pub dimensions: Option<u32>,
+}
+
+impl Default for EmbedManyOptions {
+    fn default() -> Self {
+        Self {
+            model: String::new(),
+            batch_size: 2048,
+            concurrency: 4,
+            dimensions: None,
+        }
+    }
+}
+
+impl EmbedManyOptionsBuilder {
+    pub fn new(model: impl Into<String>) -> Self {
+        Self {
+            model: Some(model.into()),
+            ..Self::default()
+        }
+    }
+}
+
+/// Parameters for [`List Files`](crate::file::list) request.
+#[skip_serializing_none]
+#[derive(Builder, Clone, Debug, Default, Deserialize, Serialize)]
+#[builder(default, setter(into, strip_option))]
+pub struct ListFilesParam {
+    /// Only return files with the given purpose.
+    pub purpose: Option<Purpose>,
+
+    /// Number of files to retrieve, between 1 and 10,000, defaults to 10,000.
+    pub limit: Option<u32>,
+
+    /// Sort order for files by their `created_at` timestamp, defaults to `desc`.
+    pub order: Option<Order>,
+
+    /// Identifier for the last file from the previous pagination request.
+    pub after: Option<String>,
+}
+
+/// Response from [`List Files`](crate::file::list) request.
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct ListFiles {
+    pub data: Files,
+    pub object: String,
+    pub first_id: Option<String>,
+    pub last_id: Option<String>,
+    pub has_more: bool,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_usage: Option<TokenUsage>,
+}
+
+/// The Possible Purposes of the uploaded documents.
+///
+/// The `*Output` and `*Results` purposes are only set by OpenAI on the files it creates.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum Purpose {
+    #[default]
+    #[serde(rename = "fine-tune")]
+    FineTune,
+    #[serde(rename = "fine-tune-results")]
+    FineTuneResults,
+    #[serde(rename = "assistants")]
+    Assistants,
+    #[serde(rename = "assistants_output")]
+    AssistantsOutput,
+    #[serde(rename = "batch")]
+    Batch,
+    #[serde(rename = "batch_output")]
+    BatchOutput,
+    #[serde(rename = "vision")]
+    Vision,
+    #[serde(rename = "user_data")]
+    UserData,
+    #[serde(rename = "evals")]
+    Evals,
+
+    // Purposes of the legacy endpoints, which no longer accept uploads.
+    #[serde(rename = "answers")]
+    Answers,
+    #[serde(rename = "search")]
+    Search,
+    #[serde(rename = "classifications")]
+    Classifications,
+}
+
+impl std::fmt::Display for Purpose {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Purpose::FineTune => write!(f, "fine-tune"),
+            Purpose::FineTuneResults => write!(f, "fine-tune-results"),
+            Purpose::Assistants => write!(f, "assistants"),
+            Purpose::AssistantsOutput => write!(f, "assistants_output"),
+            Purpose::Batch => write!(f, "batch"),
+            Purpose::BatchOutput => write!(f, "batch_output"),
+            Purpose::Vision => write!(f, "vision"),
+            Purpose::UserData => write!(f, "user_data"),
+            Purpose::Evals => write!(f, "evals"),
+            Purpose::Answers => write!(f, "answers"),
+            Purpose::Search => write!(f, "search"),
+            Purpose::Classifications => write!(f, "classifications"),
+        }
+    }
+}
+
+/// Parameters for [`Create Upload`](crate::upload::create) request.
+#[skip_serializing_none]
+#[derive(Builder, Clone, Debug, Default, Deserialize, Serialize)]
+#[builder(default, setter(into, strip_option))]
+pub struct CreateUploadParam {
+    /// The name of the file once uploaded.
+    pub filename: String,
+
+    /// The purpose of the file once uploaded.
+    pub purpose: Purpose,
+
+    /// The total number of bytes of the file, up to 8GB.
+    pub bytes: u64,
+
+    /// The MIME type of the file, like `text/jsonl`, which must be supported by the purpose.
+    pub mime_type: String,
+}
+
+impl CreateUploadParamBuilder {
+    pub fn new(
+        filename: impl Into<String>,
+        purpose: Purpose,
+        bytes: u64,
+        mime_type: impl Into<String>,
+    ) -> Self {
+        Self {
+            filename: Some(filename.into()),
+            purpose: Some(purpose),
+            bytes: Some(bytes),
+            mime_type: Some(mime_type.into()),
+        }
+    }
+}
+
+/// An upload of a file in parts, see [`Uploads`](crate::upload).
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct Upload {
+    pub id: String,
+    pub object: String,
+    pub bytes: u64,
+    pub created_at: u64,
+    pub filename: String,
+    pub purpose: String,
+    pub status: UploadStatus,
+
+    /// The Unix timestamp (in seconds) at which the upload expires, an hour after its creation.
+    pub expires_at: u64,
+
+    /// The uploaded file, once the upload is completed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<File>,
+}
+
+/// The status of an [`Upload`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UploadStatus {
+    #[default]
+    Pending,
+    Completed,
+    Cancelled,
+    Expired,
+}
+
+/// A part added to an [`Upload`].
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct UploadPart {
+    pub id: String,
+    pub object: String,
+    pub created_at: u64,
+    pub upload_id: String,
+}
+
+/// Parameters for [`Complete Upload`](crate::upload::complete) request.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CompleteUploadParam {
+    /// The IDs of the parts, in the order they make up the file.
+    pub part_ids: Vec<String>,
+
+    /// The MD5 checksum of the file, checked against the uploaded bytes.
+    pub md5: Option<String>,
+}
+
+/// The state of an [`Upload`] in progress, persisted to resume it from its last added part after an interruption.
+///
+/// The file is split in parts of `part_size` bytes, the upload resuming at the [`offset`](UploadSession::offset)
+/// following the parts already added.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct UploadSession {
+    pub upload_id: String,
+    pub filename: String,
+
+    /// The total number of bytes of the file.
+    pub bytes: u64,
+    pub part_size: usize,
+
+    /// The IDs of the parts added so far, in order.
+    pub part_ids: Vec<String>,
+
+    /// The SHA-1 of the contents of the file, hex encoded, telling apart a file changed since. Empty if unknown.
+    #[serde(default)]
+    pub sha1: String,
+
+    /// The Unix timestamp (in seconds) at which the upload expires, an hour after its creation.
+    #[serde(default)]
+    pub expires_at: u64,
+}
+
+impl UploadSession {
+    /// Creates the upload, to be uploaded in parts of `part_size` bytes, up to [`MAX_PART_SIZE`](crate::upload::MAX_PART_SIZE).
+    pub async fn create(
+        client: &crate::Client,
+        param: &CreateUploadParam,
+        part_size: usize,
+    ) -> Result<Self> {
+        let upload = crate::upload::create(client, param).await?;
+
+        Ok(Self {
+            upload_id: upload.id,
+            filename: param.filename.clone(),
+            bytes: param.bytes,
+            part_size: part_size.clamp(1, crate::upload::MAX_PART_SIZE),
+            part_ids: Vec::new(),
+            sha1: String::new(),
+            expires_at: upload.expires_at,
+        })
+    }
+
+    /// Whether the upload is expired, according to the system clock, its parts then being lost.
+    pub fn is_expired(&self) -> bool {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(true, |now| now.as_secs() >= self.expires_at)
+    }
+
+    /// The offset in the file of the next part to add.
+    pub fn offset(&self) -> u64 {
+        (self.part_ids.len() as u64 * self.part_size as u64).min(self.bytes)
+    }
+
+    /// Whether all the parts were added, leaving the upload to [`complete`](UploadSession::complete).
+    pub fn is_uploaded(&self) -> bool {
+        self.offset() == self.bytes
+    }
+
+    /// Reads a session saved with [`save`](UploadSession::save).
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        Ok(serde_json::from_slice(&fs::read(path)?)?)
+    }
+
+    /// Saves the session as JSON, replacing the previous save at once so that it's never left half written.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        let mut tmp = path.as_os_str().to_owned();
+        tmp.push(".tmp");
+
+        fs::write(&tmp, serde_json::to_vec(self)?)?;
+        fs::rename(&tmp, path)?;
+
+        Ok(())
+    }
+
+    /// Adds the next part of the file, read from the reader at the [`offset`](UploadSession::offset) of the session.
+    ///
+    /// Returns whether a part was added, `false` once the whole file is uploaded.
+    pub async fn upload_part<R>(&mut self, client: &crate::Client, reader: &mut R) -> Result<bool>
+    where
+        R: tokio::io::AsyncRead + tokio::io::AsyncSeek + Unpin,
+    {
+        use tokio::io::AsyncSeekExt;
+
+        if self.is_uploaded() {
+            return Ok(false);
+        }
+
+        reader.seek(std::io::SeekFrom::Start(self.offset())).await?;
+        let part = crate::upload::read_part(reader, self.part_size).await?;
+        if part.is_empty() {
+            return Err(Error::FileError(std::io::Error::new(
+                std::io::ErrorKind::UnexpectedEof,
+                format!(
+                    "{} is shorter than the {} bytes of the upload.",
+                    self.filename, self.bytes
+                ),
+            )));
+        }
+
+        let name = format!("{}.part{}", self.filename, self.part_ids.len());
+        let part =
+            crate::upload::add_part(client, &self.upload_id, FileUpload::bytes(name, part)).await?;
+        self.part_ids.push(part.id);
+
+        Ok(true)
+    }
+
+    /// Completes the upload with the parts added, creating the uploaded [`File`].
+    pub async fn complete(&self, client: &crate::Client) -> Result<Upload> {
+        let param = CompleteUploadParam {
+            part_ids: self.part_ids.clone(),
+            md5: None,
+        };
+
+        crate::upload::complete(client, &self.upload_id, &param).await
+    }
+}
+
+/// Parameters for [`Create Fine-tune`](create) request.
+#[skip_serializing_none]
+#[derive(Builder, Debug, Default, Deserialize, Serialize)]
+#[builder(default, setter(into, strip_option))]
+pub struct CreateFineTuneParam {
+    /// The ID of an uploaded file that contains training data.
+    ///
+    /// See [upload](crate::file::upload) file for how to upload a file.
+    training_file: String,
+
+    /// The ID of an uploaded file that contains validation data.
+    ///
+    /// If you provide this file, the data is used to generate validation metrics periodically during fine-tuning. These metrics can be viewed in the fine-tuning results file.
+    /// Your train and validation data should be mutually exclusive.
+    // Note: Even though it's given as "optional" in the docs, it's required in the API.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    validation_file: Option<String>,
+
+    /// The name of the base model to fine-tune. You can select one of "ada", "babbage", "curie", "davinci", or a fine-tuned model created after 2022-04-21.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    model: Option<String>,
+
+    /// The number of epochs to train the model for. An epoch refers to one full cycle through the training dataset.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    n_epochs: Option<i32>,
+
+    /// The batch size to use for training. The batch size is the number of training examples used to train a single forward and backward pass.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    batch_size: Option<i32>,
+
+    /// The learning rate multiplier to use for training. The fine-tuning learning rate is the original learning rate used for pretraining multiplied by this value.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    learning_rate_multiplier: Option<f32>,
+
+    /// The weight to use for loss on the prompt tokens.
+    ///
+    /// This controls how much the model tries to learn to generate the prompt (as compared to the completion which always has a weight of 1.0), and can add a stabilizing effect to training when completions are short.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    prompt_loss_weight: Option<f32>,
+
+    /// If set, we calculate classification-specific metrics such as accuracy and F-1 score using the validation set at the end of every epoch.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    compute_classification_metrics: Option<bool>,
+
+    /// The number of classes in a classification task.
+    ///
+    /// This parameter is required for multiclass classification.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    classification_n_classes: Option<i32>,
+
+    /// The positive class in binary classification.
+    ///
+    /// This parameter is needed to generate precision, recall, and F1 metrics when doing binary classification.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    classification_positive_class: Option<String>,
+
+    /// If this is provided, we calculate F-beta scores at the specified beta values. The F-beta score is a generalization of F-1 score. This is only used for binary classification.
+    ///
+    /// With a beta of 1 (i.e. the F-1 score), precision and recall are given the same weight. A larger beta score puts more weight on recall and less on precision. A smaller beta score puts more weight on precision and less on recall.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    classification_betas: Option<Vec<f32>>,
+
+    /// Suffix to be added to the model's name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    suffix: Option<String>,
+}
+
+impl CreateFineTuneParamBuilder {
+    pub fn new(training_file: impl Into<String>) -> Self {
+        Self {
+            training_file: Some(training_file.into()),
+            ..Self::default()
+        }
+    }
+}
+
+/// Response from [`Create Fine-Tune`][create] request.
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct FineTune {
+    pub id: String,
+    pub object: String,
+    pub model: String,
+    pub created_at: u64,
+    pub events: Events,
+
+    pub hyperparams: HyperParams,
+    pub organization_id: String,
+    pub result_files: Files,
+    pub validation_files: Files,
+    pub training_files: Files,
+    pub status: String,
+    pub updated_at: u64,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_usage: Option<TokenUsage>,
+}
+
+/// Hyper parameters for fine-tuning a model.
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct HyperParams {
+    pub n_epochs: u32,
+    pub batch_size: u32,
+    pub learning_rate_multiplier: f32,
+    pub prompt_loss_weight: f32,
+    pub compute_classification_metrics: bool,
+    pub classification_n_classes: u32,
+    pub classification_positive_class: String,
+    pub classification_betas: Vec<f32>,
+}
+
+/// Events occuring on Fine-tunes
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct Event {
+    pub object: String,
+    pub created_at: u64,
+    pub level: String,
+    pub message: String,
+}
+
+type Events = Vec<Event>;
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct ListEvents {
+    pub object: String,
+    pub data: Vec<Event>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_usage: Option<TokenUsage>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct ListFineTune {
+    pub object: String,
+    pub data: Vec<FineTune>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_usage: Option<TokenUsage>,
+}
+
+/// Parameters for [`Create Fine-tuning Job`](crate::fine_tuning::create) request.
+#[skip_serializing_none]
+#[derive(Builder, Clone, Debug, Default, Deserialize, Serialize)]
+#[builder(default, setter(into, strip_option))]
+pub struct CreateFineTuningJobParam {
+    /// The name of the model to fine-tune, like `gpt-4o-mini-2024-07-18`.
+    pub model: String,
+
+    /// The ID of an uploaded file that contains training data, with the [`FineTune`](Purpose::FineTune) purpose.
+    pub training_file: String,
+
+    /// The ID of an uploaded file that contains validation data, used to generate validation metrics periodically during fine-tuning.
+    pub validation_file: Option<String>,
+
+    /// A string of up to 64 characters added to the fine-tuned model name.
+    pub suffix: Option<String>,
+
+    /// The seed controlling the reproducibility of the job, picked at random if not set.
+    pub seed: Option<u64>,
+
+    /// The hyperparameters of supervised fine-tuning, superseded by the ones of the [`method`](CreateFineTuningJobParam::method).
+    pub hyperparameters: Option<FineTuningHyperparameters>,
+
+    /// The fine-tuning method, supervised by default.
+    pub method: Option<FineTuningMethod>,
+
+    /// Up to 16 key-value pairs attached to the job.
+    pub metadata: Option<HashMap<String, String>>,
+}
+
+impl CreateFineTuningJobParamBuilder {
+    pub fn new(model: impl Into<String>, training_file: impl Into<String>) -> Self {
+        Self {
+            model: Some(model.into()),
+            training_file: Some(training_file.into()),
+            ..Self::default()
+        }
+    }
+}
+
+/// The hyperparameters of a fine-tuning job, each one either set or picked automatically by OpenAI.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct FineTuningHyperparameters {
+    /// The number of examples in each batch.
+    pub batch_size: Option<Hyperparameter<u32>>,
+
+    /// The scaling factor of the learning rate.
+    pub learning_rate_multiplier: Option<Hyperparameter<f32>>,
+
+    /// The number of epochs, full cycles through the training dataset, to train the model for.
+    pub n_epochs: Option<Hyperparameter<u32>>,
+}
+
+/// A hyperparameter of a fine-tuning job, given as `auto` for OpenAI to pick it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Hyperparameter<T> {
+    Auto,
+    Value(T),
+}
+
+impl<T: Serialize> Serialize for Hyperparameter<T> {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        match self {
+            Hyperparameter::Auto => serializer.serialize_str("auto"),
+            Hyperparameter::Value(value) => value.serialize(serializer),
+        }
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Hyperparameter<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Repr<T> {
+            Mode(String),
+            Value(T),
+        }
+
+        match Repr::deserialize(deserializer)? {
+            Repr::Mode(mode) if mode == "auto" => Ok(Self::Auto),
+            Repr::Mode(mode) => Err(serde::de::Error::unknown_variant(&mode, &["auto"])),
+            Repr::Value(value) => Ok(Self::Value(value)),
+        }
+    }
+}
+
+impl<T> From<T> for Hyperparameter<T> {
+    fn from(value: T) -> Self {
+        Self::Value(value)
+    }
+}
+
+/// The method of a fine-tuning job, along with its hyperparameters.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum FineTuningMethod {
+    /// Trains the model to respond like the assistant messages of the examples.
+    Supervised { supervised: SupervisedMethod },
+
+    /// Direct Preference Optimization, training the model to prefer a response over another one for the same input.
+    Dpo { dpo: DpoMethod },
+
+    /// Any other method returned by the API, unknown to this version of the crate.
+    #[serde(other)]
+    Other,
+}
+
+impl FineTuningMethod {
+    pub fn supervised(hyperparameters: FineTuningHyperparameters) -> Self {
+        Self::Supervised {
+            supervised: SupervisedMethod {
+                hyperparameters: Some(hyperparameters),
+            },
+        }
+    }
+
+    pub fn dpo(hyperparameters: DpoHyperparameters) -> Self {
+        Self::Dpo {
+            dpo: DpoMethod {
+                hyperparameters: Some(hyperparameters),
+            },
+        }
+    }
+
+    /// The hyperparameters shared by every method, if set.
+    pub fn hyperparameters(&self) -> Option<FineTuningHyperparameters> {
+        match self {
+            FineTuningMethod::Supervised { supervised } => supervised.hyperparameters.clone(),
+            FineTuningMethod::Dpo { dpo } => {
+                dpo.hyperparameters
+                    .as_ref()
+                    .map(|hyperparameters| FineTuningHyperparameters {
+                        batch_size: hyperparameters.batch_size,
+                        learning_rate_multiplier: hyperparameters.learning_rate_multiplier,
+                        n_epochs: hyperparameters.n_epochs,
+                    })
+            }
+            FineTuningMethod::Other => None,
+        }
+    }
+}
+
+/// The configuration of the [`supervised`](FineTuningMethod::Supervised) fine-tuning method.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct SupervisedMethod {
+    pub hyperparameters: Option<FineTuningHyperparameters>,
+}
+
+/// The configuration of the [`DPO`](FineTuningMethod::Dpo) fine-tuning method.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct DpoMethod {
+    pub hyperparameters: Option<DpoHyperparameters>,
+}
+
+/// The hyperparameters of the [`DPO`](FineTuningMethod::Dpo) fine-tuning method.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct DpoHyperparameters {
+    /// The weight of the penalty between the policy and the reference model, higher being more conservative.
+    pub beta: Option<Hyperparameter<f32>>,
+
+    pub batch_size: Option<Hyperparameter<u32>>,
+    pub learning_rate_multiplier: Option<Hyperparameter<f32>>,
+    pub n_epochs: Option<Hyperparameter<u32>>,
+}
+
+/// The status of a fine-tuning job.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FineTuningJobStatus {
+    #[default]
+    ValidatingFiles,
+    Queued,
+    Running,
+    Succeeded,
+    Failed,
+    Cancelled,
+
+    /// Any other status returned by the API, unknown to this version of the crate.
+    #[serde(other)]
+    Other,
+}
+
+impl FineTuningJobStatus {
+    /// Whether the job is over, successfully or not, and won't change anymore.
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            FineTuningJobStatus::Succeeded
+                | FineTuningJobStatus::Failed
+                | FineTuningJobStatus::Cancelled
+        )
+    }
+}
+
+/// Response from [`Create Fine-tuning Job`](crate::fine_tuning::create) & [`Retrieve Fine-tuning Job`](crate::fine_tuning::retrieve) requests.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct FineTuningJob {
+    pub id: String,
+    pub object: String,
+    pub created_at: u64,
+    pub model: String,
+    pub organization_id: String,
+    pub status: FineTuningJobStatus,
+    pub hyperparameters: FineTuningHyperparameters,
+    pub training_file: String,
+    pub validation_file: Option<String>,
+
+    /// The IDs of the files holding the results of the job, retrieved through the [`Files`](crate::file::content) API.
+    pub result_files: Vec<String>,
+
+    /// The name of the fine-tuned model, once the job succeeded.
+    pub fine_tuned_model: Option<String>,
+
+    /// The total number of billable tokens processed, once the job succeeded.
+    pub trained_tokens: Option<u64>,
+
+    /// The Unix timestamp (in seconds) at which the job finished.
+    pub finished_at: Option<u64>,
+
+    /// The Unix timestamp (in seconds) at which the job is estimated to finish.
+    pub estimated_finish: Option<u64>,
+
+    /// The reason of the failure, when the job failed.
+    pub error: Option<FineTuningJobError>,
+
+    pub seed: Option<u64>,
+    pub user_provided_suffix: Option<String>,
+    pub metadata: Option<HashMap<String, String>>,
+
+    /// The method of the job, along with its hyperparameters.
+    pub method: Option<FineTuningMethod>,
+}
+
+/// The reason why a fine-tuning job failed.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct FineTuningJobError {
+    pub code: String,
+    pub message: String,
+
+    /// The parameter that was invalid, usually `training_file` or `validation_file`.
+    pub param: Option<String>,
+}
+
+/// Parameters for [`List Fine-tuning Jobs`](crate::fine_tuning::list) request.
+#[skip_serializing_none]
+#[derive(Builder, Clone, Debug, Default, Deserialize, Serialize)]
+#[builder(default, setter(into, strip_option))]
+pub struct ListFineTuningJobsParam {
+    /// Identifier for the last job from the previous pagination request.
+    pub after: Option<String>,
+
+    /// Number of jobs to retrieve, defaults to 20.
+    pub limit: Option<u32>,
+}
+
+/// Response from [`List Fine-tuning Jobs`](crate::fine_tuning::list) request.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct ListFineTuningJobs {
+    pub object: String,
+    pub data: Vec<FineTuningJob>,
+    pub has_more: bool,
+}
+
+/// A status update of a fine-tuning job, like the training metrics of a step.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct FineTuningJobEvent {
+    pub id: String,
+    pub object: String,
+    pub created_at: u64,
+
+    /// The log level of the event, `info`, `warn` or `error`.
+    pub level: String,
+    pub message: String,
+
+    /// The type of the event, `message` or `metrics`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+
+    /// The data of `metrics` events, like the `step` and the `train_loss`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<serde_json::Value>,
+}
+
+/// Parameters for [`List Fine-tuning Events`](crate::fine_tuning::list_events) request.
+#[skip_serializing_none]
+#[derive(Builder, Clone, Debug, Default, Deserialize, Serialize)]
+#[builder(default, setter(into, strip_option))]
+pub struct ListFineTuningJobEventsParam {
+    /// Identifier for the last event from the previous pagination request.
+    pub after: Option<String>,
+
+    /// Number of events to retrieve, defaults to 20.
+    pub limit: Option<u32>,
+}
+
+/// Response from [`List Fine-tuning Events`](crate::fine_tuning::list_events) request, from the newest event to the oldest.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct ListFineTuningJobEvents {
+    pub object: String,
+    pub data: Vec<FineTuningJobEvent>,
+    pub has_more: bool,
+}
+
+/// The size of the generated images.
+///
+/// Must be one of 256x256, 512x512, or 1024x1024 for `dall-e-2`, one of 1024x1024, 1792x1024, or 1024x1792 for `dall-e-3`,
+/// and one of 1024x1024, 1536x1024, 1024x1536, or auto for `gpt-image-1`.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+pub enum ImageSize {
+    S256x256,
+    S512x512,
+    #[default]
+    S1024x1024,
+    S1792x1024,
+    S1024x1792,
+    S1536x1024,
+    S1024x1536,
+
+    /// Lets `gpt-image-1` pick the size.
+    Auto,
+}
+
+impl ImageSize {
+    /// Whether the size is supported by the image model.
+    ///
+    /// The models other than `dall-e-2`, `dall-e-3` and `gpt-image-1`, like Azure deployments or snapshots,
+    /// are assumed to support all of them, the API validating them.
+    pub fn is_supported_by(&self, model: &str) -> bool {
+        match model {
+            "dall-e-3" => matches!(
+                self,
+                ImageSize::S1024x1024 | ImageSize::S1792x1024 | ImageSize::S1024x1792
+            ),
+            "gpt-image-1" => matches!(
+                self,
+                ImageSize::S1024x1024
+                    | ImageSize::S1536x1024
+                    | ImageSize::S1024x1536
+                    | ImageSize::Auto
+            ),
+            "dall-e-2" => matches!(
+                self,
+                ImageSize::S256x256 | ImageSize::S512x512 | ImageSize::S1024x1024
+            ),
+            _ => true,
+        }
+    }
+}
+
+impl std::fmt::Display for ImageSize {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ImageSize::S256x256 => write!(f, "256x256"),
+            ImageSize::S512x512 => write!(f, "512x512"),
+            ImageSize::S1024x1024 => write!(f, "1024x1024"),
+            ImageSize::S1792x1024 => write!(f, "1792x1024"),
+            ImageSize::S1024x1792 => write!(f, "1024x1792"),
+            ImageSize::S1536x1024 => write!(f, "1536x1024"),
+            ImageSize::S1024x1536 => write!(f, "1024x1536"),
+            ImageSize::Auto => write!(f, "auto"),
+        }
+    }
+}
+
+impl FromStr for ImageSize {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "256x256" => Ok(ImageSize::S256x256),
+            "512x512" => Ok(ImageSize::S512x512),
+            "1024x1024" => Ok(ImageSize::S1024x1024),
+            "1792x1024" => Ok(ImageSize::S1792x1024),
+            "1024x1792" => Ok(ImageSize::S1024x1792),
+            "1536x1024" => Ok(ImageSize::S1536x1024),
+            "1024x1536" => Ok(ImageSize::S1024x1536),
+            "auto" => Ok(ImageSize::Auto),
+            _ => Err(format!("Invalid ImageSize: {}", s)),
+        }
+    }
+}
+
+impl Serialize for ImageSize {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+/// The quality of the generated images.
+///
+/// `standard` and `hd` are supported by `dall-e-3`, while `low`, `medium`, `high` and `auto` are supported by `gpt-image-1`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ImageQuality {
+    #[default]
+    Standard,
+
+    /// Images with finer details and greater consistency across the image.
+    Hd,
+
+    Low,
+    Medium,
+    High,
+    Auto,
+}
+
+impl ImageQuality {
+    /// Whether the quality is supported by the image model, the unknown ones being assumed to support all of them.
+    pub fn is_supported_by(&self, model: &str) -> bool {
+        match model {
+            "dall-e-3" => matches!(self, ImageQuality::Standard | ImageQuality::Hd),
+            "gpt-image-1" => matches!(
+                self,
+                ImageQuality::Low | ImageQuality::Medium | ImageQuality::High | ImageQuality::Auto
+            ),
+            "dall-e-2" => *self == ImageQuality::Standard,
+            _ => true,
+        }
+    }
+}
+
+/// The format of the images generated by `gpt-image-1`, PNG by default.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ImageOutputFormat {
+    #[default]
+    Png,
+    Jpeg,
+    Webp,
+}
+
+impl ImageOutputFormat {
+    /// The file extension of the format.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ImageOutputFormat::Png => "png",
+            ImageOutputFormat::Jpeg => "jpeg",
+            ImageOutputFormat::Webp => "webp",
+        }
+    }
+}
+
+/// The background of the images generated by `gpt-image-1`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ImageBackground {
+    /// Lets the model pick the best background for the image.
+    #[default]
+    Auto,
+
+    /// A transparent background, requires the `png` or `webp` output format.
+    Transparent,
+
+    Opaque,
+}
+
+/// The content moderation level of the images generated by `gpt-image-1`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ImageModeration {
+    #[default]
+    Auto,
+
+    /// Less restrictive filtering.
+    Low,
+}
+
+/// The style of the generated images, only supported by `dall-e-3`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ImageStyle {
+    /// Hyper-real and dramatic images, the default.
+    Vivid,
+
+    /// More natural, less hyper-real looking images.
+    Natural,
+}
+
+/// Why the [`GenerateImageParam`] are invalid for the requested model, as returned when building them.
+#[derive(Clone, Debug, PartialEq, thiserror::Error)]
+pub enum ImageParamError {
+    #[error("The prompt must be at most {max} characters long for {model}.")]
+    PromptTooLong { max: usize, model: String },
+
+    #[error("n must be between 1 and {max} for {model}, got {n}.")]
+    UnsupportedN { n: u8, max: u8, model: String },
+
+    #[error("The size {size} is not supported by {model}.")]
+    UnsupportedSize { size: ImageSize, model: String },
+
+    #[error("The quality {quality:?} is not supported by {model}.")]
+    UnsupportedQuality {
+        quality: ImageQuality,
+        model: String,
+    },
+
+    /// A parameter which isn't accepted by the model at all.
+    #[error("The {param} parameter is not supported by {model}.")]
+    UnsupportedParameter { param: &'static str, model: String },
+
+    #[error("The output compression must be between 0 and 100, with the jpeg or webp output format, got {0}.")]
+    InvalidOutputCompression(u8),
+
+    #[error("The partial images must be between 0 and 3, got {0}.")]
+    InvalidPartialImages(u8),
+
+    #[error("A transparent background is not supported by the {0:?} output format.")]
+    OpaqueOutputFormat(ImageOutputFormat),
+
+    #[error("{0}")]
+    UninitializedField(&'static str),
+}
+
+impl From<derive_builder::UninitializedFieldError> for ImageParamError {
+    fn from(err: derive_builder::UninitializedFieldError) -> Self {
+        Self::UninitializedField(err.field_name())
+    }
+}
+
+/// The error returned when building [`GenerateImageParam`].
+pub type GenerateImageParamBuilderError = ImageParamError;
+
+/// Parameters for [`Generate Image`](generate) request.
+///
+/// The combination of parameters is validated against the model when building, instead of failing with a request error.
+#[skip_serializing_none]
+#[derive(Builder, Clone, Debug, Default, Deserialize, Serialize)]
+#[builder(
+    default,
+    setter(into, strip_option),
+    build_fn(validate = "Self::validate", error = "ImageParamError")
+)]
+pub struct GenerateImageParam {
+    /// A text description of the desired image(s).
+    ///
+    /// The maximum length is 1000 characters for `dall-e-2` and 4000 characters for `dall-e-3`.
+    prompt: String,
+
+    /// The model to use for image generation, one of `dall-e-2`, `dall-e-3` or `gpt-image-1`. Defaults to `dall-e-2`.
+    ///
+    /// The parameters of the other models, like Azure deployments, aren't validated by the builder, but by the API.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    model: Option<String>,
+
+    /// The background of the generated images, only supported by `gpt-image-1`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    background: Option<ImageBackground>,
+
+    /// The content moderation level of the generated images, only supported by `gpt-image-1`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    moderation: Option<ImageModeration>,
+
+    /// The number of images to generate.
+    ///
+    /// Must be between 1 and 10, only 1 is supported for `dall-e-3`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    n: Option<u8>,
+
+    /// The compression level, from 0 to 100, of the generated images.
+    ///
+    /// Only supported by `gpt-image-1`, with the `jpeg` or `webp` output format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    output_compression: Option<u8>,
+
+    /// The format of the generated images, only supported by `gpt-image-1`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    output_format: Option<ImageOutputFormat>,
+
+    /// The number of partial images, from 0 to 3, sent by [`generate_stream`](crate::image::generate_stream) before the final one.
+    ///
+    /// Only supported by `gpt-image-1`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    partial_images: Option<u8>,
+
+    /// The quality of the generated images, see [`ImageQuality`] for the qualities supported by each model.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    quality: Option<ImageQuality>,
+
+    /// The format in which the generated images are returned.
+    ///
+    /// Not supported by `gpt-image-1`, which always returns base64 encoded images.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    response_format: Option<ImageResponseFormat>,
+
+    /// The size of the generated images, see [`ImageSize`] for the sizes supported by each model.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    size: Option<ImageSize>,
+
+    /// The style of the generated images, only supported by `dall-e-3`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    style: Option<ImageStyle>,
+
+    /// A unique identifier representing your end-user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    user: Option<String>,
+
+    // Whether to stream back partial images.
+    #[serde(default, skip_serializing_if = "is_false")]
+    #[builder(setter(skip))]
+    pub(crate) stream: bool,
+}
+
+impl GenerateImageParamBuilder {
+    pub fn new(prompt: impl Into<String>) -> Self {
+        Self {
+            prompt: Some(prompt.into()),
+            ..Default::default()
+        }
+    }
+
+    fn validate(&self) -> std::result::Result<(), ImageParamError> {
+        let model = self.model.clone().flatten();
+        let model = model.as_deref().unwrap_or("dall-e-2");
+        // The other models, like Azure deployments, snapshots or newer ones, are left to the API to validate.
+        if !matches!(model, "dall-e-2" | "dall-e-3" | "gpt-image-1") {
+            return Ok(());
+        }
+        let (dalle3, gpt_image) = (model == "dall-e-3", model == "gpt-image-1");
+        let unsupported = |param| ImageParamError::UnsupportedParameter {
+            param,
+            model: model.to_string(),
+        };
+
+        let max = match model {
+            "dall-e-3" => 4000,
+            "gpt-image-1" => 32000,
+            _ => 1000,
+        };
+        let prompt = self.prompt.as_deref().unwrap_or_default();
+        if prompt.chars().count() > max {
+            return Err(ImageParamError::PromptTooLong {
+                max,
+                model: model.to_string(),
+            });
+        }
+
+        let max = if dalle3 { 1 } else { 10 };
+        if let Some(n) = self.n.flatten().filter(|n| !(1..=max).contains(n)) {
+            return Err(ImageParamError::UnsupportedN {
+                n,
+                max,
+                model: model.to_string(),
+            });
+        }
+
+        if let Some(size) = self.size.clone().flatten() {
+            if !size.is_supported_by(model) {
+                return Err(ImageParamError::UnsupportedSize {
+                    size,
+                    model: model.to_string(),
+                });
+            }
+        }
+
+        if let Some(quality) = self.quality.flatten() {
+            if !quality.is_supported_by(model) {
+                return Err(ImageParamError::UnsupportedQuality {
+                    quality,
+                    model: model.to_string(),
+                });
+            }
+        }
+
+        if !dalle3 && self.style.flatten().is_some() {
+            return Err(unsupported("style"));
+        }
+
+        // `gpt-image-1` always returns base64 encoded images.
+        if gpt_image && self.response_format.flatten().is_some() {
+            return Err(unsupported("response_format"));
+        }
+
+        let gpt_image_only = [
+            ("background", self.background.flatten().is_some()),
+            ("moderation", self.moderation.flatten().is_some()),
+            ("output_format", self.output_format.flatten().is_some()),
+            ("partial_images", self.partial_images.flatten().is_some()),
+            (
+                "output_compression",
+                self.output_compression.flatten().is_some(),
+            ),
+        ];
+        if let Some((param, _)) = gpt_image_only.iter().find(|(_, set)| *set && !gpt_image) {
+            return Err(unsupported(param));
+        }
+
+        let format = self.output_format.flatten().unwrap_or_default();
+        if let Some(compression) = self.output_compression.flatten() {
+            if format == ImageOutputFormat::Png || compression > 100 {
+                return Err(ImageParamError::InvalidOutputCompression(compression));
+            }
+        }
+
+        if let Some(n) = self.partial_images.flatten().filter(|n| *n > 3) {
+            return Err(ImageParamError::InvalidPartialImages(n));
+        }
+
+        if self.background.flatten() == Some(ImageBackground::Transparent)
+            && format == ImageOutputFormat::Jpeg
+        {
+            return Err(ImageParamError::OpaqueOutputFormat(format));
+        }
+
+        Ok(())
+    }
+}
+
+/// Response from [Generate](generate), [Edit](edit) & [Variation](variation) requests.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Image {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<u64>,
+
+    /// The format of the generated images, set by `gpt-image-1`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output_format: Option<ImageOutputFormat>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<Vec<ImageData>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_usage: Option<TokenUsage>,
+}
+
+impl Image {
+    /// Save the image(s) to the given directory.
+    /// The images will be saved as based on the generated image id.
+    ///
+    /// For example, a generated image with url `https://oaidalleapiprodscus.blob.core.windows.net/private/org-123/user-456/img-789.png`
+    /// Will be saved with a name of `img-789.png` in the given directory.
+    ///
+    ///
+    /// ## Example
+    /// ```no_run
+    /// // Generate an image based on a prompt and save it locally.
+    /// use fieri::{Client, image::{ImageSize, GenerateImageParamBuilder, generate}};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::new();
+    ///
+    ///     let param = GenerateImageParamBuilder::new("A cat")
+    ///         .size(ImageSize::S256x256)
+    ///         .n(1)
+    ///         .build()?;
+    ///
+    ///     let image = generate(&client, &param)
+    ///         .await?
+    ///         .save("/tmp/")
+    ///         .await?;
+    ///
+    ///     Ok(())
+    /// }
+    ///
+    /// ```
+    pub async fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        if let Some(data) = &self.data {
+            for (i, image) in data.iter().enumerate() {
+                let Some(url) = &image.url else {
+                    fs::write(path.as_ref().join(format!("image_{i}.png")), image.bytes()?)?;
+                    continue;
+                };
+                let resp = get(url).await?;
+
+                let def_img_name = format!("image_{i}.png");
+                let fname = resp
+                    .url()
+                    .path_segments()
+                    .and_then(|mut segments| segments.next_back())
+                    .unwrap_or(def_img_name.as_str());
+
+                let full_path = Path::new(path.as_ref()).join(fname);
+                let mut file = fs::File::create(full_path)?;
+                let mut content = Cursor::new(resp.bytes().await?);
+                copy(&mut content, &mut file)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Saves the images to the given directory as numbered files, `image_0.png`, `image_1.png` and so on.
+    ///
+    /// The extension follows the [`output_format`](Image::output_format), PNG being the default.
+    ///
+    /// Base64 encoded images are decoded, while images returned as URLs are downloaded with the client,
+    /// see [`ImageData::download`]. Returns the paths of the files.
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use fieri::{Client, image::{GenerateImageParamBuilder, ImageResponseFormat, generate}};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::new();
+    ///
+    ///     let param = GenerateImageParamBuilder::new("A cat")
+    ///         .response_format(ImageResponseFormat::B64Json)
+    ///         .n(2)
+    ///         .build()?;
+    ///
+    ///     let paths = generate(&client, &param).await?.save_all(&client, "/tmp/").await?;
+    ///     println!("{:?}", paths);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn save_all<P: AsRef<Path>>(
+        &self,
+        client: &crate::Client,
+        dir: P,
+    ) -> Result<Vec<PathBuf>> {
+        let mut paths = Vec::new();
+
+        for (i, image) in self.data.iter().flatten().enumerate() {
+            let bytes = image.download(client).await?;
+
+            let extension = self.output_format.unwrap_or_default().extension();
+            let path = dir.as_ref().join(format!("image_{i}.{extension}"));
+            fs::write(&path, bytes)?;
+            paths.push(path);
+        }
+
+        Ok(paths)
+    }
+}
+
+/// A generated image, either as a URL or base64 encoded, depending on the requested [`ImageResponseFormat`].
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct ImageData {
+    /// The URL of the image, valid for 60 minutes.
+    pub url: Option<String>,
+
+    /// The base64 encoded image.
+    pub b64_json: Option<String>,
+
+    /// The prompt that was used to generate the image, if there was any revision to the prompt.
+    pub revised_prompt: Option<String>,
+}
+
+impl ImageData {
+    /// Decodes the base64 encoded image, failing with [`Error::ImageUrlError`](crate::Error::ImageUrlError)
+    /// if the image was returned as a URL, see [`ImageData::download`].
+    pub fn bytes(&self) -> Result<Vec<u8>> {
+        use base64::Engine;
+
+        let data = self.b64_json.as_deref().ok_or(Error::ImageUrlError)?;
+        Ok(base64::engine::general_purpose::STANDARD.decode(data)?)
+    }
+
+    /// The bytes of the image, downloaded if it was returned as a URL or decoded otherwise.
+    ///
+    /// URLs expire after 60 minutes, so images should be downloaded soon after they are generated.
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use fieri::{Client, image::{GenerateImageParamBuilder, ImageResponseFormat, generate}};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::new();
+    ///
+    ///     let param = GenerateImageParamBuilder::new("A cat")
+    ///         .response_format(ImageResponseFormat::Url)
+    ///         .build()?;
+    ///
+    ///     for image in generate(&client, &param).await?.data.unwrap_or_default() {
+    ///         let bytes = image.download(&client).await?;
+    ///         println!("{} bytes", bytes.len());
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn download(&self, client: &crate::Client) -> Result<Vec<u8>> {
+        match &self.url {
+            Some(url) => client.download(url).await,
+            None => self.bytes(),
+        }
+    }
+}
+
+/// A single event from [`Generate Image Stream`](crate::image::generate_stream).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(tag = "type")]
+pub enum ImageStreamEvent {
+    /// A progressively refined frame of the image being generated.
+    #[serde(rename = "image_generation.partial_image")]
+    PartialImage {
+        /// The position of the frame, starting at 0.
+        partial_image_index: u8,
+
+        #[serde(flatten)]
+        image: ImageFrame,
+    },
+
+    /// The final image, once the generation is done.
+    #[serde(rename = "image_generation.completed")]
+    Completed {
+        #[serde(flatten)]
+        image: ImageFrame,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        usage: Option<ImageUsage>,
+    },
+
+    /// Any other event, unknown to this version of the crate, skipped by the stream.
+    #[serde(other)]
+    Other,
+}
+
+impl ImageStreamEvent {
+    /// The image carried by the event, whether partial or final.
+    pub fn image(&self) -> Option<&ImageFrame> {
+        match self {
+            ImageStreamEvent::PartialImage { image, .. } => Some(image),
+            ImageStreamEvent::Completed { image, .. } => Some(image),
+            ImageStreamEvent::Other => None,
+        }
+    }
+
+    /// Whether this is the final image.
+    pub fn is_completed(&self) -> bool {
+        matches!(self, ImageStreamEvent::Completed { .. })
+    }
+}
+
+/// An image sent by [`Generate Image Stream`](crate::image::generate_stream).
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct ImageFrame {
+    /// The base64 encoded image.
+    pub b64_json: String,
+
+    pub created_at: Option<u64>,
+
+    /// The size of the image, like `1024x1024`.
+    pub size: Option<String>,
+
+    pub quality: Option<ImageQuality>,
+    pub background: Option<ImageBackground>,
+    pub output_format: Option<ImageOutputFormat>,
+}
+
+impl ImageFrame {
+    /// Decodes the base64 encoded image.
+    pub fn bytes(&self) -> Result<Vec<u8>> {
+        use base64::Engine;
+
+        Ok(base64::engine::general_purpose::STANDARD.decode(&self.b64_json)?)
+    }
+}
+
+/// Tokens used to generate an image with `gpt-image-1`.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct ImageUsage {
+    pub input_tokens: u32,
+    pub output_tokens: u32,
+    pub total_tokens: u32,
+
+    /// Breakdown of the tokens used in the input.
+    pub input_tokens_details: Option<ImageInputTokensDetails>,
+}
+
+/// Breakdown of the tokens used in the input of an image generation.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct ImageInputTokensDetails {
+    pub text_tokens: u32,
+    pub image_tokens: u32,
+}
+
+/// link to an image.
+#[deprecated(since = "0.7.0", note = "Please use `ImageData`.")]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Link {
+    pub url: String,
+}
+
+/// The format in which the generated images are returned.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ImageResponseFormat {
+    /// URLs of the images, valid for 60 minutes after generation.
+    #[default]
+    Url,
+
+    /// The base64 encoded images, see [`ImageData::bytes`].
+    B64Json,
+}
+
+impl Display for ImageResponseFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ImageResponseFormat::Url => write!(f, "url"),
+            ImageResponseFormat::B64Json => write!(f, "b64_json"),
+        }
+    }
+}
+
+/// A file to upload as multipart/form-data, read from a path, given as bytes or streamed.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FileUpload {
+    /// A file on disk, streamed when the request is sent.
+    Path(PathBuf),
+
+    /// The contents of a file, along with the name it's uploaded as.
+    Bytes { file_name: String, data: Vec<u8> },
+
+    /// A body streamed as the request is sent, instead of being buffered in memory.
+    ///
+    /// The length, when known, is sent upfront instead of using a chunked upload.
+    Stream {
+        file_name: String,
+        length: Option<u64>,
+        body: UploadBody,
+    },
+}
+
+impl FileUpload {
+    pub fn bytes(file_name: impl Into<String>, data: impl Into<Vec<u8>>) -> Self {
+        Self::Bytes {
+            file_name: file_name.into(),
+            data: data.into(),
+        }
+    }
+
+    /// Reads the whole contents of the reader, to be uploaded under the given name.
+    pub fn reader(file_name: impl Into<String>, mut reader: impl std::io::Read) -> Result<Self> {
+        let mut data = Vec::new();
+        reader.read_to_end(&mut data)?;
+
+        Ok(Self::bytes(file_name, data))
+    }
+
+    /// Streams the contents of the async reader, to be uploaded under the given name.
+    ///
+    /// The reader is only read once the request is sent, a chunk at a time.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn async_reader<R>(file_name: impl Into<String>, reader: R) -> Self
+    where
+        R: tokio::io::AsyncRead + Send + Sync + 'static,
+    {
+        use tokio::io::AsyncReadExt;
+
+        let stream = futures::stream::try_unfold(Box::pin(reader), |mut reader| async move {
+            let mut chunk = vec![0; 64 * 1024];
+            let n = reader.read(&mut chunk).await?;
+            chunk.truncate(n);
+
+            Ok::<_, std::io::Error>((n > 0).then_some((chunk, reader)))
+        });
+
+        Self::stream(file_name, reqwest::Body::wrap_stream(stream), None)
+    }
+
+    /// Streams the already opened file from disk, to be uploaded under the given name.
+    ///
+    /// Its length is read from its metadata, so that large files, like training data, are never fully loaded in memory.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn file(file_name: impl Into<String>, file: tokio::fs::File) -> Result<Self> {
+        let length = file.metadata().await?.len();
+
+        Ok(Self::stream(file_name, file, Some(length)))
+    }
+
+    /// Streams the body, to be uploaded under the given name, along with its length if known.
+    pub fn stream(
+        file_name: impl Into<String>,
+        body: impl Into<reqwest::Body>,
+        length: Option<u64>,
+    ) -> Self {
+        Self::Stream {
+            file_name: file_name.into(),
+            length,
+            body: UploadBody::new(body.into()),
+        }
+    }
+
+    /// Turns the file into a form part, with the mime type guessed from its name.
+    pub(crate) fn into_part(self) -> Result<reqwest::multipart::Part> {
+        let (file_name, part) = match self {
+            FileUpload::Path(path) => {
+                let file_name = path
+                    .file_name()
+                    .map(|name| name.to_string_lossy().into_owned())
+                    .unwrap_or_default();
+
+                #[cfg(not(target_arch = "wasm32"))]
+                let part = {
+                    let file = fs::File::open(path)?;
+                    let length = file.metadata()?.len();
+                    let body = reqwest::Body::from(tokio::fs::File::from_std(file));
+
+                    reqwest::multipart::Part::stream_with_length(body, length)
+                };
+                #[cfg(target_arch = "wasm32")]
+                let part = reqwest::multipart::Part::bytes(fs::read(path)?);
+
+                (file_name, part)
+            }
+            FileUpload::Bytes { file_name, data } => {
+                (file_name, reqwest::multipart::Part::bytes(data))
+            }
+            FileUpload::Stream {
+                file_name,
+                length,
+                body,
+            } => {
+                let body = body.take().ok_or_else(|| {
+                    std::io::Error::new(
+                        std::io::ErrorKind::Other,
+                        format!("The stream of {file_name} was already uploaded."),
+                    )
+                })?;
+
+                #[cfg(not(target_arch = "wasm32"))]
+                let part = match length {
+                    Some(length) => reqwest::multipart::Part::stream_with_length(body, length),
+                    None => reqwest::multipart::Part::stream(body),
+                };
+                // The fetch API sends the whole body at once, its length along.
+                #[cfg(target_arch = "wasm32")]
+                let part = {
+                    let _ = length;
+                    reqwest::multipart::Part::stream(body)
+                };
+                (file_name, part)
+            }
+        };
+
+        let mime = mime_guess::from_path(&file_name).first_or_octet_stream();
+        Ok(part.file_name(file_name).mime_str(mime.as_ref())?)
+    }
+}
+
+/// A streamed [`FileUpload`] body, which can only be uploaded once.
+///
+/// Clones share the same body, so only the first of them to be uploaded sends it.
+#[derive(Clone)]
+pub struct UploadBody(Arc<Mutex<Option<reqwest::Body>>>);
+
+impl UploadBody {
+    fn new(body: reqwest::Body) -> Self {
+        Self(Arc::new(Mutex::new(Some(body))))
+    }
+
+    fn take(&self) -> Option<reqwest::Body> {
+        self.0.lock().ok()?.take()
+    }
+}
+
+impl std::fmt::Debug for UploadBody {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UploadBody").finish_non_exhaustive()
+    }
+}
+
+impl PartialEq for UploadBody {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl From<&str> for FileUpload {
+    fn from(value: &str) -> Self {
+        Self::Path(value.into())
+    }
+}
+
+impl From<String> for FileUpload {
+    fn from(value: String) -> Self {
+        Self::Path(value.into())
+    }
+}
+
+impl From<&Path> for FileUpload {
+    fn from(value: &Path) -> Self {
+        Self::Path(value.into())
+    }
+}
+
+impl From<PathBuf> for FileUpload {
+    fn from(value: PathBuf) -> Self {
+        Self::Path(value)
+    }
+}
+
+/// Parameters for [`Edit Image`](edit) request.
+#[skip_serializing_none]
+#[derive(Builder, Debug, Deserialize, Serialize)]
+#[builder(default, setter(into, strip_option))]
+pub struct EditImageParam {
+    /// A text description of the desired image(s). The maximum length is 1000 characters.
+    pub prompt: String,
+
+    /// The model to use for the edit, `dall-e-2` or `gpt-image-1`. Defaults to `dall-e-2`.
+    pub model: Option<String>,
+
+    /// An additional image whose fully transparent areas indicate where the image should be edited.
+    ///
+    /// Must be a valid PNG file, less than 4MB, and have the same dimensions as the image.
+    #[serde(skip)]
+    pub mask: Option<FileUpload>,
+
+    /// The format in which the generated images are returned.
+    pub response_format: Option<ImageResponseFormat>,
+
+    /// The number of images to generate. Must be between 1 and 10.
+    pub n: u8,
+
+    /// The size of the generated images.
+    pub size: ImageSize,
+
+    /// A unique identifier representing your end-user, which can help OpenAI to monitor and detect abuse.
+    pub user: String,
+}
+
+impl Default for EditImageParam {
+    fn default() -> Self {
+        Self {
+            prompt: String::new(),
+            model: None,
+            mask: None,
+            response_format: None,
+            n: 1,
+            size: ImageSize::S1024x1024,
+            user: String::new(),
+        }
+    }
+}
+
+impl EditImageParamBuilder {
+    pub fn new(prompt: impl Into<String>) -> Self {
+        Self {
+            prompt: Some(prompt.into()),
+            ..Default::default()
+        }
+    }
+}
+
+/// Parameters for [`Image Variation`](variation) request.
+#[skip_serializing_none]
+#[derive(Builder, Debug, Deserialize, Serialize)]
+#[builder(default, setter(into, strip_option))]
+pub struct VariateImageParam {
+    /// The model to use for the variation, only `dall-e-2` is currently available.
+    pub model: Option<String>,
+
+    /// The number of images to generate. Must be between 1 and 10.
+    pub n: u8,
+
+    /// The format in which the generated images are returned.
+    pub response_format: Option<ImageResponseFormat>,
+
+    /// The size of the generated images.
+    pub size: ImageSize,
+
+    /// A unique identifier representing your end-user, which can help OpenAI to monitor and detect abuse.
+    pub user: String,
+}
+
+impl Default for VariateImageParam {
+    fn default() -> Self {
+        Self {
+            model: None,
+            n: 1,
+            response_format: None,
+            size: ImageSize::S1024x1024,
+            user: String::new(),
+        }
+    }
+}
+
+impl VariateImageParamBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// Response from [List Models](list) request.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct Models {
+    pub object: String,
+    pub data: Vec<Model>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_usage: Option<TokenUsage>,
+}
+
+/// Response from [Retrieve a Model](retrieve) request.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct Model {
+    pub id: String,
+    pub object: String,
+
+    /// The Unix timestamp, in seconds, of when the model was created.
+    pub created: u64,
+    pub owned_by: ModelOwner,
+
+    /// No longer returned by the API.
+    pub permission: Vec<Permissions>,
+
+    /// No longer returned by the API.
+    pub root: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_usage: Option<TokenUsage>,
+}
+
+impl Model {
+    /// The time the model was created at.
+    pub fn created_at(&self) -> std::time::SystemTime {
+        std::time::UNIX_EPOCH + Duration::from_secs(self.created)
+    }
+
+    /// Whether the model is a fine-tuned one, owned by an organization.
+    pub fn is_fine_tuned(&self) -> bool {
+        self.id.starts_with("ft:")
+    }
+}
+
+/// The owner of a [`Model`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum ModelOwner {
+    #[default]
+    #[serde(rename = "openai")]
+    OpenAI,
+    #[serde(rename = "system")]
+    System,
+    #[serde(rename = "openai-internal")]
+    OpenAIInternal,
+
+    /// An organization or a user, owning the models they fine-tuned, like `org-abc123`.
+    #[serde(untagged)]
+    Organization(String),
+}
+
+/// The name of a model, one of the current ones known to the crate, or any [`other`](ModelName::Other).
+///
+/// Converts into a `String`, so it can be given wherever a builder takes the name of a model.
+///
+/// ## Example
+/// ```
+/// use fieri::{chat::{ChatMessage, ChatParamBuilder}, model::ModelName};
+///
+/// let param = ChatParamBuilder::new(ModelName::Gpt4o, vec![ChatMessage::user("Hi!")]).build().unwrap();
+/// let param = ChatParamBuilder::new("my-fine-tuned-model", vec![ChatMessage::user("Hi!")]).build().unwrap();
+///
+/// assert_eq!("gpt-4o".parse::<ModelName>(), Ok(ModelName::Gpt4o));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ModelName {
+    // Chat models.
+    Gpt4o,
+    Gpt4oMini,
+    Gpt4_1,
+    Gpt4_1Mini,
+    Gpt4_1Nano,
+    Gpt4Turbo,
+    Gpt4,
+    Gpt35Turbo,
+    O1,
+    O1Mini,
+    O3,
+    O3Mini,
+    O4Mini,
+    Gpt4oAudioPreview,
+    Gpt4oRealtimePreview,
+    Gpt4oMiniRealtimePreview,
+
+    // Embedding models.
+    TextEmbedding3Small,
+    TextEmbedding3Large,
+    TextEmbeddingAda002,
+
+    // Image models.
+    GptImage1,
+    DallE3,
+    DallE2,
+
+    // Audio models.
+    Whisper1,
+    Gpt4oTranscribe,
+    Gpt4oMiniTranscribe,
+    Tts1,
+    Tts1Hd,
+    Gpt4oMiniTts,
+
+    // Moderation models.
+    OmniModerationLatest,
+    TextModerationLatest,
+
+    /// A model unknown to the crate, like a fine-tuned or a newly released one.
+    Other(String),
+}
+
+impl ModelName {
+    /// All the models known to the crate.
+    pub const KNOWN: [ModelName; 30] = [
+        ModelName::Gpt4o,
+        ModelName::Gpt4oMini,
+        ModelName::Gpt4_1,
+        ModelName::Gpt4_1Mini,
+        ModelName::Gpt4_1Nano,
+        ModelName::Gpt4Turbo,
+        ModelName::Gpt4,
+        ModelName::Gpt35Turbo,
+        ModelName::O1,
+        ModelName::O1Mini,
+        ModelName::O3,
+        ModelName::O3Mini,
+        ModelName::O4Mini,
+        ModelName::Gpt4oAudioPreview,
+        ModelName::Gpt4oRealtimePreview,
+        ModelName::Gpt4oMiniRealtimePreview,
+        ModelName::TextEmbedding3Small,
+        ModelName::TextEmbedding3Large,
+        ModelName::TextEmbeddingAda002,
+        ModelName::GptImage1,
+        ModelName::DallE3,
+        ModelName::DallE2,
+        ModelName::Whisper1,
+        ModelName::Gpt4oTranscribe,
+        ModelName::Gpt4oMiniTranscribe,
+        ModelName::Tts1,
+        ModelName::Tts1Hd,
+        ModelName::Gpt4oMiniTts,
+        ModelName::OmniModerationLatest,
+        ModelName::TextModerationLatest,
+    ];
+
+    /// The name of the model, as sent to the API.
+    pub fn as_str(&self) -> &str {
+        match self {
+            ModelName::Gpt4o => "gpt-4o",
+            ModelName::Gpt4oMini => "gpt-4o-mini",
+            ModelName::Gpt4_1 => "gpt-4.1",
+            ModelName::Gpt4_1Mini => "gpt-4.1-mini",
+            ModelName::Gpt4_1Nano => "gpt-4.1-nano",
+            ModelName::Gpt4Turbo => "gpt-4-turbo",
+            ModelName::Gpt4 => "gpt-4",
+            ModelName::Gpt35Turbo => "gpt-3.5-turbo",
+            ModelName::O1 => "o1",
+            ModelName::O1Mini => "o1-mini",
+            ModelName::O3 => "o3",
+            ModelName::O3Mini => "o3-mini",
+            ModelName::O4Mini => "o4-mini",
+            ModelName::Gpt4oAudioPreview => "gpt-4o-audio-preview",
+            ModelName::Gpt4oRealtimePreview => "gpt-4o-realtime-preview",
+            ModelName::Gpt4oMiniRealtimePreview => "gpt-4o-mini-realtime-preview",
+            ModelName::TextEmbedding3Small => "text-embedding-3-small",
+            ModelName::TextEmbedding3Large => "text-embedding-3-large",
+            ModelName::TextEmbeddingAda002 => "text-embedding-ada-002",
+            ModelName::GptImage1 => "gpt-image-1",
+            ModelName::DallE3 => "dall-e-3",
+            ModelName::DallE2 => "dall-e-2",
+            ModelName::Whisper1 => "whisper-1",
+            ModelName::Gpt4oTranscribe => "gpt-4o-transcribe",
+            ModelName::Gpt4oMiniTranscribe => "gpt-4o-mini-transcribe",
+            ModelName::Tts1 => "tts-1",
+            ModelName::Tts1Hd => "tts-1-hd",
+            ModelName::Gpt4oMiniTts => "gpt-4o-mini-tts",
+            ModelName::OmniModerationLatest => "omni-moderation-latest",
+            ModelName::TextModerationLatest => "text-moderation-latest",
+            ModelName::Other(name) => name,
+        }
+    }
+}
+
+impl Display for ModelName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for ModelName {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(s.into())
+    }
+}
+
+impl From<&str> for ModelName {
+    fn from(value: &str) -> Self {
+        Self::KNOWN
+            .into_iter()
+            .find(|model| model.as_str() == value)
+            .unwrap_or_else(|| ModelName::Other(value.to_string()))
+    }
+}
+
+impl From<String> for ModelName {
+    fn from(value: String) -> Self {
+        value.as_str().into()
+    }
+}
+
+impl From<ModelName> for String {
+    fn from(value: ModelName) -> Self {
+        match value {
+            ModelName::Other(name) => name,
+            model => model.as_str().to_string(),
+        }
+    }
+}
+
+impl Serialize for ModelName {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for ModelName {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        Ok(String::deserialize(deserializer)?.into())
+    }
+}
+
+/// Types of permissions that can be applied to a model.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Permissions {
+    pub id: String,
+    pub object: String,
+    pub created: u64,
+    pub allow_create_engine: bool,
+    pub allow_sampling: bool,
+    pub allow_logprobs: bool,
+    pub allow_search_indices: bool,
+    pub allow_view: bool,
+    pub allow_fine_tuning: bool,
+    pub organization: String,
+    pub is_blocking: bool,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group: Option<String>,
+}
+
+/// Parameters for [`Create Moderation`](create) request.
+#[skip_serializing_none]
+#[derive(Builder, Debug, Default, Deserialize, Serialize)]
+#[builder(default, setter(into, strip_option))]
+pub struct ModerationParam {
+    /// The content moderations model to use for the request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    model: Option<String>,
+
+    /// The input text to classify, or multiple texts classified at once.
+    input: ModerationInput,
+}
+
+impl ModerationParamBuilder {
+    pub fn new(input: impl Into<ModerationInput>) -> Self {
+        Self {
+            input: Some(input.into()),
+            ..Self::default()
+        }
+    }
+}
+
+/// The input of a [`Create Moderation`](create) request, a single text, a batch of them,
+/// or the text and image parts of a single multimodal input, only supported by the omni models.
+///
+/// The [`results`](Moderation::results) are in the order of the texts, one per text, or a single one for the parts.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum ModerationInput {
+    Text(String),
+    Texts(Vec<String>),
+    Parts(Vec<ContentPart>),
+}
+
+impl Default for ModerationInput {
+    fn default() -> Self {
+        Self::Text(String::new())
+    }
+}
+
+impl From<&str> for ModerationInput {
+    fn from(value: &str) -> Self {
+        Self::Text(value.to_string())
+    }
+}
+
+impl From<String> for ModerationInput {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+
+impl From<Vec<&str>> for ModerationInput {
+    fn from(value: Vec<&str>) -> Self {
+        Self::Texts(value.into_iter().map(String::from).collect())
+    }
+}
+
+impl From<Vec<String>> for ModerationInput {
+    fn from(value: Vec<String>) -> Self {
+        Self::Texts(value)
+    }
+}
+
+impl From<Vec<ContentPart>> for ModerationInput {
+    fn from(value: Vec<ContentPart>) -> Self {
+        Self::Parts(value)
+    }
+}
+
+/// Response from [`Create Moderation`](create) request.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct Moderation {
+    pub id: String,
+    pub model: String,
+
+    /// The results of the classification, one per input text, in the order of the texts.
+    pub results: Vec<ModerationResult>,
+
+    pub token_usage: Option<TokenUsage>,
+}
+
+impl Moderation {
+    /// Evaluates the results against the policy, returning a verdict per input text, in the order of the texts.
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use fieri::{Client, moderation::{create, ModerationParamBuilder, ModerationPolicy}};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::new();
+    ///
+    ///     let policy = ModerationPolicy::new().threshold("violence", 0.7).allow("harassment");
+    ///
+    ///     let param = ModerationParamBuilder::new("I want to kill them.").build()?;
+    ///     let resp = create(&client, &param).await?;
+    ///
+    ///     for violation in &resp.evaluate(&policy)[0].violations {
+    ///         println!("{}: {}", violation.category, violation.score);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn evaluate(&self, policy: &ModerationPolicy) -> Vec<ModerationVerdict> {
+        self.results
+            .iter()
+            .map(|result| policy.evaluate(result))
+            .collect()
+    }
+
+    /// Whether any of the input texts is flagged.
+    pub fn any_flagged(&self) -> bool {
+        self.results.iter().any(|result| result.flagged)
+    }
+
+    /// The flagged results, along with the index of their input text.
+    pub fn flagged_results(&self) -> impl Iterator<Item = (usize, &ModerationResult)> {
+        self.results
+            .iter()
+            .enumerate()
+            .filter(|(_, result)| result.flagged)
+    }
+
+    /// The flagged categories of all the results, along with the index of their input text.
+    pub fn flagged_categories(&self) -> impl Iterator<Item = (usize, &str)> {
+        self.results.iter().enumerate().flat_map(|(index, result)| {
+            result
+                .flagged_categories()
+                .map(move |category| (index, category))
+        })
+    }
+}
+
+/// The result of the content moderation of a single input text.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct ModerationResult {
+    /// Whether the text violates any of the categories.
+    pub flagged: bool,
+    pub categories: Categories,
+    pub category_scores: CategoryScores,
+
+    /// The types of input each category was applied to, by the name of the category like `violence/graphic`,
+    /// only returned by the omni models.
+    pub category_applied_input_types: Option<HashMap<String, Vec<ModerationInputType>>>,
+}
+
+impl ModerationResult {
+    /// The names of the flagged categories, like `violence/graphic`.
+    pub fn flagged_categories(&self) -> impl Iterator<Item = &str> {
+        self.categories
+            .iter()
+            .filter_map(|(category, flagged)| flagged.then_some(category))
+    }
+
+    /// The types of input the category was applied to, telling whether a flag came from the text or the image of the input.
+    ///
+    /// Empty for an unknown category, or a model not returning them.
+    pub fn applied_input_types(&self, category: &str) -> &[ModerationInputType] {
+        self.category_applied_input_types
+            .as_ref()
+            .and_then(|types| types.get(category))
+            .map_or(&[], Vec::as_slice)
+    }
+}
+
+/// A type of input a moderation category is applied to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ModerationInputType {
+    Text,
+    Image,
+    #[serde(other)]
+    Other,
+}
+
+/// Contains a per-category binary content policy violation flags.
+///
+/// For each category, the value is `true` if the model flags the corresponding category as violated, `false` otherwise.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct Categories {
+    pub harassment: bool,
+    #[serde(rename = "harassment/threatening")]
+    pub harassment_threatening: bool,
+    pub hate: bool,
+    #[serde(rename = "hate/threatening")]
+    pub hate_threatening: bool,
+
+    // Returned as null by the models predating the omni ones.
+    #[serde(deserialize_with = "null_as_default")]
+    pub illicit: bool,
+    #[serde(rename = "illicit/violent", deserialize_with = "null_as_default")]
+    pub illicit_violent: bool,
+
+    #[serde(rename = "self-harm")]
+    pub self_harm: bool,
+    #[serde(rename = "self-harm/intent")]
+    pub self_harm_intent: bool,
+    #[serde(rename = "self-harm/instructions")]
+    pub self_harm_instructions: bool,
+    pub sexual: bool,
+    #[serde(rename = "sexual/minors")]
+    pub sexual_minors: bool,
+    pub violence: bool,
+    #[serde(rename = "violence/graphic")]
+    pub violence_graphic: bool,
+
+    /// The categories unknown to this version of the crate, by their name like `violence/graphic`.
+    #[serde(flatten)]
+    pub extra: HashMap<String, bool>,
+}
+
+/// Contains a per-category raw scores output by the model, denoting the model's confidence that the input violates the OpenAI's policy for the category.
+///
+/// The value is between 0 and 1, where higher values denote higher confidence.
+///
+/// The scores should not be interpreted as probabilities.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct CategoryScores {
+    pub harassment: f64,
+    #[serde(rename = "harassment/threatening")]
+    pub harassment_threatening: f64,
+    pub hate: f64,
+    #[serde(rename = "hate/threatening")]
+    pub hate_threatening: f64,
+
+    // Returned as null by the models predating the omni ones.
+    #[serde(deserialize_with = "null_as_default")]
+    pub illicit: f64,
+    #[serde(rename = "illicit/violent", deserialize_with = "null_as_default")]
+    pub illicit_violent: f64,
+
+    #[serde(rename = "self-harm")]
+    pub self_harm: f64,
+    #[serde(rename = "self-harm/intent")]
+    pub self_harm_intent: f64,
+    #[serde(rename = "self-harm/instructions")]
+    pub self_harm_instructions: f64,
+    pub sexual: f64,
+    #[serde(rename = "sexual/minors")]
+    pub sexual_minors: f64,
+    pub violence: f64,
+    #[serde(rename = "violence/graphic")]
+    pub violence_graphic: f64,
+
+    /// The scores of the categories unknown to this version of the crate, by their name like `violence/graphic`.
+    #[serde(flatten)]
+    pub extra: HashMap<String, f64>,
+}
+
+impl Categories {
+    /// The flags of all the categories, known and [`extra`](Categories::extra), by their name like `violence/graphic`.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, bool)> + '_ {
+        [
+            ("harassment", self.harassment),
+            ("harassment/threatening", self.harassment_threatening),
+            ("hate", self.hate),
+            ("hate/threatening", self.hate_threatening),
+            ("illicit", self.illicit),
+            ("illicit/violent", self.illicit_violent),
+            ("self-harm", self.self_harm),
+            ("self-harm/intent", self.self_harm_intent),
+            ("self-harm/instructions", self.self_harm_instructions),
+            ("sexual", self.sexual),
+            ("sexual/minors", self.sexual_minors),
+            ("violence", self.violence),
+            ("violence/graphic", self.violence_graphic),
+        ]
+        .into_iter()
+        .chain(self.extra.iter().map(|(name, flag)| (name.as_str(), *flag)))
+    }
+}
+
+impl CategoryScores {
+    /// The scores of all the categories, known and [`extra`](CategoryScores::extra), by their name like `violence/graphic`.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, f64)> + '_ {
+        [
+            ("harassment", self.harassment),
+            ("harassment/threatening", self.harassment_threatening),
+            ("hate", self.hate),
+            ("hate/threatening", self.hate_threatening),
+            ("illicit", self.illicit),
+            ("illicit/violent", self.illicit_violent),
+            ("self-harm", self.self_harm),
+            ("self-harm/intent", self.self_harm_intent),
+            ("self-harm/instructions", self.self_harm_instructions),
+            ("sexual", self.sexual),
+            ("sexual/minors", self.sexual_minors),
+            ("violence", self.violence),
+            ("violence/graphic", self.violence_graphic),
+        ]
+        .into_iter()
+        .chain(
+            self.extra
+                .iter()
+                .map(|(name, score)| (name.as_str(), *score)),
+        )
+    }
+}
+
+/// How a [`ModerationPolicy`] decides on a category.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CategoryRule {
+    /// The category is never a violation.
+    Allow,
+
+    /// The category is a violation whenever the model flags it.
+    Deny,
+
+    /// The category is a violation once its score reaches the threshold, between 0 and 1, whatever the model flags.
+    Threshold(f64),
+}
+
+/// Decides whether moderated content is allowed, out of rules per category, see [`Moderation::evaluate`].
+///
+/// The categories without a rule follow the [`default`](ModerationPolicy::default_rule) one, denying what the model flags unless set.
+///
+/// ## Example
+/// ```
+/// use fieri::moderation::{CategoryRule, ModerationPolicy};
+///
+/// let policy = ModerationPolicy::new()
+///     .threshold("violence", 0.7)
+///     .threshold("sexual/minors", 0.01)
+///     .allow("harassment")
+///     .default_rule(CategoryRule::Threshold(0.5));
+/// ```
+#[derive(Clone, Debug)]
+pub struct ModerationPolicy {
+    rules: HashMap<String, CategoryRule>,
+    default: CategoryRule,
+}
+
+impl Default for ModerationPolicy {
+    fn default() -> Self {
+        Self {
+            rules: HashMap::new(),
+            default: CategoryRule::Deny,
+        }
+    }
+}
+
+impl ModerationPolicy {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the rule of the category, by its name like `violence/graphic`.
+    pub fn rule(mut self, category: impl Into<String>, rule: CategoryRule) -> Self {
+        self.rules.insert(category.into(), rule);
+        self
+    }
+
+    /// Denies the category once its score reaches the threshold.
+    pub fn threshold(self, category: impl Into<String>, threshold: f64) -> Self {
+        self.rule(category, CategoryRule::Threshold(threshold))
+    }
+
+    /// Allows the category, whatever its score.
+    pub fn allow(self, category: impl Into<String>) -> Self {
+        self.rule(category, CategoryRule::Allow)
+    }
+
+    /// Denies the category whenever the model flags it.
+    pub fn deny(self, category: impl Into<String>) -> Self {
+        self.rule(category, CategoryRule::Deny)
+    }
+
+    /// Sets the rule of the categories without one.
+    pub fn default_rule(mut self, rule: CategoryRule) -> Self {
+        self.default = rule;
+        self
+    }
+
+    /// Evaluates the result of a single input against the policy.
+    pub fn evaluate(&self, result: &ModerationResult) -> ModerationVerdict {
+        let violations = result
+            .category_scores
+            .iter()
+            .filter_map(|(category, score)| {
+                let flagged = result
+                    .categories
+                    .iter()
+                    .any(|(name, flag)| name == category && flag);
+                let violated = match self.rules.get(category).unwrap_or(&self.default) {
+                    CategoryRule::Allow => false,
+                    CategoryRule::Deny => flagged,
+                    CategoryRule::Threshold(threshold) => score >= *threshold,
+                };
+
+                violated.then(|| PolicyViolation {
+                    category: category.to_string(),
+                    score,
+                    flagged,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        ModerationVerdict {
+            allowed: violations.is_empty(),
+            violations,
+        }
+    }
+}
+
+/// The decision of a [`ModerationPolicy`] on a single input.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ModerationVerdict {
+    /// Whether the input violates none of the categories.
+    pub allowed: bool,
+
+    /// The categories violated by the input.
+    pub violations: Vec<PolicyViolation>,
+}
+
+/// A category violated by an input, per a [`ModerationPolicy`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PolicyViolation {
+    /// The name of the category, like `violence/graphic`.
+    pub category: String,
+    pub score: f64,
+
+    /// Whether the model flagged the category too.
+    pub flagged: bool,
+}
+
+/// Parameters for [`Transcribe`](crate::audio::transcribe) request.
+#[skip_serializing_none]
+#[derive(Builder, Clone, Debug, Default, Deserialize, Serialize)]
+#[builder(default, setter(into, strip_option))]
+pub struct TranscriptionParam {
+    /// The model to use, like `whisper-1` or `gpt-4o-transcribe`.
+    pub model: String,
+
+    /// The language of the audio, in [ISO-639-1](https://en.wikipedia.org/wiki/List_of_ISO_639-1_codes) format like `en`.
+    ///
+    /// Supplying it improves the accuracy and latency.
+    pub language: Option<String>,
+
+    /// A text to guide the style of the transcription or continue a previous audio segment, in the same language as the audio.
+    pub prompt: Option<String>,
+
+    /// The format of the transcription, JSON by default.
+    pub response_format: Option<TranscriptionFormat>,
+
+    /// The sampling temperature, between 0 and 1.
+    ///
+    /// Higher values like 0.8 will make the output more random, while lower values like 0.2 will make it more focused and deterministic.
+    pub temperature: Option<f32>,
+
+    /// The timestamps to include in the transcription, requires the `verbose_json` [`format`](TranscriptionFormat::VerboseJson).
+    ///
+    /// Segment timestamps come at no additional latency, while word timestamps do.
+    pub timestamp_granularities: Option<Vec<TimestampGranularity>>,
+
+    // Whether to stream back the transcription as it's generated.
+    #[serde(default, skip_serializing_if = "is_false")]
+    #[builder(setter(skip))]
+    pub(crate) stream: bool,
+}
+
+impl TranscriptionParamBuilder {
+    pub fn new(model: impl Into<String>) -> Self {
+        Self {
+            model: Some(model.into()),
+            ..Self::default()
+        }
+    }
+}
+
+impl TranscriptionParam {
+    /// The multipart form of the request, along with the uploaded audio file.
+    pub(crate) fn to_form(&self, file: FileUpload) -> Result<reqwest::multipart::Form> {
+        let mut form = reqwest::multipart::Form::new()
+            .part("file", file.into_part()?)
+            .text("model", self.model.clone());
+
+        if let Some(language) = &self.language {
+            form = form.text("language", language.clone());
+        }
+        if let Some(prompt) = &self.prompt {
+            form = form.text("prompt", prompt.clone());
+        }
+        if let Some(format) = self.response_format {
+            form = form.text("response_format", format.to_string());
+        }
+        if let Some(temperature) = self.temperature {
+            form = form.text("temperature", temperature.to_string());
+        }
+        for granularity in self.timestamp_granularities.iter().flatten() {
+            form = form.text("timestamp_granularities[]", granularity.to_string());
+        }
+        if self.stream {
+            form = form.text("stream", "true");
+        }
+
+        Ok(form)
+    }
+}
+
+/// A single event from [`Transcribe Stream`](crate::audio::transcribe_stream).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(tag = "type")]
+pub enum TranscriptionEvent {
+    /// The next piece of the transcribed text.
+    #[serde(rename = "transcript.text.delta")]
+    Delta { delta: String },
+
+    /// The whole transcribed text, once the audio is fully processed.
+    #[serde(rename = "transcript.text.done")]
+    Done {
+        text: String,
+
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        usage: Option<TranscriptionUsage>,
+    },
+
+    /// Any other event, unknown to this version of the crate, skipped by the stream.
+    #[serde(other)]
+    Other,
+}
+
+/// Tokens used to transcribe audio with the `gpt-4o` transcription models.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct TranscriptionUsage {
+    pub input_tokens: u32,
+    pub output_tokens: u32,
+    pub total_tokens: u32,
+}
+
+/// The granularity of the timestamps of a transcription.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TimestampGranularity {
+    Word,
+    Segment,
+}
+
+impl Display for TimestampGranularity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TimestampGranularity::Word => write!(f, "word"),
+            TimestampGranularity::Segment => write!(f, "segment"),
+        }
+    }
+}
+
+/// Parameters for [`Translate`](crate::audio::translate) request.
+#[skip_serializing_none]
+#[derive(Builder, Clone, Debug, Default, Deserialize, Serialize)]
+#[builder(default, setter(into, strip_option))]
+pub struct TranslationParam {
+    /// The model to use, only `whisper-1` is currently available.
+    pub model: String,
+
+    /// A text to guide the style of the translation or continue a previous audio segment, in English.
+    pub prompt: Option<String>,
+
+    /// The format of the translation, JSON by default.
+    pub response_format: Option<TranscriptionFormat>,
+
+    /// The sampling temperature, between 0 and 1.
+    pub temperature: Option<f32>,
+}
+
+impl TranslationParamBuilder {
+    pub fn new(model: impl Into<String>) -> Self {
+        Self {
+            model: Some(model.into()),
+            ..Self::default()
+        }
+    }
+}
+
+impl TranslationParam {
+    /// The multipart form of the request, along with the uploaded audio file.
+    pub(crate) fn to_form(&self, file: FileUpload) -> Result<reqwest::multipart::Form> {
+        let mut form = reqwest::multipart::Form::new()
+            .part("file", file.into_part()?)
+            .text("model", self.model.clone());
+
+        if let Some(prompt) = &self.prompt {
+            form = form.text("prompt", prompt.clone());
+        }
+        if let Some(format) = self.response_format {
+            form = form.text("response_format", format.to_string());
+        }
+        if let Some(temperature) = self.temperature {
+            form = form.text("temperature", temperature.to_string());
+        }
+
+        Ok(form)
+    }
+}
+
+/// Parameters for [`Speech`](crate::audio::speech) request.
+///
+/// The input length and the speed are validated when building, instead of failing with a request error.
+#[skip_serializing_none]
+#[derive(Builder, Clone, Debug, Default, Deserialize, Serialize)]
+#[builder(
+    default,
+    setter(into, strip_option),
+    build_fn(validate = "Self::validate")
+)]
+pub struct SpeechParam {
+    /// The model to use, like `tts-1`, `tts-1-hd` or `gpt-4o-mini-tts`.
+    pub model: String,
+
+    /// The text to generate audio for, up to 4096 characters.
+    pub input: String,
+
+    /// The voice to use.
+    pub voice: Voice,
+
+    /// The format of the audio, MP3 by default.
+    pub response_format: Option<SpeechFormat>,
+
+    /// The speed of the audio, from 0.25 to 4.0, 1.0 by default.
+    pub speed: Option<f32>,
+}
+
+impl SpeechParamBuilder {
+    pub fn new(model: impl Into<String>, input: impl Into<String>, voice: Voice) -> Self {
+        Self {
+            model: Some(model.into()),
+            input: Some(input.into()),
+            voice: Some(voice),
+            ..Self::default()
+        }
+    }
+
+    fn validate(&self) -> std::result::Result<(), String> {
+        let input = self.input.as_deref().unwrap_or_default();
+        if input.chars().count() > 4096 {
+            return Err("The input must be at most 4096 characters long.".into());
+        }
+
+        if let Some(speed) = self.speed.flatten() {
+            if !(0.25..=4.0).contains(&speed) {
+                return Err(format!(
+                    "The speed must be between 0.25 and 4.0, got {speed}."
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The voices of the text to speech models.
+///
+/// Previews of the voices are available in the [Text to speech guide](https://platform.openai.com/docs/guides/text-to-speech#voice-options).
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Voice {
+    #[default]
+    Alloy,
+    Ash,
+    Ballad,
+    Coral,
+    Echo,
+    Fable,
+    Onyx,
+    Nova,
+    Sage,
+    Shimmer,
+    Verse,
+
+    /// A voice unknown to the crate, like a newly released one.
+    Other(String),
+}
+
+impl Voice {
+    /// The voices known to the crate.
+    pub const KNOWN: [Voice; 11] = [
+        Voice::Alloy,
+        Voice::Ash,
+        Voice::Ballad,
+        Voice::Coral,
+        Voice::Echo,
+        Voice::Fable,
+        Voice::Onyx,
+        Voice::Nova,
+        Voice::Sage,
+        Voice::Shimmer,
+        Voice::Verse,
+    ];
+
+    /// The name of the voice, as sent to the API.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Voice::Alloy => "alloy",
+            Voice::Ash => "ash",
+            Voice::Ballad => "ballad",
+            Voice::Coral => "coral",
+            Voice::Echo => "echo",
+            Voice::Fable => "fable",
+            Voice::Onyx => "onyx",
+            Voice::Nova => "nova",
+            Voice::Sage => "sage",
+            Voice::Shimmer => "shimmer",
+            Voice::Verse => "verse",
+            Voice::Other(name) => name,
+        }
+    }
+}
+
+impl Display for Voice {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl From<&str> for Voice {
+    fn from(value: &str) -> Self {
+        Self::KNOWN
+            .into_iter()
+            .find(|voice| voice.as_str() == value)
+            .unwrap_or_else(|| Voice::Other(value.to_string()))
+    }
+}
+
+impl Serialize for Voice {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for Voice {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        Ok(String::deserialize(deserializer)?.as_str().into())
+    }
+}
+
+/// The format of generated speech.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SpeechFormat {
+    #[default]
+    Mp3,
+    Opus,
+    Aac,
+    Flac,
+    Wav,
+
+    /// Raw samples, 24kHz 16-bit signed little-endian, without a header.
+    Pcm,
+}
+
+/// The format of a transcription or translation.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TranscriptionFormat {
+    #[default]
+    Json,
+
+    /// Plain text, without any JSON around it.
+    Text,
+
+    /// SubRip subtitles.
+    Srt,
+
+    /// WebVTT subtitles.
+    Vtt,
+
+    /// JSON along with the language, the duration and the timestamped segments of the audio.
+    VerboseJson,
+}
+
+impl TranscriptionFormat {
+    /// Whether the response is subtitles, which are parsed into [`Caption`]s.
+    pub(crate) fn is_subtitles(&self) -> bool {
+        matches!(self, TranscriptionFormat::Srt | TranscriptionFormat::Vtt)
+    }
+
+    /// Whether the response is a JSON object, instead of plain text.
+    pub(crate) fn is_json(&self) -> bool {
+        matches!(
+            self,
+            TranscriptionFormat::Json | TranscriptionFormat::VerboseJson
+        )
+    }
+}
+
+impl Display for TranscriptionFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TranscriptionFormat::Json => write!(f, "json"),
+            TranscriptionFormat::Text => write!(f, "text"),
+            TranscriptionFormat::Srt => write!(f, "srt"),
+            TranscriptionFormat::Vtt => write!(f, "vtt"),
+            TranscriptionFormat::VerboseJson => write!(f, "verbose_json"),
+        }
+    }
+}
+
+/// Response from [`Transcribe`](crate::audio::transcribe) request.
+///
+/// With the `text`, `srt` or `vtt` [`format`](TranscriptionFormat), the text is the raw response.
+/// The other fields are only set with the `verbose_json` format.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct Transcription {
+    pub text: String,
+
+    /// The detected language of the audio.
+    pub language: Option<String>,
+
+    /// The duration of the audio, in seconds.
+    pub duration: Option<f64>,
+
+    /// The segments of the transcription, with the `segment` [`granularity`](TimestampGranularity).
+    pub segments: Option<Vec<TranscriptionSegment>>,
+
+    /// The words of the transcription, with the `word` [`granularity`](TimestampGranularity).
+    pub words: Option<Vec<TranscriptionWord>>,
+
+    /// The cues parsed out of the text, with the `srt` or `vtt` [`format`](TranscriptionFormat).
+    #[serde(skip)]
+    pub captions: Option<Vec<Caption>>,
+}
+
+/// A segment of a transcribed or translated audio, with its timestamps in seconds.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct TranscriptionSegment {
+    pub id: u32,
+
+    /// The seek offset of the segment.
+    pub seek: u32,
+
+    pub start: f64,
+    pub end: f64,
+    pub text: String,
+    pub tokens: Vec<u32>,
+    pub temperature: f32,
+
+    /// The average log probability of the segment, below -1 the segment is likely to be wrongly transcribed.
+    pub avg_logprob: f64,
+
+    /// Above 2.4, the segment is likely to be repeated text or gibberish.
+    pub compression_ratio: f64,
+
+    /// The probability of there being no speech in the segment.
+    pub no_speech_prob: f64,
+}
+
+/// A word of a transcribed audio, with its timestamps in seconds.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct TranscriptionWord {
+    pub word: String,
+    pub start: f64,
+    pub end: f64,
+}
+
+/// Response from [`Translate`](crate::audio::translate) request, the English text of the audio.
+///
+/// With the `text`, `srt` or `vtt` [`format`](TranscriptionFormat), the text is the raw response.
+/// The other fields are only set with the `verbose_json` format.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct Translation {
+    pub text: String,
+
+    /// The language of the output, always `english`.
+    pub language: Option<String>,
+
+    /// The duration of the audio, in seconds.
+    pub duration: Option<f64>,
+
+    pub segments: Option<Vec<TranscriptionSegment>>,
+
+    /// The cues parsed out of the text, with the `srt` or `vtt` [`format`](TranscriptionFormat).
+    #[serde(skip)]
+    pub captions: Option<Vec<Caption>>,
+}
+
+/// A cue of SRT or WebVTT subtitles.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Caption {
+    /// The number of the cue, as given in SRT subtitles, or its position starting at 1 otherwise.
+    pub index: u32,
+
+    pub start: Duration,
+    pub end: Duration,
+
+    /// The text of the cue, with its lines separated by newlines.
+    pub text: String,
+}
+
+impl Caption {
+    /// Parses SRT or WebVTT subtitles into their cues, skipping the blocks that aren't cues, like the WebVTT header and notes.
+    ///
+    /// ## Example
+    /// ```
+    /// use std::time::Duration;
+    /// use fieri::audio::Caption;
+    ///
+    /// let captions = Caption::parse("1\n00:00:00,000 --> 00:00:01,500\nHello there.\n");
+    /// assert_eq!(captions[0].end, Duration::from_millis(1500));
+    /// assert_eq!(captions[0].text, "Hello there.");
+    /// ```
+    pub fn parse(subtitles: &str) -> Vec<Caption> {
+        let subtitles = subtitles.replace("\r\n", "\n");
+
+        subtitles
+            .split("\n\n")
+            .filter_map(|block| {
+                let lines = block.trim_matches('\n').lines().collect::<Vec<_>>();
+                let timing = lines.iter().position(|line| line.contains("-->"))?;
+
+                let (start, end) = lines[timing].split_once("-->")?;
+                // WebVTT cue settings may follow the end time.
+                let end = end.split_whitespace().next()?;
+
+                Some(Caption {
+                    index: timing
+                        .checked_sub(1)
+                        .and_then(|i| lines[i].trim().parse().ok())
+                        .unwrap_or_default(),
+                    start: parse_timestamp(start)?,
+                    end: parse_timestamp(end)?,
+                    text: lines[timing + 1..].join("\n"),
+                })
+            })
+            .enumerate()
+            .map(|(i, caption)| Caption {
+                index: if caption.index == 0 {
+                    i as u32 + 1
+                } else {
+                    caption.index
+                },
+                ..caption
+            })
+            .collect()
+    }
+}
+
+/// Parses a `HH:MM:SS,mmm` SRT or `[HH:]MM:SS.mmm` WebVTT timestamp.
+fn parse_timestamp(timestamp: &str) -> Option<Duration> {
+    let (time, millis) = timestamp.trim().split_once([',', '.'])?;
+
+    let mut seconds = 0;
+    for part in time.split(':') {
+        seconds = seconds * 60 + part.parse::<u64>().ok()?;
+    }
+
+    Some(Duration::from_secs(seconds) + Duration::from_millis(millis.parse().ok()?))
+}
+
+/// The configuration of a [`Realtime`](crate::realtime) session, set with a [`session.update`](ClientEvent::SessionUpdate) event.
+///
+/// Fields left unset keep their current value.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct RealtimeSessionConfig {
+    /// The realtime model, like `gpt-4o-realtime-preview`.
+    pub model: Option<String>,
+
+    /// The modalities the model responds with.
+    pub modalities: Option<Vec<RealtimeModality>>,
+
+    /// The system instructions prepended to the conversation.
+    pub instructions: Option<String>,
+
+    /// The voice the model responds with, which can't be changed once the model responded with audio.
+    pub voice: Option<Voice>,
+
+    pub input_audio_format: Option<RealtimeAudioFormat>,
+    pub output_audio_format: Option<RealtimeAudioFormat>,
+
+    /// Transcription of the input audio, which is off by default.
+    pub input_audio_transcription: Option<InputAudioTranscription>,
+
+    /// The detection of the end of the user's turn, to respond automatically.
+    pub turn_detection: Option<TurnDetection>,
+
+    /// The functions the model can call, like `{"type": "function", "name": "...", "parameters": {...}}`.
+    pub tools: Option<Vec<serde_json::Value>>,
+
+    /// How the model chooses tools, `auto`, `none`, `required` or a function.
+    pub tool_choice: Option<serde_json::Value>,
+
+    /// The sampling temperature, between 0.6 and 1.2.
+    pub temperature: Option<f32>,
+
+    /// The maximum number of output tokens of a single response, either a number or `inf`.
+    pub max_response_output_tokens: Option<serde_json::Value>,
+}
+
+/// A modality of the realtime models.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RealtimeModality {
+    Text,
+    Audio,
+}
+
+/// The format of the audio sent to or received from the realtime models.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RealtimeAudioFormat {
+    /// 16-bit PCM at 24kHz, mono, little-endian.
+    #[default]
+    Pcm16,
+
+    G711Ulaw,
+    G711Alaw,
+}
+
+/// The transcription of the audio input of a realtime session.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct InputAudioTranscription {
+    /// The model to use, like `whisper-1` or `gpt-4o-transcribe`.
+    pub model: String,
+
+    pub language: Option<String>,
+    pub prompt: Option<String>,
+}
+
+impl InputAudioTranscription {
+    pub fn new(model: impl Into<String>) -> Self {
+        Self {
+            model: model.into(),
+            ..Self::default()
+        }
+    }
+}
+
+/// The detection of the end of the user's turn in a realtime session.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum TurnDetection {
+    /// Detects the end of the turn from periods of silence.
+    ServerVad {
+        /// The activation threshold, between 0 and 1, higher requiring louder audio.
+        threshold: Option<f32>,
+        prefix_padding_ms: Option<u32>,
+        silence_duration_ms: Option<u32>,
+
+        /// Whether to respond automatically at the end of the turn.
+        create_response: Option<bool>,
+    },
+
+    /// Detects the end of the turn from what the user said.
+    SemanticVad {
+        /// How eager the model is to respond, `low`, `medium`, `high` or `auto`.
+        eagerness: Option<String>,
+        create_response: Option<bool>,
+    },
+
+    /// A detection unknown to this version of the crate, kept as is.
+    #[serde(untagged)]
+    Other(serde_json::Value),
+}
+
+/// The configuration of a single response, overriding the one of the session.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct RealtimeResponseConfig {
+    pub modalities: Option<Vec<RealtimeModality>>,
+    pub instructions: Option<String>,
+    pub voice: Option<Voice>,
+    pub output_audio_format: Option<RealtimeAudioFormat>,
+    pub temperature: Option<f32>,
+
+    /// The maximum number of output tokens, either a number or `inf`.
+    pub max_output_tokens: Option<serde_json::Value>,
+}
+
+/// An event sent to a [`Realtime`](crate::realtime) session.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(tag = "type")]
+pub enum ClientEvent {
+    /// Updates the configuration of the session.
+    #[serde(rename = "session.update")]
+    SessionUpdate { session: RealtimeSessionConfig },
+
+    /// Appends base64 encoded audio to the input buffer, see [`ClientEvent::append_audio`].
+    #[serde(rename = "input_audio_buffer.append")]
+    InputAudioBufferAppend { audio: String },
+
+    /// Commits the input buffer as a user message, when the turn detection is off.
+    #[serde(rename = "input_audio_buffer.commit")]
+    InputAudioBufferCommit,
+
+    #[serde(rename = "input_audio_buffer.clear")]
+    InputAudioBufferClear,
+
+    /// Adds an item to the conversation, like `{"type": "message", "role": "user", "content": [...]}`.
+    #[serde(rename = "conversation.item.create")]
+    ConversationItemCreate { item: serde_json::Value },
+
+    /// Asks the model to respond, when the turn detection is off or for text conversations.
+    #[serde(rename = "response.create")]
+    ResponseCreate {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        response: Option<RealtimeResponseConfig>,
+    },
+
+    /// Cancels the response in progress.
+    #[serde(rename = "response.cancel")]
+    ResponseCancel,
+}
+
+impl ClientEvent {
+    /// Appends raw audio, in the input audio format of the session, to the input buffer.
+    pub fn append_audio(audio: &[u8]) -> Self {
+        use base64::Engine;
+
+        Self::InputAudioBufferAppend {
+            audio: base64::engine::general_purpose::STANDARD.encode(audio),
+        }
+    }
+}
+
+/// An event received from a [`Realtime`](crate::realtime) session.
+///
+/// Events which aren't modeled yet are received as [`ServerEvent::Other`].
+#[derive(Clone, Debug, Deserialize)]
+#[serde(tag = "type")]
+pub enum ServerEvent {
+    /// An error, which doesn't end the session.
+    #[serde(rename = "error")]
+    Error { error: crate::error::ErrorMessage },
+
+    #[serde(rename = "session.created")]
+    SessionCreated { session: serde_json::Value },
+
+    #[serde(rename = "session.updated")]
+    SessionUpdated { session: serde_json::Value },
+
+    /// The turn detection detected speech in the input buffer.
+    #[serde(rename = "input_audio_buffer.speech_started")]
+    SpeechStarted {
+        audio_start_ms: u64,
+        item_id: String,
+    },
+
+    #[serde(rename = "input_audio_buffer.speech_stopped")]
+    SpeechStopped { audio_end_ms: u64, item_id: String },
+
+    #[serde(rename = "input_audio_buffer.committed")]
+    InputAudioBufferCommitted { item_id: String },
+
+    #[serde(rename = "conversation.item.created")]
+    ConversationItemCreated { item: serde_json::Value },
+
+    /// The transcription of the user's audio, with the input audio transcription on.
+    #[serde(rename = "conversation.item.input_audio_transcription.completed")]
+    InputAudioTranscriptionCompleted { item_id: String, transcript: String },
+
+    #[serde(rename = "response.created")]
+    ResponseCreated { response: serde_json::Value },
+
+    /// The response is done, along with its output items and token usage.
+    #[serde(rename = "response.done")]
+    ResponseDone { response: serde_json::Value },
+
+    #[serde(rename = "response.text.delta")]
+    TextDelta {
+        response_id: String,
+        item_id: String,
+        delta: String,
+    },
+
+    #[serde(rename = "response.text.done")]
+    TextDone {
+        response_id: String,
+        item_id: String,
+        text: String,
+    },
+
+    /// The next piece of the audio response, base64 encoded, see [`ServerEvent::audio`].
+    #[serde(rename = "response.audio.delta")]
+    AudioDelta {
+        response_id: String,
+        item_id: String,
+        delta: String,
+    },
+
+    #[serde(rename = "response.audio.done")]
+    AudioDone {
+        response_id: String,
+        item_id: String,
+    },
+
+    #[serde(rename = "response.audio_transcript.delta")]
+    AudioTranscriptDelta {
+        response_id: String,
+        item_id: String,
+        delta: String,
+    },
+
+    #[serde(rename = "response.audio_transcript.done")]
+    AudioTranscriptDone {
+        response_id: String,
+        item_id: String,
+        transcript: String,
+    },
+
+    /// The model called a function, whose output is sent back as a `function_call_output` item.
+    #[serde(rename = "response.function_call_arguments.done")]
+    FunctionCallArgumentsDone {
+        response_id: String,
+        item_id: String,
+        call_id: String,
+        name: String,
+        arguments: String,
+    },
+
+    #[serde(other)]
+    Other,
+}
+
+impl ServerEvent {
+    /// Decodes the audio of an [`AudioDelta`](ServerEvent::AudioDelta) event, in the output audio format of the session.
+    pub fn audio(&self) -> Option<Result<Vec<u8>>> {
+        use base64::Engine;
+
+        match self {
+            ServerEvent::AudioDelta { delta, .. } => Some(
+                base64::engine::general_purpose::STANDARD
+                    .decode(delta)
+                    .map_err(Error::from),
+            ),
+            _ => None,
+        }
+    }
+}
+
+/// The configuration of a realtime session dedicated to transcribing the audio input, without responses from the model.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct RealtimeTranscriptionSessionConfig {
+    pub input_audio_format: Option<RealtimeAudioFormat>,
+    pub input_audio_transcription: Option<InputAudioTranscription>,
+    pub turn_detection: Option<TurnDetection>,
+    pub input_audio_noise_reduction: Option<NoiseReduction>,
+
+    /// Extra fields to include in the transcription events, like `item.input_audio_transcription.logprobs`.
+    pub include: Option<Vec<String>>,
+}
+
+/// The noise reduction applied to the audio input, according to the kind of microphone.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum NoiseReduction {
+    /// Close-talking microphones, like headphones.
+    NearField,
+
+    /// Far-field microphones, like laptop or conference room microphones.
+    FarField,
+
+    /// A noise reduction unknown to this version of the crate, kept as is.
+    #[serde(untagged)]
+    Other(serde_json::Value),
+}
+
+/// A realtime session created through the API, along with the ephemeral token to connect to it.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct RealtimeSession {
+    pub id: Option<String>,
+    pub object: Option<String>,
+
+    #[serde(flatten)]
+    pub config: RealtimeSessionConfig,
+
+    pub client_secret: ClientSecret,
+}
+
+/// A realtime transcription session created through the API, along with the ephemeral token to connect to it.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct RealtimeTranscriptionSession {
+    pub id: Option<String>,
+    pub object: Option<String>,
+
+    #[serde(flatten)]
+    pub config: RealtimeTranscriptionSessionConfig,
+
+    pub client_secret: ClientSecret,
+}
+
+/// A short-lived token, used by browsers and mobile clients in place of the API key to connect to a realtime session.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ClientSecret {
+    pub value: String,
+
+    /// The expiration of the token, as a unix timestamp in seconds, usually a minute after its creation.
+    pub expires_at: u64,
+}
+
+impl ClientSecret {
+    /// Whether the token is expired, according to the system clock.
+    pub fn is_expired(&self) -> bool {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(true, |now| now.as_secs() >= self.expires_at)
+    }
+}
+
+/// A tool of an [`Assistant`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AssistantTool {
+    /// Lets the assistant write and run Python code in a sandbox, like to process files.
+    CodeInterpreter,
+
+    /// Lets the assistant search the files of its vector stores.
+    FileSearch {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        file_search: Option<FileSearchOptions>,
+    },
+
+    /// Lets the assistant call one of your functions.
+    Function { function: FunctionSpec },
+}
+
+impl AssistantTool {
+    pub fn file_search() -> Self {
+        Self::FileSearch { file_search: None }
+    }
+
+    /// The file search tool with the given options, like its maximum number of results.
+    pub fn file_search_with(options: FileSearchOptions) -> Self {
+        Self::FileSearch {
+            file_search: Some(options),
+        }
+    }
+
+    pub fn function(function: FunctionSpec) -> Self {
+        Self::Function { function }
+    }
+}
+
+/// The options of the [`file search`](AssistantTool::FileSearch) tool.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct FileSearchOptions {
+    /// The maximum number of results, between 1 and 50.
+    pub max_num_results: Option<u32>,
+
+    pub ranking_options: Option<RankingOptions>,
+}
+
+/// How the results of a file search are ranked.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct RankingOptions {
+    /// The ranker, `auto` or a dated one like `default_2024_08_21`.
+    pub ranker: Option<String>,
+
+    /// The minimum score of the results, between 0 and 1. Required by the API when ranking options are set.
+    pub score_threshold: Option<f32>,
+}
+
+/// How the files attached to a vector store are split into chunks.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ChunkingStrategy {
+    /// Chunks of 800 tokens, overlapping by 400 tokens.
+    #[default]
+    Auto,
+
+    /// Chunks of a fixed size, see [`ChunkingStrategy::fixed`].
+    Static {
+        #[serde(rename = "static")]
+        chunks: StaticChunking,
+    },
+
+    /// The strategy of the files attached before chunking strategies existed.
+    #[serde(other)]
+    Other,
+}
+
+impl ChunkingStrategy {
+    /// Chunks of `max_chunk_size_tokens`, between 100 and 4096, overlapping by `chunk_overlap_tokens`, up to half of the size.
+    pub fn fixed(max_chunk_size_tokens: u32, chunk_overlap_tokens: u32) -> Self {
+        Self::Static {
+            chunks: StaticChunking {
+                max_chunk_size_tokens,
+                chunk_overlap_tokens,
+            },
+        }
+    }
+}
+
+/// The size of the chunks of the [`static`](ChunkingStrategy::Static) chunking strategy.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct StaticChunking {
+    pub max_chunk_size_tokens: u32,
+    pub chunk_overlap_tokens: u32,
+}
+
+/// The resources made available to the tools of an assistant or a thread.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct ToolResources {
+    pub code_interpreter: Option<CodeInterpreterResources>,
+    pub file_search: Option<FileSearchResources>,
+}
+
+/// The files available to the [`code interpreter`](AssistantTool::CodeInterpreter) tool.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct CodeInterpreterResources {
+    /// Up to 20 file IDs.
+    pub file_ids: Vec<String>,
+}
+
+impl CodeInterpreterResources {
+    pub fn new(file_ids: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self {
+            file_ids: file_ids.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// The vector stores searched by the [`file search`](AssistantTool::FileSearch) tool.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct FileSearchResources {
+    /// A single vector store ID.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub vector_store_ids: Vec<String>,
+
+    /// A single vector store created from files along with the assistant or the thread,
+    /// instead of an existing one from [`vector_store_ids`](FileSearchResources::vector_store_ids).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vector_stores: Option<Vec<NewVectorStore>>,
+}
+
+/// A vector store created from files, see [`FileSearchResources::vector_stores`].
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct NewVectorStore {
+    /// Up to 10000 file IDs.
+    pub file_ids: Vec<String>,
+    pub chunking_strategy: Option<ChunkingStrategy>,
+    pub metadata: Option<HashMap<String, String>>,
+}
+
+/// Parameters for [`Create Assistant`](crate::assistant::create) & [`Modify Assistant`](crate::assistant::modify) requests.
+///
+/// The model is required to create an assistant, while only the fields set are changed when modifying one.
+#[skip_serializing_none]
+#[derive(Builder, Clone, Debug, Default, Deserialize, Serialize)]
+#[builder(default, setter(into, strip_option))]
+pub struct AssistantParam {
+    /// ID of the model to use.
+    pub model: Option<String>,
+
+    /// The name of the assistant, up to 256 characters.
+    pub name: Option<String>,
+
+    /// The description of the assistant, up to 512 characters.
+    pub description: Option<String>,
+
+    /// The system instructions of the assistant, up to 256,000 characters.
+    pub instructions: Option<String>,
+
+    /// Up to 128 tools enabled on the assistant.
+    pub tools: Option<Vec<AssistantTool>>,
+
+    pub tool_resources: Option<ToolResources>,
+
+    /// Up to 16 key-value pairs attached to the assistant.
+    pub metadata: Option<HashMap<String, String>>,
+
+    /// What sampling temperature to use, between 0 and 2.
+    pub temperature: Option<f32>,
+
+    /// An alternative to sampling with temperature, called nucleus sampling.
+    pub top_p: Option<f32>,
+
+    /// The format that the model must output.
+    pub response_format: Option<ResponseFormat>,
+
+    /// The reasoning effort of reasoning models.
+    pub reasoning_effort: Option<ReasoningEffort>,
+}
+
+impl AssistantParamBuilder {
+    pub fn new(model: impl Into<String>) -> Self {
+        Self {
+            model: Some(Some(model.into())),
+            ..Self::default()
+        }
+    }
+}
+
+/// Response from [`Create Assistant`](crate::assistant::create), [`Retrieve Assistant`](crate::assistant::retrieve) & [`Modify Assistant`](crate::assistant::modify) requests.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct Assistant {
+    pub id: String,
+    pub object: String,
+    pub created_at: u64,
+    pub model: String,
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub instructions: Option<String>,
+    pub tools: Vec<AssistantTool>,
+    pub tool_resources: Option<ToolResources>,
+    pub metadata: HashMap<String, String>,
+    pub temperature: Option<f32>,
+    pub top_p: Option<f32>,
+
+    /// The format that the model must output, `auto` or a [`ResponseFormat`].
+    pub response_format: Option<serde_json::Value>,
+}
+
+/// Parameters for paginated listings, like [`List Assistants`](crate::assistant::list).
+#[skip_serializing_none]
+#[derive(Builder, Clone, Debug, Default, Deserialize, Serialize)]
+#[builder(default, setter(into, strip_option))]
+pub struct ListParam {
+    /// Number of objects to retrieve, between 1 and 100, defaults to 20.
+    pub limit: Option<u32>,
+
+    /// Sort order by the `created_at` timestamp, defaults to `desc`.
+    pub order: Option<Order>,
+
+    /// Identifier of the last object of the previous page, to retrieve the next one.
+    pub after: Option<String>,
+
+    /// Identifier of the first object of the previous page, to retrieve the previous one.
+    pub before: Option<String>,
+}
+
+/// Response from [`List Assistants`](crate::assistant::list) request.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct ListAssistants {
+    pub object: String,
+    pub data: Vec<Assistant>,
+    pub first_id: Option<String>,
+    pub last_id: Option<String>,
+    pub has_more: bool,
+}
+
+/// Parameters for [`Create Thread`](crate::thread::create) & [`Modify Thread`](crate::thread::modify) requests.
+///
+/// The initial messages are only accepted when creating a thread.
+#[skip_serializing_none]
+#[derive(Builder, Clone, Debug, Default, Deserialize, Serialize)]
+#[builder(default, setter(into, strip_option))]
+pub struct ThreadParam {
+    /// The messages to start the thread with.
+    pub messages: Option<Vec<CreateMessageParam>>,
+
+    pub tool_resources: Option<ToolResources>,
+
+    /// Up to 16 key-value pairs attached to the thread.
+    pub metadata: Option<HashMap<String, String>>,
+}
+
+/// Response from [`Create Thread`](crate::thread::create), [`Retrieve Thread`](crate::thread::retrieve) & [`Modify Thread`](crate::thread::modify) requests.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct Thread {
+    pub id: String,
+    pub object: String,
+    pub created_at: u64,
+    pub tool_resources: Option<ToolResources>,
+    pub metadata: HashMap<String, String>,
+}
+
+/// The role of the author of a message in a thread.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MessageRole {
+    #[default]
+    User,
+    Assistant,
+}
+
+/// A message added to a thread.
+#[skip_serializing_none]
+#[derive(Builder, Clone, Debug, Default, Deserialize, Serialize)]
+#[builder(default, setter(into, strip_option))]
+pub struct CreateMessageParam {
+    pub role: MessageRole,
+    pub content: MessageInput,
+
+    /// Files attached to the message, along with the tools they're added to.
+    pub attachments: Option<Vec<Attachment>>,
+
+    /// Up to 16 key-value pairs attached to the message.
+    pub metadata: Option<HashMap<String, String>>,
+}
+
+impl CreateMessageParamBuilder {
+    pub fn new(role: MessageRole, content: impl Into<MessageInput>) -> Self {
+        Self {
+            role: Some(role),
+            content: Some(content.into()),
+            ..Self::default()
+        }
+    }
+}
+
+impl CreateMessageParam {
+    /// A message from the user.
+    pub fn user(content: impl Into<MessageInput>) -> Self {
+        Self {
+            role: MessageRole::User,
+            content: content.into(),
+            ..Self::default()
+        }
+    }
+}
+
+/// A file attached to a message, added to the given tools.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct Attachment {
+    pub file_id: String,
+
+    /// The tools to add the file to, the [`code interpreter`](AssistantTool::CodeInterpreter) or [`file search`](AssistantTool::FileSearch).
+    pub tools: Vec<AssistantTool>,
+}
+
+impl Attachment {
+    pub fn new(file_id: impl Into<String>, tools: Vec<AssistantTool>) -> Self {
+        Self {
+            file_id: file_id.into(),
+            tools,
+        }
+    }
+}
+
+/// The content of a message added to a thread, either text or a list of [`content parts`](MessageContentPart).
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum MessageInput {
+    Text(String),
+    Parts(Vec<MessageContentPart>),
+}
+
+impl Default for MessageInput {
+    fn default() -> Self {
+        Self::Text(String::new())
+    }
+}
+
+impl From<&str> for MessageInput {
+    fn from(text: &str) -> Self {
+        Self::Text(text.into())
+    }
+}
+
+impl From<String> for MessageInput {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
+}
+
+impl From<Vec<MessageContentPart>> for MessageInput {
+    fn from(parts: Vec<MessageContentPart>) -> Self {
+        Self::Parts(parts)
+    }
+}
+
+/// A part of the content of a message added to a thread.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum MessageContentPart {
+    Text {
+        text: String,
+    },
+
+    /// An image uploaded with the [`Vision`](Purpose::Vision) purpose.
+    ImageFile {
+        image_file: ImageFile,
+    },
+
+    ImageUrl {
+        image_url: ImageUrl,
+    },
+}
+
+impl MessageContentPart {
+    pub fn text(text: impl Into<String>) -> Self {
+        Self::Text { text: text.into() }
+    }
+
+    pub fn image_file(file_id: impl Into<String>) -> Self {
+        Self::ImageFile {
+            image_file: ImageFile {
+                file_id: file_id.into(),
+                detail: None,
+            },
+        }
+    }
+
+    pub fn image_url(url: impl Into<String>) -> Self {
+        Self::ImageUrl {
+            image_url: ImageUrl {
+                url: url.into(),
+                detail: None,
+            },
+        }
+    }
+}
+
+/// An image of a message, referred to by its file ID.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+pub struct ImageFile {
+    pub file_id: String,
+    pub detail: Option<ImageDetail>,
+}
+
+/// A message of a thread, from the user or the assistant.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct Message {
+    pub id: String,
+    pub object: String,
+    pub created_at: u64,
+    pub thread_id: String,
+    pub role: MessageRole,
+    pub content: Vec<MessageContent>,
+
+    /// The status of the message, only set on the messages of the assistant.
+    pub status: Option<MessageStatus>,
+
+    /// The assistant and the run which created the message, if it comes from the assistant.
+    pub assistant_id: Option<String>,
+    pub run_id: Option<String>,
+
+    pub attachments: Option<Vec<Attachment>>,
+    pub metadata: HashMap<String, String>,
+
+    /// The Unix timestamps (in seconds) at which the message was completed, or marked as incomplete.
+    pub completed_at: Option<u64>,
+    pub incomplete_at: Option<u64>,
+
+    /// Why the message is incomplete, like `{"reason": "max_tokens"}`.
+    pub incomplete_details: Option<serde_json::Value>,
+}
+
+impl Message {
+    /// The text of the message, joining all its text blocks.
+    pub fn text(&self) -> String {
+        self.content
+            .iter()
+            .filter_map(|content| match content {
+                MessageContent::Text { text } => Some(text.value.as_str()),
+                _ => None,
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// The IDs of the files generated for the message by the [`code interpreter`](AssistantTool::CodeInterpreter) tool,
+    /// either images of its content or files referred to by the annotations of its text, to fetch with [`file::content`](crate::file::content).
+    pub fn file_ids(&self) -> Vec<&str> {
+        self.content
+            .iter()
+            .flat_map(|content| match content {
+                MessageContent::ImageFile { image_file } => vec![image_file.file_id.as_str()],
+                MessageContent::Text { text } => text
+                    .annotations
+                    .iter()
+                    .filter_map(|annotation| match annotation {
+                        Annotation::FilePath { file_path, .. } => Some(file_path.file_id.as_str()),
+                        _ => None,
+                    })
+                    .collect(),
+                _ => vec![],
+            })
+            .collect()
+    }
+}
+
+/// The status of a message of the assistant.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MessageStatus {
+    InProgress,
+    Incomplete,
+    Completed,
+
+    /// Any other status returned by the API, unknown to this version of the crate.
+    #[serde(other)]
+    Other,
+}
+
+/// A block of the content of a [`Message`].
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum MessageContent {
+    Text {
+        text: MessageText,
+    },
+    ImageFile {
+        image_file: ImageFile,
+    },
+    ImageUrl {
+        image_url: ImageUrl,
+    },
+
+    /// The refusal of the assistant to respond.
+    Refusal {
+        refusal: String,
+    },
+
+    /// Any other content returned by the API, unknown to this version of the crate.
+    #[serde(other)]
+    Other,
+}
+
+/// The text of a [`Message`], along with its annotations.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct MessageText {
+    pub value: String,
+    pub annotations: Vec<Annotation>,
+}
+
+/// An annotation of a part of the text of a message, which the text refers to with a placeholder like `【13†source】`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Annotation {
+    /// A citation of a file searched by the [`file search`](AssistantTool::FileSearch) tool.
+    FileCitation {
+        /// The placeholder in the text of the message.
+        text: String,
+        file_citation: FileReference,
+        start_index: u32,
+        end_index: u32,
+    },
+
+    /// A file generated by the [`code interpreter`](AssistantTool::CodeInterpreter) tool.
+    FilePath {
+        text: String,
+        file_path: FileReference,
+        start_index: u32,
+        end_index: u32,
+    },
+
+    /// Any other annotation returned by the API, unknown to this version of the crate.
+    #[serde(other)]
+    Other,
+}
+
+/// A file referred to by an [`Annotation`].
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct FileReference {
+    pub file_id: String,
+}
+
+/// Parameters for [`List Messages`](crate::thread::list_messages) request.
+#[skip_serializing_none]
+#[derive(Builder, Clone, Debug, Default, Deserialize, Serialize)]
+#[builder(default, setter(into, strip_option))]
+pub struct ListMessagesParam {
+    /// Number of messages to retrieve, between 1 and 100, defaults to 20.
+    pub limit: Option<u32>,
+
+    /// Sort order by the `created_at` timestamp, defaults to `desc`.
+    pub order: Option<Order>,
+
+    /// Identifier of the last message of the previous page, to retrieve the next one.
+    pub after: Option<String>,
+
+    /// Identifier of the first message of the previous page, to retrieve the previous one.
+    pub before: Option<String>,
+
+    /// Only return the messages created by the given run.
+    pub run_id: Option<String>,
+}
+
+/// Response from [`List Messages`](crate::thread::list_messages) request.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct ListMessages {
+    pub object: String,
+    pub data: Vec<Message>,
+    pub first_id: Option<String>,
+    pub last_id: Option<String>,
+    pub has_more: bool,
+}
+
+/// Parameters for [`Create Run`](crate::run::create) request.
+///
+/// The fields set override the ones of the assistant, for this run only.
+#[skip_serializing_none]
+#[derive(Builder, Clone, Debug, Default, Deserialize, Serialize)]
+#[builder(default, setter(into, strip_option))]
+pub struct RunParam {
+    /// The ID of the assistant running the thread.
+    pub assistant_id: String,
+
+    pub model: Option<String>,
+
+    /// Replaces the instructions of the assistant.
+    pub instructions: Option<String>,
+
+    /// Appended to the instructions of the assistant.
+    pub additional_instructions: Option<String>,
+
+    /// Messages added to the thread before the run starts.
+    pub additional_messages: Option<Vec<CreateMessageParam>>,
+
+    pub tools: Option<Vec<AssistantTool>>,
+    pub metadata: Option<HashMap<String, String>>,
+    pub temperature: Option<f32>,
+    pub top_p: Option<f32>,
+
+    /// The maximum number of prompt tokens used over the whole run, after which it's [`incomplete`](RunStatus::Incomplete).
+    pub max_prompt_tokens: Option<u32>,
+
+    /// The maximum number of completion tokens used over the whole run, after which it's [`incomplete`](RunStatus::Incomplete).
+    pub max_completion_tokens: Option<u32>,
+
+    /// How the thread is truncated to fit the context, like `{"type": "last_messages", "last_messages": 10}`.
+    pub truncation_strategy: Option<serde_json::Value>,
+
+    /// Which tool is called, `none`, `auto`, `required`, or a tool like `{"type": "file_search"}`.
+    pub tool_choice: Option<serde_json::Value>,
+
+    pub parallel_tool_calls: Option<bool>,
+    pub response_format: Option<ResponseFormat>,
+    pub reasoning_effort: Option<ReasoningEffort>,
+
+    // Set by the streaming variants of the runs endpoints.
+    #[serde(default, skip_serializing_if = "is_false")]
+    #[builder(setter(skip))]
+    pub(crate) stream: bool,
+}
+
+impl RunParamBuilder {
+    pub fn new(assistant_id: impl Into<String>) -> Self {
+        Self {
+            assistant_id: Some(assistant_id.into()),
+            ..Self::default()
+        }
+    }
+}
+
+/// A run of an assistant on a thread, see [`Runs`](crate::run).
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct Run {
+    pub id: String,
+    pub object: String,
+    pub created_at: u64,
+    pub thread_id: String,
+    pub assistant_id: String,
+    pub status: RunStatus,
+
+    /// The action required to continue the run, once it [`requires action`](RunStatus::RequiresAction).
+    pub required_action: Option<RequiredAction>,
+
+    /// The reason of the failure, once the run [`failed`](RunStatus::Failed).
+    pub last_error: Option<RunError>,
+
+    /// The Unix timestamps (in seconds) of the changes of status of the run.
+    pub expires_at: Option<u64>,
+    pub started_at: Option<u64>,
+    pub cancelled_at: Option<u64>,
+    pub failed_at: Option<u64>,
+    pub completed_at: Option<u64>,
+
+    /// Why the run is incomplete, like `{"reason": "max_prompt_tokens"}`.
+    pub incomplete_details: Option<serde_json::Value>,
+
+    pub model: String,
+    pub instructions: String,
+    pub tools: Vec<AssistantTool>,
+    pub metadata: HashMap<String, String>,
+
+    /// The tokens used by the run, once it's terminal.
+    pub usage: Option<TokenUsage>,
+
+    pub temperature: Option<f32>,
+    pub top_p: Option<f32>,
+    pub max_prompt_tokens: Option<u32>,
+    pub max_completion_tokens: Option<u32>,
+    pub truncation_strategy: Option<serde_json::Value>,
+    pub tool_choice: Option<serde_json::Value>,
+    pub parallel_tool_calls: Option<bool>,
+    pub response_format: Option<serde_json::Value>,
+}
+
+/// The status of a [`Run`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RunStatus {
+    #[default]
+    Queued,
+    InProgress,
+
+    /// The run waits for the outputs of its tool calls, see [`Run::required_action`].
+    RequiresAction,
+    Cancelling,
+    Cancelled,
+    Failed,
+    Completed,
+    Incomplete,
+    Expired,
+
+    /// Any other status returned by the API, unknown to this version of the crate.
+    #[serde(other)]
+    Other,
+}
+
+impl RunStatus {
+    /// Whether the run is over, and won't change anymore.
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            RunStatus::Cancelled
+                | RunStatus::Failed
+                | RunStatus::Completed
+                | RunStatus::Incomplete
+                | RunStatus::Expired
+        )
+    }
+
+    /// Whether the run is still being processed, without any action required: queued, in progress, cancelling
+    /// or in an unknown status.
+    pub fn is_pending(&self) -> bool {
+        matches!(
+            self,
+            RunStatus::Queued | RunStatus::InProgress | RunStatus::Cancelling | RunStatus::Other
+        )
+    }
+}
+
+impl Run {
+    /// Polls the run until it's no longer [`pending`](RunStatus::is_pending), returning its latest state.
+    ///
+    /// The run is either terminal or [`requires action`](RunStatus::RequiresAction) once returned.
+    /// The delay between polls grows from [`interval`](PollOptions::interval) up to [`max_interval`](PollOptions::max_interval).
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use fieri::{Client, run::{retrieve, PollOptions}};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::new();
+    ///
+    ///     let run = retrieve(&client, "thread_abc123", "run_abc123").await?;
+    ///     let run = run.wait(&client, PollOptions::default()).await?;
+    ///     println!("{:?}", run.status);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn wait(&self, client: &crate::Client, opts: PollOptions) -> crate::Result<Run> {
+        self.wait_with(client, opts, |_| {}).await
+    }
+
+    /// Polls the run like [`wait`](Run::wait), calling `on_status` with the run each time its status changes.
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use fieri::{Client, run::{retrieve, PollOptions}};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::new();
+    ///
+    ///     let run = retrieve(&client, "thread_abc123", "run_abc123").await?;
+    ///     let run = run
+    ///         .wait_with(&client, PollOptions::default(), |run| println!("{:?}", run.status))
+    ///         .await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn wait_with(
+        &self,
+        client: &crate::Client,
+        opts: PollOptions,
+        mut on_status: impl FnMut(&Run),
+    ) -> crate::Result<Run> {
+        let mut status = self.status;
+        let mut polls = 0;
+
+        if !status.is_pending() {
+            return Ok(self.clone());
+        }
+
+        loop {
+            polls += 1;
+            crate::utils::sleep(opts.delay(polls)).await;
+
+            let run = crate::run::retrieve(client, &self.thread_id, &self.id).await?;
+            if run.status != status {
+                status = run.status;
+                on_status(&run);
+            }
+
+            if !run.status.is_pending() {
+                return Ok(run);
+            }
+        }
+    }
+}
+
+/// Options for polling until something is done, like [`Run::wait`], [`VectorStoreFileBatch::wait`] & [`Batch::wait`].
+#[derive(Builder, Clone, Debug)]
+#[builder(default, setter(into))]
+pub struct PollOptions {
+    /// The delay before the first poll. Defaults to 500ms.
+    pub interval: Duration,
+
+    /// The maximum delay between two polls. Defaults to 5s.
+    pub max_interval: Duration,
+
+    /// The factor by which the delay grows after each poll. Defaults to 1.5.
+    pub multiplier: f32,
+
+    /// The fraction, between 0 and 1, by which each delay is randomly shortened or lengthened,
+    /// so that concurrent waits don't poll in lockstep. Defaults to 0.1.
+    pub jitter: f32,
+}
+
+impl Default for PollOptions {
+    fn default() -> Self {
+        Self {
+            interval: Duration::from_millis(500),
+            max_interval: Duration::from_secs(5),
+            multiplier: 1.5,
+            jitter: 0.1,
+        }
+    }
+}
+
+impl PollOptions {
+    // The delay to sleep for before the given poll, starting at 1, growing and spread like the backoff of retries.
+    fn delay(&self, poll: u32) -> Duration {
+        crate::config::RetryPolicy {
+            initial_backoff: self.interval,
+            max_backoff: self.max_interval,
+            multiplier: self.multiplier,
+            jitter: self.jitter,
+            ..crate::config::RetryPolicy::none()
+        }
+        .backoff(poll)
+    }
+}
+
+/// An action required to continue a [`Run`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RequiredAction {
+    /// The outputs of the tool calls are to be [`submitted`](crate::run::submit_tool_outputs).
+    SubmitToolOutputs {
+        submit_tool_outputs: RequiredToolCalls,
+    },
+
+    /// Any other action returned by the API, unknown to this version of the crate.
+    #[serde(other)]
+    Other,
+}
+
+impl RequiredAction {
+    /// The tool calls waiting for their outputs.
+    pub fn tool_calls(&self) -> &[ToolCall] {
+        match self {
+            RequiredAction::SubmitToolOutputs {
+                submit_tool_outputs,
+            } => &submit_tool_outputs.tool_calls,
+            RequiredAction::Other => &[],
+        }
+    }
+}
+
+/// The tool calls waiting for their outputs.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct RequiredToolCalls {
+    pub tool_calls: Vec<ToolCall>,
+}
+
+/// The reason why a [`Run`] failed.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct RunError {
+    /// One of `server_error`, `rate_limit_exceeded` or `invalid_prompt`.
+    pub code: String,
+    pub message: String,
+}
+
+/// The output of a tool call, submitted to continue a [`Run`].
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ToolOutput {
+    pub tool_call_id: String,
+    pub output: String,
+}
+
+impl ToolOutput {
+    pub fn new(tool_call_id: impl Into<String>, output: impl Into<String>) -> Self {
+        Self {
+            tool_call_id: tool_call_id.into(),
+            output: output.into(),
+        }
+    }
+}
+
+/// Parameters for [`Submit Tool Outputs`](crate::run::submit_tool_outputs) request.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct SubmitToolOutputsParam {
+    pub tool_outputs: Vec<ToolOutput>,
+
+    // Set by the streaming variants of the runs endpoints.
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub(crate) stream: bool,
+}
+
+/// Response from [`List Runs`](crate::run::list) request.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct ListRuns {
+    pub object: String,
+    pub data: Vec<Run>,
+    pub first_id: Option<String>,
+    pub last_id: Option<String>,
+    pub has_more: bool,
+}
+
+/// A step of a [`Run`], either the creation of a message or calls to tools.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct RunStep {
+    pub id: String,
+    pub object: String,
+    pub created_at: u64,
+    pub assistant_id: String,
+    pub thread_id: String,
+    pub run_id: String,
+    pub status: RunStepStatus,
+
+    /// The details of the step, either the creation of a message or calls to tools.
+    pub step_details: Option<StepDetails>,
+
+    /// The reason of the failure, once the step [`failed`](RunStepStatus::Failed).
+    pub last_error: Option<RunError>,
+
+    /// The Unix timestamps (in seconds) of the changes of status of the step.
+    pub expired_at: Option<u64>,
+    pub cancelled_at: Option<u64>,
+    pub failed_at: Option<u64>,
+    pub completed_at: Option<u64>,
+
+    pub metadata: HashMap<String, String>,
+
+    /// The tokens used by the step, once it's terminal.
+    pub usage: Option<TokenUsage>,
+}
+
+impl RunStep {
+    /// The calls to the [`code interpreter`](AssistantTool::CodeInterpreter) tool made by the step, along with their outputs.
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use fieri::{Client, file, run::{list_steps, ListParamBuilder}};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::new();
+    ///
+    ///     let param = ListParamBuilder::default().build()?;
+    ///     let steps = list_steps(&client, "thread_abc123", "run_abc123", &param).await?;
+    ///
+    ///     for step in &steps.data {
+    ///         for call in step.code_interpreter_calls() {
+    ///             for file_id in call.outputs.iter().filter_map(|output| output.image_file_id()) {
+    ///                 let image = file::content(&client, file_id).await?;
+    ///                 std::fs::write(format!("{file_id}.png"), image)?;
+    ///             }
+    ///         }
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn code_interpreter_calls(&self) -> Vec<&CodeInterpreterCall> {
+        match &self.step_details {
+            Some(StepDetails::ToolCalls { tool_calls }) => tool_calls
+                .iter()
+                .filter_map(|call| match call {
+                    StepToolCall::CodeInterpreter {
+                        code_interpreter, ..
+                    } => Some(code_interpreter),
+                    _ => None,
+                })
+                .collect(),
+            _ => vec![],
+        }
+    }
+}
+
+/// The status of a [`RunStep`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RunStepStatus {
+    #[default]
+    InProgress,
+    Cancelled,
+    Failed,
+    Completed,
+    Expired,
+
+    /// Any other status returned by the API, unknown to this version of the crate.
+    #[serde(other)]
+    Other,
+}
+
+/// The changes of a [`RunStep`] while it's streamed.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct RunStepDelta {
+    pub id: String,
+    pub object: String,
+    pub delta: RunStepDeltaBody,
+}
+
+/// The changed fields of a [`RunStepDelta`].
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct RunStepDeltaBody {
+    /// The changes of the details of the step, like the arguments of tool calls as they are generated.
+    pub step_details: Option<StepDetailsDelta>,
+}
+
+/// The changes of a [`Message`] while it's streamed.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct MessageDelta {
+    pub id: String,
+    pub object: String,
+    pub delta: MessageDeltaBody,
+}
+
+impl MessageDelta {
+    /// The text added to the message, joining the text of all the content deltas.
+    pub fn text(&self) -> String {
+        self.delta
+            .content
+            .iter()
+            .filter_map(|content| match content {
+                MessageContentDelta::Text {
+                    text: Some(text), ..
+                } => text.value.as_deref(),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+/// The changed fields of a [`MessageDelta`].
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct MessageDeltaBody {
+    pub role: Option<MessageRole>,
+    pub content: Vec<MessageContentDelta>,
+}
+
+/// The change of a block of [`MessageContent`], at the given `index` of the content of the message.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum MessageContentDelta {
+    Text {
+        index: u32,
+        text: Option<MessageTextDelta>,
+    },
+    ImageFile {
+        index: u32,
+        image_file: Option<ImageFile>,
+    },
+    ImageUrl {
+        index: u32,
+        image_url: Option<ImageUrl>,
+    },
+    Refusal {
+        index: u32,
+        refusal: Option<String>,
+    },
+
+    /// Any other delta returned by the API, unknown to this version of the crate.
+    #[serde(other)]
+    Other,
+}
+
+/// The change of a [`MessageText`].
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct MessageTextDelta {
+    /// The text added to the block.
+    pub value: Option<String>,
+
+    /// The annotations added to the block, partially streamed along with their `index`.
+    pub annotations: Option<Vec<serde_json::Value>>,
+}
+
+/// An event streamed back by the streaming variants of the runs endpoints, like [`Create And Stream`](crate::run::create_and_stream).
+///
+/// The events follow the lifecycle of the run: the changes of status of the run, its steps and its messages,
+/// along with the deltas of the steps and messages as they are generated, up to the final [`Done`](AssistantStreamEvent::Done).
+#[derive(Clone, Debug, Deserialize)]
+#[serde(tag = "event", content = "data")]
+#[non_exhaustive]
+pub enum AssistantStreamEvent {
+    #[serde(rename = "thread.created")]
+    ThreadCreated(Thread),
+
+    #[serde(rename = "thread.run.created")]
+    RunCreated(Run),
+    #[serde(rename = "thread.run.queued")]
+    RunQueued(Run),
+    #[serde(rename = "thread.run.in_progress")]
+    RunInProgress(Run),
+    /// The outputs of the tool calls of the run are to be [`submitted`](crate::run::submit_tool_outputs_stream).
+    #[serde(rename = "thread.run.requires_action")]
+    RunRequiresAction(Run),
+    #[serde(rename = "thread.run.completed")]
+    RunCompleted(Run),
+    #[serde(rename = "thread.run.incomplete")]
+    RunIncomplete(Run),
+    #[serde(rename = "thread.run.failed")]
+    RunFailed(Run),
+    #[serde(rename = "thread.run.cancelling")]
+    RunCancelling(Run),
+    #[serde(rename = "thread.run.cancelled")]
+    RunCancelled(Run),
+    #[serde(rename = "thread.run.expired")]
+    RunExpired(Run),
+
+    #[serde(rename = "thread.run.step.created")]
+    RunStepCreated(RunStep),
+    #[serde(rename = "thread.run.step.in_progress")]
+    RunStepInProgress(RunStep),
+    #[serde(rename = "thread.run.step.delta")]
+    RunStepDelta(RunStepDelta),
+    #[serde(rename = "thread.run.step.completed")]
+    RunStepCompleted(RunStep),
+    #[serde(rename = "thread.run.step.failed")]
+    RunStepFailed(RunStep),
+    #[serde(rename = "thread.run.step.cancelled")]
+    RunStepCancelled(RunStep),
+    #[serde(rename = "thread.run.step.expired")]
+    RunStepExpired(RunStep),
+
+    #[serde(rename = "thread.message.created")]
+    MessageCreated(Message),
+    #[serde(rename = "thread.message.in_progress")]
+    MessageInProgress(Message),
+    #[serde(rename = "thread.message.delta")]
+    MessageDelta(MessageDelta),
+    #[serde(rename = "thread.message.completed")]
+    MessageCompleted(Message),
+    #[serde(rename = "thread.message.incomplete")]
+    MessageIncomplete(Message),
+
+    /// The end of the stream.
+    #[serde(rename = "done")]
+    Done,
+
+    /// An event unknown to this version of the crate, kept as is.
+    #[serde(untagged)]
+    Unknown {
+        event: String,
+        #[serde(default)]
+        data: serde_json::Value,
+    },
+}
+
+impl AssistantStreamEvent {
+    /// The run, for the events of the changes of status of the run.
+    pub fn run(&self) -> Option<&Run> {
+        match self {
+            AssistantStreamEvent::RunCreated(run)
+            | AssistantStreamEvent::RunQueued(run)
+            | AssistantStreamEvent::RunInProgress(run)
+            | AssistantStreamEvent::RunRequiresAction(run)
+            | AssistantStreamEvent::RunCompleted(run)
+            | AssistantStreamEvent::RunIncomplete(run)
+            | AssistantStreamEvent::RunFailed(run)
+            | AssistantStreamEvent::RunCancelling(run)
+            | AssistantStreamEvent::RunCancelled(run)
+            | AssistantStreamEvent::RunExpired(run) => Some(run),
+            _ => None,
+        }
+    }
+}
+
+/// The details of a [`RunStep`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum StepDetails {
+    /// The creation of a message by the assistant.
+    MessageCreation { message_creation: MessageCreation },
+
+    /// Calls to the tools of the assistant.
+    ToolCalls { tool_calls: Vec<StepToolCall> },
+
+    /// Any other details returned by the API, unknown to this version of the crate.
+    #[serde(other)]
+    Other,
+}
+
+/// The message created by a [`RunStep`].
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct MessageCreation {
+    pub message_id: String,
+}
+
+/// A call to a tool, made by a [`RunStep`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum StepToolCall {
+    CodeInterpreter {
+        id: String,
+        code_interpreter: CodeInterpreterCall,
+    },
+    FileSearch {
+        id: String,
+        file_search: FileSearchCall,
+    },
+    Function {
+        id: String,
+        function: StepFunctionCall,
+    },
+
+    /// Any other tool call returned by the API, unknown to this version of the crate.
+    #[serde(other)]
+    Other,
+}
+
+/// The code run by the [`code interpreter`](AssistantTool::CodeInterpreter) tool, along with its outputs.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct CodeInterpreterCall {
+    pub input: String,
+    pub outputs: Vec<CodeInterpreterOutput>,
+}
+
+/// An output of the [`code interpreter`](AssistantTool::CodeInterpreter) tool.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum CodeInterpreterOutput {
+    /// The text output of the code.
+    Logs { logs: String },
+
+    /// An image generated by the code, stored as a file.
+    Image { image: FileReference },
+
+    /// Any other output returned by the API, unknown to this version of the crate.
+    #[serde(other)]
+    Other,
+}
+
+impl CodeInterpreterOutput {
+    /// The ID of the file of the image, to fetch with [`file::content`](crate::file::content).
+    pub fn image_file_id(&self) -> Option<&str> {
+        match self {
+            CodeInterpreterOutput::Image { image } => Some(&image.file_id),
+            _ => None,
+        }
+    }
+}
+
+/// A search by the [`file search`](AssistantTool::FileSearch) tool.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct FileSearchCall {
+    pub ranking_options: Option<RankingOptions>,
+
+    /// The results of the search, only returned when explicitly included.
+    pub results: Option<Vec<FileSearchResult>>,
+}
+
+/// A result of a [`FileSearchCall`].
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct FileSearchResult {
+    pub file_id: String,
+    pub file_name: String,
+    pub score: f32,
+    pub content: Option<Vec<serde_json::Value>>,
+}
+
+/// A function called by a [`RunStep`], along with its output once [`submitted`](crate::run::submit_tool_outputs).
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct StepFunctionCall {
+    pub name: String,
+    pub arguments: String,
+    pub output: Option<String>,
+}
+
+/// The change of the [`StepDetails`] of a streamed [`RunStep`].
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum StepDetailsDelta {
+    MessageCreation {
+        message_creation: Option<MessageCreation>,
+    },
+    ToolCalls {
+        tool_calls: Option<Vec<StepToolCallDelta>>,
+    },
+
+    /// Any other delta returned by the API, unknown to this version of the crate.
+    #[serde(other)]
+    Other,
+}
+
+/// The change of a [`StepToolCall`], at the given `index` of the tool calls of the step.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum StepToolCallDelta {
+    CodeInterpreter {
+        index: u32,
+        id: Option<String>,
+        code_interpreter: Option<CodeInterpreterCallDelta>,
+    },
+    FileSearch {
+        index: u32,
+        id: Option<String>,
+        file_search: Option<FileSearchCall>,
+    },
+    Function {
+        index: u32,
+        id: Option<String>,
+        function: Option<StepFunctionCallDelta>,
+    },
+
+    /// Any other delta returned by the API, unknown to this version of the crate.
+    #[serde(other)]
+    Other,
+}
+
+/// The change of a [`CodeInterpreterCall`].
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct CodeInterpreterCallDelta {
+    /// The code added to the input.
+    pub input: Option<String>,
+    pub outputs: Option<Vec<CodeInterpreterOutputDelta>>,
+}
+
+/// The change of a [`CodeInterpreterOutput`], at the given `index` of the outputs.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum CodeInterpreterOutputDelta {
+    Logs {
+        index: u32,
+        logs: Option<String>,
+    },
+    Image {
+        index: u32,
+        image: Option<FileReference>,
+    },
+
+    /// Any other delta returned by the API, unknown to this version of the crate.
+    #[serde(other)]
+    Other,
+}
+
+/// The change of a [`StepFunctionCall`].
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct StepFunctionCallDelta {
+    pub name: Option<String>,
+
+    /// The arguments added as they are generated.
+    pub arguments: Option<String>,
+    pub output: Option<String>,
+}
+
+/// Response from [`List Run Steps`](crate::run::list_steps) request.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct ListRunSteps {
+    pub object: String,
+    pub data: Vec<RunStep>,
+    pub first_id: Option<String>,
+    pub last_id: Option<String>,
+    pub has_more: bool,
+}
+
+/// Parameters for [`Create Vector Store File`](crate::vector_store::create_file) request.
+#[skip_serializing_none]
+#[derive(Builder, Clone, Debug, Default, Deserialize, Serialize)]
+#[builder(default, setter(into, strip_option))]
+pub struct CreateVectorStoreFileParam {
+    /// The ID of an uploaded [`file`](crate::file) to attach to the vector store.
+    pub file_id: String,
+
+    /// Defaults to the [`auto`](ChunkingStrategy::Auto) chunking strategy.
+    pub chunking_strategy: Option<ChunkingStrategy>,
+
+    /// Attributes of the file, strings, numbers or booleans, to filter the searches by.
+    pub attributes: Option<HashMap<String, serde_json::Value>>,
+}
+
+impl CreateVectorStoreFileParamBuilder {
+    pub fn new(file_id: impl Into<String>) -> Self {
+        Self {
+            file_id: Some(file_id.into()),
+            ..Self::default()
+        }
+    }
+}
+
+/// A file attached to a vector store, from [`Create Vector Store File`](crate::vector_store::create_file) & [`Retrieve Vector Store File`](crate::vector_store::retrieve_file) requests.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct VectorStoreFile {
+    pub id: String,
+    pub object: String,
+    pub created_at: u64,
+    pub vector_store_id: String,
+
+    /// The processing status of the file, only searchable once [`completed`](VectorStoreFileStatus::Completed).
+    pub status: VectorStoreFileStatus,
+
+    /// The size of the file in the vector store, which may differ from the size of the uploaded file.
+    pub usage_bytes: u64,
+
+    /// The reason of the failure, once the processing [`failed`](VectorStoreFileStatus::Failed).
+    pub last_error: Option<VectorStoreFileError>,
+
+    pub chunking_strategy: Option<ChunkingStrategy>,
+    pub attributes: Option<HashMap<String, serde_json::Value>>,
+}
+
+/// The processing status of a [`VectorStoreFile`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VectorStoreFileStatus {
+    #[default]
+    InProgress,
+    Completed,
+    Cancelled,
+    Failed,
+
+    /// Any other status returned by the API, unknown to this version of the crate.
+    #[serde(other)]
+    Other,
+}
+
+impl VectorStoreFileStatus {
+    /// Whether the processing is over, successfully or not, and won't change anymore.
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            VectorStoreFileStatus::Completed
+                | VectorStoreFileStatus::Cancelled
+                | VectorStoreFileStatus::Failed
+        )
+    }
+}
+
+/// The reason why the processing of a [`VectorStoreFile`] failed.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct VectorStoreFileError {
+    /// One of `server_error`, `unsupported_file` or `invalid_file`.
+    pub code: String,
+    pub message: String,
+}
+
+/// Parameters for [`List Vector Store Files`](crate::vector_store::list_files) & [`List Batch Files`](crate::vector_store::list_batch_files) requests.
+#[skip_serializing_none]
+#[derive(Builder, Clone, Debug, Default, Deserialize, Serialize)]
+#[builder(default, setter(into, strip_option))]
+pub struct ListVectorStoreFilesParam {
+    /// Number of files to retrieve, between 1 and 100, defaults to 20.
+    pub limit: Option<u32>,
+
+    /// Sort order by the `created_at` timestamp, defaults to `desc`.
+    pub order: Option<Order>,
+
+    /// Identifier of the last file of the previous page, to retrieve the next one.
+    pub after: Option<String>,
+
+    /// Identifier of the first file of the previous page, to retrieve the previous one.
+    pub before: Option<String>,
+
+    /// Only return the files with the given processing status.
+    pub filter: Option<VectorStoreFileStatus>,
+}
+
+/// Response from [`List Vector Store Files`](crate::vector_store::list_files) & [`List Batch Files`](crate::vector_store::list_batch_files) requests.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct ListVectorStoreFiles {
+    pub object: String,
+    pub data: Vec<VectorStoreFile>,
+    pub first_id: Option<String>,
+    pub last_id: Option<String>,
+    pub has_more: bool,
+}
+
+/// Parameters for [`Create File Batch`](crate::vector_store::create_file_batch) request.
+#[skip_serializing_none]
+#[derive(Builder, Clone, Debug, Default, Deserialize, Serialize)]
+#[builder(default, setter(into, strip_option))]
+pub struct CreateFileBatchParam {
+    /// The IDs of the uploaded [`files`](crate::file) to attach to the vector store, up to 500.
+    pub file_ids: Vec<String>,
+
+    /// Defaults to the [`auto`](ChunkingStrategy::Auto) chunking strategy.
+    pub chunking_strategy: Option<ChunkingStrategy>,
+
+    /// Attributes of the files, strings, numbers or booleans, to filter the searches by.
+    pub attributes: Option<HashMap<String, serde_json::Value>>,
+}
+
+impl CreateFileBatchParamBuilder {
+    pub fn new(file_ids: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self {
+            file_ids: Some(file_ids.into_iter().map(Into::into).collect()),
+            ..Self::default()
+        }
+    }
+}
+
+/// A batch of files attached to a vector store at once, see [`Create File Batch`](crate::vector_store::create_file_batch).
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct VectorStoreFileBatch {
+    pub id: String,
+    pub object: String,
+    pub created_at: u64,
+    pub vector_store_id: String,
+
+    /// The processing status of the batch, [`completed`](VectorStoreFileStatus::Completed) once all its files are processed.
+    pub status: VectorStoreFileStatus,
+    pub file_counts: FileCounts,
+}
+
+impl VectorStoreFileBatch {
+    /// Polls the batch until all its files are processed or failed, returning its latest state.
+    ///
+    /// The delay between polls grows from [`interval`](PollOptions::interval) up to [`max_interval`](PollOptions::max_interval).
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use fieri::{Client, vector_store::{create_file_batch, CreateFileBatchParamBuilder, PollOptions}};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::new();
+    ///
+    ///     let param = CreateFileBatchParamBuilder::new(["file-abc123", "file-def456"]).build()?;
+    ///
+    ///     let batch = create_file_batch(&client, "vs_abc123", &param).await?;
+    ///     let batch = batch.wait(&client, PollOptions::default()).await?;
+    ///     println!("{} files failed", batch.file_counts.failed);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn wait(
+        &self,
+        client: &crate::Client,
+        opts: PollOptions,
+    ) -> crate::Result<VectorStoreFileBatch> {
+        let mut polls = 0;
+        let mut batch = self.clone();
+
+        while !batch.status.is_terminal() {
+            polls += 1;
+            crate::utils::sleep(opts.delay(polls)).await;
+
+            batch =
+                crate::vector_store::retrieve_file_batch(client, &self.vector_store_id, &self.id)
+                    .await?;
+        }
+
+        Ok(batch)
+    }
+}
+
+/// The numbers of files of a [`VectorStoreFileBatch`], by processing status.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct FileCounts {
+    pub in_progress: u32,
+    pub completed: u32,
+    pub failed: u32,
+    pub cancelled: u32,
+    pub total: u32,
+}
+
+/// Parameters for [`Create Response`](crate::responses::create) request.
+#[skip_serializing_none]
+#[derive(Builder, Clone, Debug, Default, Deserialize, Serialize)]
+#[builder(default, setter(into, strip_option))]
+pub struct ResponseParam {
+    /// ID of the model to use.
+    pub model: String,
+
+    /// The input of the model, either a single text or a list of items like messages and outputs of function calls.
+    pub input: ResponseInput,
+
+    /// A system (or developer) message inserted into the context of the model.
+    ///
+    /// Unlike messages, the instructions of a previous response aren't carried over with `previous_response_id`.
+    pub instructions: Option<String>,
+
+    /// The ID of a previous response, to continue the conversation from it without sending its input and output again.
+    pub previous_response_id: Option<String>,
+
+    /// The tools the model may call, either functions or tools built in the API.
+    pub tools: Option<Vec<ResponseTool>>,
+
+    /// Controls which (if any) tool is called by the model.
+    pub tool_choice: Option<ResponseToolChoice>,
+
+    pub parallel_tool_calls: Option<bool>,
+
+    /// An upper bound for the number of tokens that can be generated, including visible output tokens and reasoning tokens.
+    pub max_output_tokens: Option<u32>,
+
+    /// The configuration of reasoning models, like the o-series.
+    pub reasoning: Option<ReasoningConfig>,
+
+    /// The format of the text output, like `{"format": {"type": "json_schema", "name": "...", "schema": {...}}}`.
+    pub text: Option<serde_json::Value>,
+
+    /// Additional data to include in the response, like `file_search_call.results`.
+    pub include: Option<Vec<String>>,
+
+    /// Whether to store the response, to be retrieved later or continued from with `previous_response_id`. Defaults to `true`.
+    pub store: Option<bool>,
+
+    /// Up to 16 key-value pairs, with keys up to 64 characters and values up to 512 characters.
+    pub metadata: Option<HashMap<String, String>>,
+
+    pub temperature: Option<f32>,
+    pub top_p: Option<f32>,
+
+    /// The truncation strategy, `auto` to drop items from the middle of the conversation when it exceeds the context, or `disabled`.
+    pub truncation: Option<String>,
+
+    pub service_tier: Option<ServiceTier>,
+
+    /// A unique identifier representing your end-user.
+    pub user: Option<String>,
+}
+
+impl ResponseParamBuilder {
+    pub fn new(model: impl Into<String>, input: impl Into<ResponseInput>) -> Self {
+        Self {
+            model: Some(model.into()),
+            input: Some(input.into()),
+            ..Self::default()
+        }
+    }
+}
+
+/// The input of a [`ResponseParam`], either a single text or a list of items.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum ResponseInput {
+    /// A text, equivalent to a single message of the user.
+    Text(String),
+    Items(Vec<InputItem>),
+}
+
+impl Default for ResponseInput {
+    fn default() -> Self {
+        Self::Text(String::new())
+    }
+}
+
+impl From<&str> for ResponseInput {
+    fn from(value: &str) -> Self {
+        Self::Text(value.to_string())
+    }
+}
+
+impl From<String> for ResponseInput {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+
+impl From<Vec<InputItem>> for ResponseInput {
+    fn from(value: Vec<InputItem>) -> Self {
+        Self::Items(value)
+    }
+}
+
+/// An item of the input of the model.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum InputItem {
+    /// A message, of the user or the developer, or of the assistant in previous turns.
+    Message {
+        role: InputRole,
+        content: InputContent,
+    },
+
+    /// The output of a function called by the model, see [`FunctionToolCall::call_id`].
+    FunctionCallOutput { call_id: String, output: String },
+
+    /// A reference to an item of a stored response by its ID.
+    ItemReference { id: String },
+
+    /// The answer to an [`McpApprovalRequest`], approving the call to the tool or not.
+    McpApprovalResponse {
+        approval_request_id: String,
+        approve: bool,
+        reason: Option<String>,
+    },
+
+    /// An item output by the model, sent back as is, like a function call followed by its output.
+    #[serde(untagged)]
+    Output(OutputItem),
+}
+
+impl InputItem {
+    pub fn message(role: InputRole, content: impl Into<InputContent>) -> Self {
+        Self::Message {
+            role,
+            content: content.into(),
+        }
+    }
+
+    /// Creates a message of the user.
+    pub fn user(content: impl Into<InputContent>) -> Self {
+        Self::message(InputRole::User, content)
+    }
+
+    /// Creates a message of the developer, with instructions taking precedence over the messages of the user.
+    pub fn developer(content: impl Into<InputContent>) -> Self {
+        Self::message(InputRole::Developer, content)
+    }
+
+    /// Approves, or not, the call to a tool of an MCP server requested with the given id.
+    pub fn mcp_approval_response(approval_request_id: impl Into<String>, approve: bool) -> Self {
+        Self::McpApprovalResponse {
+            approval_request_id: approval_request_id.into(),
+            approve,
+            reason: None,
+        }
+    }
+
+    /// Creates the output of the function call with the given id.
+    pub fn function_call_output(call_id: impl Into<String>, output: impl Into<String>) -> Self {
+        Self::FunctionCallOutput {
+            call_id: call_id.into(),
+            output: output.into(),
+        }
+    }
+}
+
+impl From<OutputItem> for InputItem {
+    fn from(item: OutputItem) -> Self {
+        Self::Output(item)
+    }
+}
+
+/// The role of the author of an [`input message`](InputItem::Message).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InputRole {
+    #[default]
+    User,
+    Assistant,
+    System,
+    Developer,
+}
+
+/// The content of an [`input message`](InputItem::Message), either a text or a list of parts like images and files.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum InputContent {
+    Text(String),
+    Parts(Vec<InputContentPart>),
+}
+
+impl From<&str> for InputContent {
+    fn from(value: &str) -> Self {
+        Self::Text(value.to_string())
+    }
+}
+
+impl From<String> for InputContent {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+
+impl From<Vec<InputContentPart>> for InputContent {
+    fn from(value: Vec<InputContentPart>) -> Self {
+        Self::Parts(value)
+    }
+}
+
+/// A part of the content of an [`input message`](InputItem::Message).
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum InputContentPart {
+    InputText {
+        text: String,
+    },
+
+    /// An image, either from a URL or a base64 encoded data URL, or from an uploaded file.
+    InputImage {
+        image_url: Option<String>,
+        file_id: Option<String>,
+        detail: Option<ImageDetail>,
+    },
+
+    /// A file, either uploaded or base64 encoded along with its name, like a PDF.
+    InputFile {
+        file_id: Option<String>,
+        file_data: Option<String>,
+        filename: Option<String>,
+    },
+}
+
+impl InputContentPart {
+    pub fn text(text: impl Into<String>) -> Self {
+        Self::InputText { text: text.into() }
+    }
+
+    pub fn image_url(url: impl Into<String>) -> Self {
+        Self::InputImage {
+            image_url: Some(url.into()),
+            file_id: None,
+            detail: None,
+        }
+    }
+
+    pub fn file(file_id: impl Into<String>) -> Self {
+        Self::InputFile {
+            file_id: Some(file_id.into()),
+            file_data: None,
+            filename: None,
+        }
+    }
+}
+
+/// A tool the model may call in a [`Response`].
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ResponseTool {
+    /// A function defined by you, called with the arguments generated by the model.
+    Function {
+        #[serde(flatten)]
+        function: FunctionSpec,
+
+        /// Whether to enable strict schema adherence when generating the arguments.
+        strict: Option<bool>,
+    },
+
+    /// A search in the files of the given vector stores.
+    FileSearch {
+        vector_store_ids: Vec<String>,
+        max_num_results: Option<u32>,
+        ranking_options: Option<RankingOptions>,
+
+        /// A filter on the attributes of the files, like `{"type": "eq", "key": "region", "value": "us"}`.
+        filters: Option<serde_json::Value>,
+    },
+
+    /// A search on the web, for the model to respond with up to date information along with the sources cited.
+    #[serde(alias = "web_search_preview")]
+    WebSearch {
+        /// How much context is retrieved from the web, `medium` by default.
+        search_context_size: Option<SearchContextSize>,
+
+        /// The approximate location of the user, to refine the search.
+        user_location: Option<UserLocation>,
+
+        filters: Option<WebSearchFilters>,
+    },
+
+    /// The tools of a remote MCP (Model Context Protocol) server, listed and called by the API on behalf of the model.
+    Mcp {
+        /// The label identifying the server in the tool calls.
+        server_label: String,
+        server_url: Option<String>,
+        server_description: Option<String>,
+
+        /// The headers sent to the server, like the `Authorization` one.
+        headers: Option<HashMap<String, String>>,
+
+        /// The tools of the server the model may call, all of them by default.
+        allowed_tools: Option<Vec<String>>,
+
+        /// Which tool calls require an approval, all of them by default.
+        require_approval: Option<McpApproval>,
+    },
+
+    /// A tool unknown to this version of the crate, kept as is.
+    #[serde(untagged)]
+    Other(serde_json::Value),
+}
+
+impl ResponseTool {
+    pub fn function(function: FunctionSpec) -> Self {
+        Self::Function {
+            function,
+            strict: None,
+        }
+    }
+
+    pub fn file_search(vector_store_ids: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self::FileSearch {
+            vector_store_ids: vector_store_ids.into_iter().map(Into::into).collect(),
+            max_num_results: None,
+            ranking_options: None,
+            filters: None,
+        }
+    }
+
+    /// The tools of the MCP server at the given URL, identified by `server_label`.
+    pub fn mcp(server_label: impl Into<String>, server_url: impl Into<String>) -> Self {
+        Self::Mcp {
+            server_label: server_label.into(),
+            server_url: Some(server_url.into()),
+            server_description: None,
+            headers: None,
+            allowed_tools: None,
+            require_approval: None,
+        }
+    }
+
+    pub fn web_search() -> Self {
+        Self::WebSearch {
+            search_context_size: None,
+            user_location: None,
+            filters: None,
+        }
+    }
+}
+
+/// How much context the [`web search`](ResponseTool::WebSearch) tool retrieves, trading quality for cost and latency.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchContextSize {
+    Low,
+    #[default]
+    Medium,
+    High,
+}
+
+/// Which calls to the tools of an [`MCP server`](ResponseTool::Mcp) require an approval before being made.
+///
+/// A call requiring an approval comes as an [`McpApprovalRequest`], answered with an [`approval response`](InputItem::McpApprovalResponse).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum McpApproval {
+    Always,
+    Never,
+
+    /// The calls of the tools listed in `always` require an approval, while those listed in `never` don't.
+    Tools {
+        always: Vec<String>,
+        never: Vec<String>,
+    },
+}
+
+#[derive(Default, Deserialize, Serialize)]
+#[serde(default)]
+struct McpToolNames {
+    tool_names: Vec<String>,
+}
+
+impl Serialize for McpApproval {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        match self {
+            McpApproval::Always => serializer.serialize_str("always"),
+            McpApproval::Never => serializer.serialize_str("never"),
+            McpApproval::Tools { always, never } => serde_json::json!({
+                "always": McpToolNames { tool_names: always.clone() },
+                "never": McpToolNames { tool_names: never.clone() },
+            })
+            .serialize(serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for McpApproval {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            Mode(String),
+            Tools {
+                #[serde(default)]
+                always: McpToolNames,
+                #[serde(default)]
+                never: McpToolNames,
+            },
+        }
+
+        match Repr::deserialize(deserializer)? {
+            Repr::Mode(mode) if mode == "always" => Ok(Self::Always),
+            Repr::Mode(mode) if mode == "never" => Ok(Self::Never),
+            Repr::Mode(mode) => Err(serde::de::Error::unknown_variant(
+                &mode,
+                &["always", "never"],
+            )),
+            Repr::Tools { always, never } => Ok(Self::Tools {
+                always: always.tool_names,
+                never: never.tool_names,
+            }),
+        }
+    }
+}
+
+/// The approximate location of the user of the [`web search`](ResponseTool::WebSearch) tool.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+#[serde(tag = "type", rename = "approximate")]
+pub struct UserLocation {
+    /// The two-letter ISO country code, like `US`.
+    pub country: Option<String>,
+    pub region: Option<String>,
+    pub city: Option<String>,
+
+    /// The IANA timezone, like `America/Chicago`.
+    pub timezone: Option<String>,
+}
+
+/// The domains the [`web search`](ResponseTool::WebSearch) tool is restricted to.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct WebSearchFilters {
+    /// Up to 20 domains, like `openai.com`, including their subdomains.
+    pub allowed_domains: Vec<String>,
+}
+
+impl From<FunctionSpec> for ResponseTool {
+    fn from(function: FunctionSpec) -> Self {
+        Self::function(function)
+    }
+}
+
+/// Controls which (if any) tool is called by the model in a [`Response`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ResponseToolChoice {
+    /// The model can pick between generating a message or calling one or more tools.
+    Auto,
+
+    /// The model will not call any tool and instead generates a message.
+    None,
+
+    /// The model must call one or more tools.
+    Required,
+
+    /// Forces the model to call the function with the given name.
+    Function(String),
+}
+
+impl Serialize for ResponseToolChoice {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        match self {
+            ResponseToolChoice::Auto => serializer.serialize_str("auto"),
+            ResponseToolChoice::None => serializer.serialize_str("none"),
+            ResponseToolChoice::Required => serializer.serialize_str("required"),
+            ResponseToolChoice::Function(name) => {
+                serde_json::json!({ "type": "function", "name": name }).serialize(serializer)
+            }
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for ResponseToolChoice {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            Mode(String),
+            Function { name: String },
+        }
+
+        match Repr::deserialize(deserializer)? {
+            Repr::Mode(mode) => match mode.as_str() {
+                "auto" => Ok(Self::Auto),
+                "none" => Ok(Self::None),
+                "required" => Ok(Self::Required),
+                _ => Err(serde::de::Error::unknown_variant(
+                    &mode,
+                    &["auto", "none", "required"],
+                )),
+            },
+            Repr::Function { name } => Ok(Self::Function(name)),
+        }
+    }
+}
+
+/// The configuration of reasoning models, like the o-series.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct ReasoningConfig {
+    pub effort: Option<ReasoningEffort>,
+
+    /// Whether to return a summary of the reasoning, `auto`, `concise` or `detailed`.
+    pub summary: Option<String>,
+}
+
+/// Response from [`Create Response`](crate::responses::create) & [`Retrieve Response`](crate::responses::retrieve) requests.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct Response {
+    pub id: String,
+    pub object: String,
+    pub created_at: u64,
+    pub status: ResponseStatus,
+
+    /// The reason of the failure, once the response [`failed`](ResponseStatus::Failed).
+    pub error: Option<ResponseError>,
+
+    /// Why the response is incomplete, like `{"reason": "max_output_tokens"}`.
+    pub incomplete_details: Option<serde_json::Value>,
+
+    pub model: String,
+
+    /// The items generated by the model, like messages, function calls and reasoning.
+    pub output: Vec<OutputItem>,
+
+    pub instructions: Option<serde_json::Value>,
+    pub previous_response_id: Option<String>,
+    pub tools: Vec<ResponseTool>,
+    pub tool_choice: Option<serde_json::Value>,
+    pub parallel_tool_calls: Option<bool>,
+    pub max_output_tokens: Option<u32>,
+    pub reasoning: Option<ReasoningConfig>,
+    pub text: Option<serde_json::Value>,
+    pub temperature: Option<f32>,
+    pub top_p: Option<f32>,
+    pub truncation: Option<String>,
+    pub usage: Option<ResponseUsage>,
+    pub metadata: HashMap<String, String>,
+    pub user: Option<String>,
+}
+
+impl Response {
+    /// The text output by the model, joining the text of all its messages.
+    pub fn output_text(&self) -> String {
+        self.output
+            .iter()
+            .filter_map(|item| match item {
+                OutputItem::Message(message) => Some(message.text()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// The calls to the tools of MCP servers awaiting an [`approval`](InputItem::McpApprovalResponse).
+    pub fn mcp_approval_requests(&self) -> Vec<&McpApprovalRequest> {
+        self.output
+            .iter()
+            .filter_map(|item| match item {
+                OutputItem::McpApprovalRequest(request) => Some(request),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// The functions called by the model, awaiting their outputs as [`function call outputs`](InputItem::FunctionCallOutput).
+    pub fn function_calls(&self) -> Vec<&FunctionToolCall> {
+        self.output
+            .iter()
+            .filter_map(|item| match item {
+                OutputItem::FunctionCall(call) => Some(call),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+/// The status of a [`Response`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ResponseStatus {
+    #[default]
+    Completed,
+    Failed,
+    InProgress,
+    Incomplete,
+    Queued,
+    Cancelled,
+
+    /// Any other status returned by the API, unknown to this version of the crate.
+    #[serde(other)]
+    Other,
+}
+
+/// The reason why a [`Response`] failed.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct ResponseError {
+    pub code: String,
+    pub message: String,
+}
+
+/// Tokens used by a [`Response`].
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct ResponseUsage {
+    pub input_tokens: u32,
+    pub output_tokens: u32,
+    pub total_tokens: u32,
+
+    /// Breakdown of the tokens used in the input, like the cached ones.
+    pub input_tokens_details: Option<PromptTokensDetails>,
+
+    /// Breakdown of the tokens used in the output, like the reasoning ones.
+    pub output_tokens_details: Option<CompletionTokensDetails>,
+}
+
+/// An item output by the model in a [`Response`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum OutputItem {
+    Message(OutputMessage),
+    FunctionCall(FunctionToolCall),
+    FileSearchCall(FileSearchToolCall),
+    WebSearchCall(WebSearchToolCall),
+    McpListTools(McpListTools),
+    McpCall(McpToolCall),
+    McpApprovalRequest(McpApprovalRequest),
+    Reasoning(ReasoningItem),
+
+    /// An item unknown to this version of the crate, kept as is.
+    #[serde(untagged)]
+    Other(serde_json::Value),
+}
+
+/// The status of an [`OutputItem`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ItemStatus {
+    #[default]
+    Completed,
+    InProgress,
+    Incomplete,
+
+    /// Any other status returned by the API, unknown to this version of the crate.
+    #[serde(other)]
+    Other,
+}
+
+/// A message output by the model.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct OutputMessage {
+    pub id: String,
+    pub role: InputRole,
+    pub status: Option<ItemStatus>,
+    pub content: Vec<OutputContent>,
+}
+
+impl OutputMessage {
+    /// The text of the message, joining all its text parts.
+    pub fn text(&self) -> String {
+        self.content
+            .iter()
+            .filter_map(|content| match content {
+                OutputContent::OutputText { text, .. } => Some(text.as_str()),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+/// A part of the content of an [`OutputMessage`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum OutputContent {
+    OutputText {
+        text: String,
+        #[serde(default)]
+        annotations: Vec<OutputAnnotation>,
+    },
+
+    /// The refusal of the model to respond.
+    Refusal { refusal: String },
+
+    /// Any other content returned by the API, unknown to this version of the crate.
+    #[serde(other)]
+    Other,
+}
+
+/// An annotation of a part of an [`output text`](OutputContent::OutputText).
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum OutputAnnotation {
+    /// A citation of a file searched by the [`file search`](ResponseTool::FileSearch) tool.
+    FileCitation {
+        file_id: String,
+        filename: Option<String>,
+
+        /// The index of the text the citation refers to.
+        index: u32,
+    },
+
+    /// A citation of a web page found by the [`web search`](ResponseTool::WebSearch) tool.
+    UrlCitation {
+        url: String,
+        title: String,
+
+        /// The range of the text the citation refers to.
+        start_index: u32,
+        end_index: u32,
+    },
+
+    /// A file generated by a tool.
+    FilePath { file_id: String, index: u32 },
+
+    /// An annotation unknown to this version of the crate, kept as is.
+    #[serde(untagged)]
+    Other(serde_json::Value),
+}
+
+/// A function called by the model, to be answered with an [`output`](InputItem::FunctionCallOutput) of the same `call_id`.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct FunctionToolCall {
+    pub id: Option<String>,
+    pub call_id: String,
+    pub name: String,
+
+    /// The arguments to call the function with, as generated by the model in JSON format.
+    pub arguments: String,
+    pub status: Option<ItemStatus>,
+}
+
+impl FunctionToolCall {
+    /// Deserializes the generated arguments into `T`.
+    pub fn parse_arguments<T: serde::de::DeserializeOwned>(&self) -> Result<T> {
+        Ok(serde_json::from_str(&self.arguments)?)
+    }
+}
+
+/// A search by the [`file search`](ResponseTool::FileSearch) tool.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct FileSearchToolCall {
+    pub id: String,
+    pub status: Option<String>,
+    pub queries: Vec<String>,
+
+    /// The results of the search, only returned when included with `file_search_call.results`.
+    pub results: Option<Vec<FileSearchToolResult>>,
+}
+
+/// The tools listed by an [`MCP server`](ResponseTool::Mcp), once per conversation.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct McpListTools {
+    pub id: String,
+    pub server_label: String,
+    pub tools: Vec<McpToolInfo>,
+
+    /// The error listing the tools, if any.
+    pub error: Option<String>,
+}
+
+/// A tool of an [`MCP server`](ResponseTool::Mcp).
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct McpToolInfo {
+    pub name: String,
+    pub description: Option<String>,
+
+    /// The parameters the tool accepts, described as a JSON Schema object.
+    pub input_schema: serde_json::Value,
+    pub annotations: Option<serde_json::Value>,
+}
+
+/// A call to a tool of an [`MCP server`](ResponseTool::Mcp), made by the API on behalf of the model.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct McpToolCall {
+    pub id: String,
+    pub server_label: String,
+    pub name: String,
+
+    /// The arguments of the call, in JSON format.
+    pub arguments: String,
+
+    /// The output of the tool, or the error of the call.
+    pub output: Option<String>,
+    pub error: Option<serde_json::Value>,
+
+    /// The ID of the approval request of the call, if it required one.
+    pub approval_request_id: Option<String>,
+}
+
+/// A request to approve a call to a tool of an [`MCP server`](ResponseTool::Mcp), see [`McpApproval`].
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct McpApprovalRequest {
+    pub id: String,
+    pub server_label: String,
+    pub name: String,
+
+    /// The arguments of the call, in JSON format.
+    pub arguments: String,
+}
+
+/// A search by the [`web search`](ResponseTool::WebSearch) tool, whose results are cited in the [`annotations`](OutputAnnotation::UrlCitation) of the output text.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct WebSearchToolCall {
+    pub id: String,
+    pub status: Option<String>,
+
+    /// What the tool did, like searching for a query.
+    pub action: Option<WebSearchAction>,
+}
+
+/// An action of a [`WebSearchToolCall`].
+#[skip_serializing_none]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum WebSearchAction {
+    Search {
+        query: Option<String>,
+    },
+    OpenPage {
+        url: Option<String>,
+    },
+
+    /// A search for a pattern in a page.
+    Find {
+        url: Option<String>,
+        pattern: Option<String>,
+    },
+
+    /// An action unknown to this version of the crate, kept as is.
+    #[serde(untagged)]
+    Other(serde_json::Value),
+}
+
+/// A result of a [`FileSearchToolCall`].
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct FileSearchToolResult {
+    pub file_id: String,
+    pub filename: String,
+    pub score: f32,
+    pub text: String,
+    pub attributes: Option<HashMap<String, serde_json::Value>>,
+}
+
+/// The reasoning of a reasoning model, sent back in the input of the next turns when responses aren't stored.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct ReasoningItem {
+    pub id: String,
+    pub summary: Vec<ReasoningSummary>,
+
+    /// The reasoning encrypted, when included with `reasoning.encrypted_content`.
+    pub encrypted_content: Option<String>,
+}
+
+/// A part of the summary of a [`ReasoningItem`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ReasoningSummary {
+    SummaryText {
+        text: String,
+    },
+
+    /// Any other summary returned by the API, unknown to this version of the crate.
+    #[serde(other)]
+    Other,
+}
+
+/// Parameters for [`Create Batch`](crate::batch::create) request.
+#[skip_serializing_none]
+#[derive(Builder, Clone, Debug, Default, Deserialize, Serialize)]
+#[builder(default, setter(into, strip_option))]
+pub struct CreateBatchParam {
+    /// The ID of an uploaded JSONL file of requests, with the [`batch`](Purpose::Batch) purpose.
+    ///
+    /// Up to 50,000 requests and 200MB, all to the same endpoint.
+    pub input_file_id: String,
+
+    /// The endpoint the requests are sent to.
+    pub endpoint: BatchEndpoint,
+
+    /// The time frame within which the batch is processed, only `24h` is supported.
+    pub completion_window: String,
+
+    pub metadata: Option<HashMap<String, String>>,
+}
+
+impl CreateBatchParamBuilder {
+    pub fn new(input_file_id: impl Into<String>, endpoint: BatchEndpoint) -> Self {
+        Self {
+            input_file_id: Some(input_file_id.into()),
+            endpoint: Some(endpoint),
+            completion_window: Some("24h".to_string()),
+            ..Self::default()
+        }
+    }
+}
+
+/// An endpoint the requests of a [`Batch`] are sent to.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum BatchEndpoint {
+    #[default]
+    #[serde(rename = "/v1/chat/completions")]
+    ChatCompletions,
+    #[serde(rename = "/v1/embeddings")]
+    Embeddings,
+    #[serde(rename = "/v1/completions")]
+    Completions,
+    #[serde(rename = "/v1/responses")]
+    Responses,
+    #[serde(rename = "/v1/moderations")]
+    Moderations,
+
+    /// Any other endpoint returned by the API, unknown to this version of the crate, which can't be requested.
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+impl BatchEndpoint {
+    /// The path of the endpoint, like `/v1/chat/completions`, or an empty one for an [`unknown`](BatchEndpoint::Other) endpoint.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BatchEndpoint::ChatCompletions => "/v1/chat/completions",
+            BatchEndpoint::Embeddings => "/v1/embeddings",
+            BatchEndpoint::Completions => "/v1/completions",
+            BatchEndpoint::Responses => "/v1/responses",
+            BatchEndpoint::Moderations => "/v1/moderations",
+            BatchEndpoint::Other => "",
+        }
+    }
+}
+
+/// A batch of requests processed asynchronously, at half the price, see [`Batches`](crate::batch).
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct Batch {
+    pub id: String,
+    pub object: String,
+    pub endpoint: String,
+
+    /// The errors of the validation of the input file, once the batch [`failed`](BatchStatus::Failed).
+    pub errors: Option<BatchErrors>,
+
+    pub input_file_id: String,
+    pub completion_window: String,
+    pub status: BatchStatus,
+
+    /// The file of the responses of the requests which succeeded, once the batch is terminal.
+    pub output_file_id: Option<String>,
+
+    /// The file of the errors of the requests which failed, once the batch is terminal.
+    pub error_file_id: Option<String>,
+
+    /// The Unix timestamps (in seconds) of the changes of status of the batch.
+    pub created_at: u64,
+    pub in_progress_at: Option<u64>,
+    pub expires_at: Option<u64>,
+    pub finalizing_at: Option<u64>,
+    pub completed_at: Option<u64>,
+    pub failed_at: Option<u64>,
+    pub expired_at: Option<u64>,
+    pub cancelling_at: Option<u64>,
+    pub cancelled_at: Option<u64>,
+
+    pub request_counts: BatchRequestCounts,
+    pub metadata: Option<HashMap<String, String>>,
+}
+
+/// The status of a [`Batch`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BatchStatus {
+    /// The input file is being validated before the batch can begin.
+    #[default]
+    Validating,
+
+    /// The input file failed the validation.
+    Failed,
+    InProgress,
+
+    /// The batch completed, and the results are being prepared.
+    Finalizing,
+    Completed,
+
+    /// The batch couldn't be completed within the completion window.
+    Expired,
+    Cancelling,
+    Cancelled,
+
+    /// Any other status returned by the API, unknown to this version of the crate.
+    #[serde(other)]
+    Other,
+}
+
+impl BatchStatus {
+    /// Whether the batch is over, and won't change anymore.
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            BatchStatus::Failed
+                | BatchStatus::Completed
+                | BatchStatus::Expired
+                | BatchStatus::Cancelled
+        )
+    }
+}
+
+impl Batch {
+    /// Polls the batch until it's [`terminal`](BatchStatus::is_terminal), returning its latest state.
+    ///
+    /// Batches take minutes to hours, so the delay between polls is best set in seconds,
+    /// growing from [`interval`](PollOptions::interval) up to [`max_interval`](PollOptions::max_interval).
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use fieri::{Client, batch::{retrieve, PollOptionsBuilder}};
+    /// use std::time::Duration;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::new();
+    ///
+    ///     let opts = PollOptionsBuilder::default()
+    ///         .interval(Duration::from_secs(10))
+    ///         .max_interval(Duration::from_secs(300))
+    ///         .build()?;
+    ///
+    ///     let batch = retrieve(&client, "batch_abc123").await?;
+    ///     let batch = batch.wait(&client, opts).await?;
+    ///     println!("{:?}", batch.status);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn wait(&self, client: &crate::Client, opts: PollOptions) -> crate::Result<Batch> {
+        self.wait_with(client, opts, |_| {}).await
+    }
+
+    /// Polls the batch like [`wait`](Batch::wait), calling `on_progress` with the batch each time
+    /// its status or its [`request_counts`](Batch::request_counts) change.
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use fieri::{Client, batch::{retrieve, PollOptions}};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = Client::new();
+    ///
+    ///     let batch = retrieve(&client, "batch_abc123").await?;
+    ///     let batch = batch
+    ///         .wait_with(&client, PollOptions::default(), |batch| {
+    ///             let counts = &batch.request_counts;
+    ///             println!("{:?}: {}/{} done, {} failed", batch.status, counts.completed, counts.total, counts.failed);
+    ///         })
+    ///         .await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn wait_with(
+        &self,
+        client: &crate::Client,
+        opts: PollOptions,
+        mut on_progress: impl FnMut(&Batch),
+    ) -> crate::Result<Batch> {
+        let mut polls = 0;
+        let mut batch = self.clone();
+
+        while !batch.status.is_terminal() {
+            polls += 1;
+            crate::utils::sleep(opts.delay(polls)).await;
+
+            let latest = crate::batch::retrieve(client, &self.id).await?;
+            if latest.status != batch.status || latest.request_counts != batch.request_counts {
+                on_progress(&latest);
+            }
+            batch = latest;
+        }
+
+        Ok(batch)
+    }
+}
+
+/// The numbers of requests of a [`Batch`], by status.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct BatchRequestCounts {
+    pub total: u32,
+    pub completed: u32,
+    pub failed: u32,
+}
+
+/// The errors of the validation of the input file of a [`Batch`].
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct BatchErrors {
+    pub object: String,
+    pub data: Vec<BatchError>,
+}
+
+/// An error of a [`Batch`], found validating its input file or sending one of its requests.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct BatchError {
+    pub code: String,
+    pub message: String,
+    pub param: Option<String>,
+
+    /// The line of the input file causing the error.
+    pub line: Option<u32>,
+}
+
+/// Parameters for [`List Batches`](crate::batch::list) request.
+#[skip_serializing_none]
+#[derive(Builder, Clone, Debug, Default, Deserialize, Serialize)]
+#[builder(default, setter(into, strip_option))]
+pub struct ListBatchesParam {
+    /// Number of batches to retrieve, between 1 and 100, defaults to 20.
+    pub limit: Option<u32>,
+
+    /// Identifier of the last batch of the previous page, to retrieve the next one.
+    pub after: Option<String>,
+}
+
+/// Response from [`List Batches`](crate::batch::list) request.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct ListBatches {
+    pub object: String,
+    pub data: Vec<Batch>,
+    pub first_id: Option<String>,
+    pub last_id: Option<String>,
+    pub has_more: bool,
+}
+
+/// The result of a request of a [`Batch`], a line of its output or error file.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct BatchResult {
+    pub id: String,
+
+    /// The custom id of the request, as given in the input file.
+    pub custom_id: String,
+
+    /// The response of the endpoint, either the body it responded with or an API error.
+    pub response: Option<BatchResponse>,
+
+    /// The error of a request that couldn't be sent at all.
+    pub error: Option<BatchError>,
+}
+
+impl BatchResult {
+    /// Parses the response body as `T`, or returns the error the request failed with, along with the custom id.
+    pub fn parse<T: serde::de::DeserializeOwned>(self) -> (String, Result<T>) {
+        let result = match (self.response, self.error) {
+            (_, Some(error)) => Err(Error::APIError(crate::error::RequestError {
+                error: crate::error::ErrorMessage {
+                    message: error.message,
+                    r#type: "batch_error".to_string(),
+                    param: error.param.map_or(serde_json::Value::Null, Into::into),
+                    code: error.code.into(),
+                },
+                meta: Default::default(),
+            })),
+            (Some(response), None) if (200..300).contains(&response.status_code) => {
+                serde_json::from_value(response.body).map_err(Error::from)
+            }
+            (Some(response), None) => Err(Error::APIError(
+                serde_json::from_value::<crate::error::RequestError>(response.body.clone())
+                    .unwrap_or_else(|_| crate::error::RequestError {
+                        error: crate::error::ErrorMessage {
+                            message: response.body.to_string(),
+                            r#type: format!("status_{}", response.status_code),
+                            ..Default::default()
+                        },
+                        meta: Default::default(),
+                    })
+                    .meta(crate::client::ResponseMeta {
+                        request_id: Some(response.request_id),
+                        processing_time: None,
+                    }),
+            )),
+            (None, None) => Err(Error::APIError(crate::error::RequestError {
+                error: crate::error::ErrorMessage {
+                    message: "The result has neither a response nor an error.".to_string(),
+                    r#type: "batch_error".to_string(),
+                    ..Default::default()
+                },
+                meta: Default::default(),
+            })),
+        };
+
+        (self.custom_id, result)
+    }
+}
+
+/// The response of the endpoint to a request of a [`Batch`].
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct BatchResponse {
+    pub status_code: u16,
+    pub request_id: String,
+    pub body: serde_json::Value,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chat_deserialization() {
+        let param: ChatParam = serde_json::from_str(
+            r#"
+            {
+                "model": "gpt-3.5-turbo",
+                "messages": [{"role": "user", "content": "Hello!"}]
+            }
+            "#,
+        )
+        .unwrap();
+
+        let resp: Chat = serde_json::from_str(
+            r#"
+            {
+                "id": "chatcmpl-123",
+                "object": "chat.completion",
+                "created": 1677652288,
+                "choices": [{
+                  "index": 0,
+                  "message": {
+                    "role": "assistant",
+                    "content": "\n\nHello there, how may I assist you today?"
+                  },
+                  "finish_reason": "stop"
+                }],
+                "usage": {
+                  "prompt_tokens": 9,
+                  "completion_tokens": 12,
+                  "total_tokens": 21
+                }
+              }
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(param.model, "gpt-3.5-turbo");
+        assert_eq!(param.messages.len(), 1);
+        assert_eq!(resp.choices.len(), 1);
+        assert_eq!(
+            resp.choices[0].message.content,
+            "\n\nHello there, how may I assist you today?"
+        );
+        assert_eq!(resp.choices[0].finish_reason, Some("stop".to_string()));
+        assert_eq!(resp.usage.prompt_tokens, 9);
+    }
+
+    #[test]
+    fn test_chat_message_serialization() {
+        let param = ChatParamBuilder::new(
+            "gpt-3.5-turbo",
+            vec![
+                ChatMessage::system("Be brief."),
+                ChatMessage::user("Hello!"),
+            ],
+        )
+        .build()
+        .unwrap();
+
+        assert_eq!(
+            serde_json::to_value(&param).unwrap(),
+            serde_json::json!({
+                "model": "gpt-3.5-turbo",
+                "messages": [
+                    {"role": "system", "content": "Be brief."},
+                    {"role": "user", "content": "Hello!"}
+                ]
+            })
+        );
+    }
+
+    #[test]
+    fn test_chat_stream_accumulation() {
+        let chunks: Vec<ChatChunk> = [
+            r#"{"id": "chatcmpl-1", "object": "chat.completion.chunk", "created": 1, "model": "gpt-4", "choices": [{"index": 0, "delta": {"role": "assistant", "content": ""}, "finish_reason": null}]}"#,
+            r#"{"id": "chatcmpl-1", "object": "chat.completion.chunk", "created": 1, "model": "gpt-4", "choices": [{"index": 0, "delta": {"content": "Hello"}, "finish_reason": null}]}"#,
+            r#"{"id": "chatcmpl-1", "object": "chat.completion.chunk", "created": 1, "model": "gpt-4", "choices": [{"index": 0, "delta": {"content": " there!"}, "finish_reason": null}]}"#,
+            r#"{"id": "chatcmpl-1", "object": "chat.completion.chunk", "created": 1, "model": "gpt-4", "choices": [{"index": 0, "delta": {}, "finish_reason": "stop"}]}"#,
+        ]
+        .iter()
+        .map(|chunk| serde_json::from_str(chunk).unwrap())
+        .collect();
+
+        let mut acc = ChatAccumulator::default();
+        chunks.iter().for_each(|chunk| acc.push(chunk));
+        assert!(acc.usage().is_none());
+
+        let usage: ChatChunk = serde_json::from_str(
+            r#"{"id": "chatcmpl-1", "object": "chat.completion.chunk", "created": 1, "model": "gpt-4", "choices": [], "usage": {"prompt_tokens": 9, "completion_tokens": 3, "total_tokens": 12}}"#,
+        )
+        .unwrap();
+        acc.push(&usage);
+
+        assert_eq!(acc.usage().unwrap().total_tokens, 12);
+
+        let past: ChatChunk = serde_json::from_str(
+            r#"{"id": "chatcmpl-1", "object": "chat.completion.chunk", "created": 1, "model": "gpt-4", "choices": [{"index": 4294967295, "delta": {"content": "Boom"}, "finish_reason": null}]}"#,
+        )
+        .unwrap();
+        acc.push(&past);
+        assert_eq!(acc.choices().len(), 1);
+        assert_eq!(acc.choices()[0].finish_reason, Some("stop".to_string()));
+        let message = acc.into_message().unwrap();
+        assert_eq!(message.role, ChatRole::Assistant);
+        assert_eq!(message.content, "Hello there!");
+    }
+
+    #[test]
+    fn test_chat_function_call() {
+        let param = ChatParamBuilder::new("gpt-3.5-turbo", vec![ChatMessage::user("Weather?")])
+            .functions(vec![FunctionSpecBuilder::new(
+                "get_weather",
+                serde_json::json!({"type": "object", "properties": {"city": {"type": "string"}}}),
+            )
+            .build()
+            .unwrap()])
+            .function_call(FunctionChoice::Function("get_weather".to_string()))
+            .build()
+            .unwrap();
+
+        let value = serde_json::to_value(&param).unwrap();
+        assert_eq!(value["functions"][0]["name"], "get_weather");
+        assert_eq!(
+            value["function_call"],
+            serde_json::json!({"name": "get_weather"})
+        );
+
+        let resp: Chat = serde_json::from_str(
+            r#"
+            {
+                "id": "chatcmpl-123",
+                "object": "chat.completion",
+                "created": 1677652288,
+                "model": "gpt-3.5-turbo-0613",
+                "choices": [{
+                    "index": 0,
+                    "message": {
+                        "role": "assistant",
+                        "content": null,
+                        "function_call": {"name": "get_weather", "arguments": "{\"city\": \"Sofia\"}"}
+                    },
+                    "finish_reason": "function_call"
+                }]
+            }
+            "#,
+        )
+        .unwrap();
+
+        let call = resp.choices[0].message.function_call.as_ref().unwrap();
+        let args: serde_json::Value = call.parse_arguments().unwrap();
+        assert_eq!(call.name, "get_weather");
+        assert_eq!(args["city"], "Sofia");
+        assert_eq!(resp.choices[0].message.content, "");
+    }
+
+    #[test]
+    fn test_chat_tool_calls() {
+        let param = ChatParamBuilder::new("gpt-4o", vec![ChatMessage::user("Weather?")])
+            .tools(vec![Tool::function(
+                FunctionSpecBuilder::new("get_weather", serde_json::json!({"type": "object"}))
+                    .build()
+                    .unwrap(),
+            )])
+            .tool_choice(ToolChoice::Required)
+            .parallel_tool_calls(true)
+            .build()
+            .unwrap();
+
+        let value = serde_json::to_value(&param).unwrap();
+        assert_eq!(value["tools"][0]["type"], "function");
+        assert_eq!(value["tool_choice"], "required");
+        assert_eq!(
+            serde_json::to_value(ToolChoice::Function("get_weather".to_string())).unwrap(),
+            serde_json::json!({"type": "function", "function": {"name": "get_weather"}})
+        );
+
+        let chunks: Vec<ChatChunk> = [
+            r#"{"choices": [{"index": 0, "delta": {"role": "assistant", "content": null, "tool_calls": [{"index": 0, "id": "call_1", "type": "function", "function": {"name": "get_weather", "arguments": ""}}]}}]}"#,
+            r#"{"choices": [{"index": 0, "delta": {"tool_calls": [{"index": 0, "function": {"arguments": "{\"city\":"}}]}}]}"#,
+            r#"{"choices": [{"index": 0, "delta": {"tool_calls": [{"index": 1, "id": "call_2", "type": "function", "function": {"name": "get_time", "arguments": "{}"}}]}}]}"#,
+            r#"{"choices": [{"index": 0, "delta": {"tool_calls": [{"index": 0, "function": {"arguments": " \"Sofia\"}"}}]}}]}"#,
+            r#"{"choices": [{"index": 0, "delta": {"tool_calls": [{"index": 4294967295, "function": {"arguments": "{}"}}]}}]}"#,
+            r#"{"choices": [{"index": 0, "delta": {}, "finish_reason": "tool_calls"}]}"#,
+        ]
+        .iter()
+        .map(|chunk| serde_json::from_str(chunk).unwrap())
+        .collect();
+
+        let mut acc = ChatAccumulator::default();
+        chunks.iter().for_each(|chunk| acc.push(chunk));
+
+        let message = acc.into_message().unwrap();
+        let calls = message.tool_calls.as_ref().unwrap();
+        assert_eq!(calls.len(), 2);
+        assert_eq!(calls[0].id, "call_1");
+        assert_eq!(calls[0].function.arguments, "{\"city\": \"Sofia\"}");
+        assert_eq!(calls[1].function.name, "get_time");
+
+        // The accumulated message can be sent back as-is, followed by the results of the calls.
+        let value = serde_json::to_value(&message).unwrap();
+        assert_eq!(value["tool_calls"][1]["id"], "call_2");
+        assert_eq!(
+            serde_json::to_value(ChatMessage::tool("call_1", "25C")).unwrap(),
+            serde_json::json!({"role": "tool", "content": "25C", "tool_call_id": "call_1"})
+        );
+    }
+
+    #[test]
+    fn test_response_format_serialization() {
+        let format = ResponseFormat::JsonSchema {
+            name: "person".to_string(),
+            schema: serde_json::json!({"type": "object", "properties": {"name": {"type": "string"}}}),
+            strict: Some(true),
+        };
+
+        let value = serde_json::to_value(&format).unwrap();
+        assert_eq!(
+            value,
+            serde_json::json!({
+                "type": "json_schema",
+                "json_schema": {
+                    "name": "person",
+                    "schema": {"type": "object", "properties": {"name": {"type": "string"}}},
+                    "strict": true
+                }
+            })
+        );
+        assert_eq!(
+            serde_json::from_value::<ResponseFormat>(value).unwrap(),
+            format
+        );
+        assert_eq!(
+            serde_json::to_value(ResponseFormat::Text).unwrap(),
+            serde_json::json!({"type": "text"})
+        );
+    }
+
+    #[test]
+    fn test_chat_parse_json() {
+        let mut resp = Chat {
+            choices: vec![ChatChoice {
+                message: ChatMessage::assistant(r#"{"name": "Alice", "age": 30}"#),
+                ..ChatChoice::default()
+            }],
+            ..Chat::default()
+        };
+
+        let value: serde_json::Value = resp.parse_json().unwrap();
+        assert_eq!(value["age"], 30);
+
+        resp.choices[0].message.content = "Not JSON.".into();
+        match resp.parse_json::<serde_json::Value>() {
+            Err(Error::OutputParseError { content, .. }) => assert_eq!(content, "Not JSON."),
+            other => panic!("unexpected result: {other:?}"),
+        }
+
+        resp.choices[0].message.refusal = Some("I can't help with that.".to_string());
+        assert!(matches!(
+            resp.parse_json::<serde_json::Value>(),
+            Err(Error::RefusalError(_))
+        ));
+        assert_eq!(
+            serde_json::to_value(ResponseFormat::JsonObject).unwrap(),
+            serde_json::json!({"type": "json_object"})
+        );
+    }
+
+    #[test]
+    fn test_chat_image_content() {
+        let path = std::env::temp_dir().join("fieri_test_chat_image_content.png");
+        fs::write(&path, [0x89, b'P', b'N', b'G']).unwrap();
+
+        let message =
+            ChatMessage::user_with_image("What's in it?", path.to_str().unwrap()).unwrap();
+        let url = ContentPart::image("https://example.com/cat.png")
+            .unwrap()
+            .detail(ImageDetail::Low);
+
+        let value = serde_json::to_value(&message).unwrap();
+        assert_eq!(
+            value["content"][0],
+            serde_json::json!({"type": "text", "text": "What's in it?"})
+        );
+        assert_eq!(value["content"][1]["type"], "image_url");
+        assert_eq!(
+            value["content"][1]["image_url"]["url"],
+            "data:image/png;base64,iVBORw=="
+        );
+        assert_eq!(
+            serde_json::to_value(url).unwrap(),
+            serde_json::json!({"type": "image_url", "image_url": {"url": "https://example.com/cat.png", "detail": "low"}})
+        );
+
+        let message: ChatMessage = serde_json::from_value(value).unwrap();
+        assert!(matches!(message.content, ChatContent::Parts(ref parts) if parts.len() == 2));
+        assert_eq!(message.content.to_string(), "What's in it?");
+    }
+
+    #[test]
+    fn test_chat_audio() {
+        let param = ChatParamBuilder::new(
+            "gpt-4o-audio-preview",
+            vec![ChatMessage::user(vec![
+                ContentPart::text("What is in this recording?"),
+                ContentPart::input_audio("UklGRg==", InputAudioFormat::Wav),
+            ])],
+        )
+        .modalities(vec![Modality::Text, Modality::Audio])
+        .audio(ChatAudioParam::new("alloy", ChatAudioFormat::Wav))
+        .build()
+        .unwrap();
+
+        let value = serde_json::to_value(&param).unwrap();
+        assert_eq!(value["modalities"], serde_json::json!(["text", "audio"]));
+        assert_eq!(
+            value["audio"],
+            serde_json::json!({"voice": "alloy", "format": "wav"})
+        );
+        assert_eq!(
+            value["messages"][0]["content"][1],
+            serde_json::json!({"type": "input_audio", "input_audio": {"data": "UklGRg==", "format": "wav"}})
+        );
+
+        let message: ChatMessage = serde_json::from_str(
+            r#"
+            {
+                "role": "assistant",
+                "content": null,
+                "audio": {
+                    "id": "audio_abc123",
+                    "expires_at": 1729018505,
+                    "data": "UklGRg==",
+                    "transcript": "Yes, I am."
+                }
+            }
+            "#,
+        )
+        .unwrap();
+
+        let audio = message.audio.unwrap();
+        assert_eq!(audio.decode().unwrap(), b"RIFF");
+        assert_eq!(audio.transcript.as_deref(), Some("Yes, I am."));
+        assert_eq!(
+            serde_json::to_value(ChatAudio::reference("audio_abc123")).unwrap(),
+            serde_json::json!({"id": "audio_abc123"})
+        );
+    }
+
+    #[test]
+    fn test_conversation_history() {
+        let mut conversation = Conversation::new("gpt-4o").system("Be brief.");
+        conversation.push(ChatMessage::user("Hi!"));
+        conversation.push(ChatMessage::assistant("Hello!"));
+
+        assert_eq!(conversation.history().len(), 3);
+        assert_eq!(conversation.last().unwrap().content, "Hello!");
+        assert_eq!(conversation.param.model, "gpt-4o");
+        assert_eq!(conversation.into_history()[0].role, ChatRole::System);
+    }
+
+    #[test]
+    fn test_logprobs_deserialization() {
+        let choice: ChatChoice = serde_json::from_str(
+            r#"
+            {
+                "index": 0,
+                "message": {"role": "assistant", "content": "Hi"},
+                "logprobs": {
+                    "content": [{
+                        "token": "Hi",
+                        "logprob": -0.31725305,
+                        "bytes": [72, 105],
+                        "top_logprobs": [
+                            {"token": "Hi", "logprob": -0.31725305, "bytes": [72, 105]},
+                            {"token": "Hello", "logprob": -1.3190403, "bytes": null}
+                        ]
+                    }],
+                    "refusal": null
+                },
+                "finish_reason": "stop"
+            }
+            "#,
+        )
+        .unwrap();
+
+        let logprobs = choice.logprobs.unwrap();
+        assert_eq!(logprobs.content[0].bytes, Some(b"Hi".to_vec()));
+        assert_eq!(logprobs.content[0].top_logprobs[1].token, "Hello");
+        assert!((logprobs.content[0].probability() - 0.728).abs() < 0.001);
+        assert!(logprobs.refusal.is_empty());
+
+        let choice: Choices = serde_json::from_str(
+            r#"
+            {
+                "text": " test",
+                "index": 0,
+                "logprobs": {
+                    "tokens": [" test"],
+                    "token_logprobs": [-0.2],
+                    "top_logprobs": [{" test": -0.2, " trial": -2.1}],
+                    "text_offset": [4]
+                },
+                "finish_reason": "length"
+            }
+            "#,
+        )
+        .unwrap();
+
+        let logprobs = choice.logprobs.unwrap();
+        assert_eq!(logprobs.token_logprobs, vec![Some(-0.2)]);
+        assert_eq!(logprobs.top_logprobs[0].as_ref().unwrap()[" trial"], -2.1);
+    }
+
+    #[cfg(feature = "tokenizer")]
+    #[test]
+    fn test_logit_bias_words() {
+        let param = ChatParamBuilder::new("gpt-4", vec![ChatMessage::user("Hello!")])
+            .logit_bias_words([("Hello world", -100)])
+            .unwrap()
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            serde_json::to_value(&param).unwrap()["logit_bias"],
+            serde_json::json!({"9906": -100, "1917": -100})
+        );
+    }
+
+    #[test]
+    fn test_stop_serialization() {
+        let param = ChatParamBuilder::new("gpt-4", vec![ChatMessage::user("Hello!")])
+            .stop("\n")
+            .build()
+            .unwrap();
+        assert_eq!(serde_json::to_value(&param).unwrap()["stop"], "\n");
+
+        let param = CompletionParamBuilder::new("gpt-3.5-turbo-instruct")
+            .stop(vec!["\n", "END"])
+            .build()
+            .unwrap();
+        assert_eq!(
+            serde_json::to_value(&param).unwrap()["stop"],
+            serde_json::json!(["\n", "END"])
+        );
+
+        let stop: Stop = serde_json::from_str(r#"["a", "b"]"#).unwrap();
+        assert_eq!(stop, Stop::from(vec!["a", "b"]));
+        let stop: Stop = serde_json::from_str(r#""a""#).unwrap();
+        assert_eq!(stop, Stop::Single("a".to_string()));
+    }
+
+    #[test]
+    fn test_reasoning_model_params() {
+        let param = ChatParamBuilder::new("o3-mini", vec![ChatMessage::user("Hello!")])
+            .max_tokens(100u32)
+            .reasoning_effort(ReasoningEffort::Low)
+            .build()
+            .unwrap();
+
+        let value = serde_json::to_value(param.for_model_family()).unwrap();
+        assert_eq!(value["max_completion_tokens"], 100);
+        assert_eq!(value["reasoning_effort"], "low");
+        assert!(value.get("max_tokens").is_none());
+
+        let param = ChatParam {
+            model: "gpt-4o".to_string(),
+            ..param
+        };
+        assert!(matches!(param.for_model_family(), Cow::Borrowed(_)));
+        assert!(!is_reasoning_model("omni-moderation-latest"));
+    }
+
+    #[test]
+    fn test_token_usage_details() {
+        let usage: TokenUsage = serde_json::from_str(
+            r#"
+            {
+                "prompt_tokens": 2006,
+                "completion_tokens": 300,
+                "total_tokens": 2306,
+                "prompt_tokens_details": {"cached_tokens": 1920},
+                "completion_tokens_details": {"reasoning_tokens": 256, "audio_tokens": 0}
+            }
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(usage.prompt_tokens_details.unwrap().cached_tokens, 1920);
+        assert_eq!(
+            usage.completion_tokens_details.unwrap().reasoning_tokens,
+            256
+        );
+
+        let usage: TokenUsage = serde_json::from_str(
+            r#"{"prompt_tokens": 5, "completion_tokens": 7, "total_tokens": 12}"#,
+        )
+        .unwrap();
+        assert!(usage.prompt_tokens_details.is_none());
+    }
+
+    #[test]
+    fn test_prediction_serialization() {
+        let param = ChatParamBuilder::new("gpt-4o", vec![ChatMessage::user("Rename x to y.")])
+            .prediction(Prediction::content("let x = 1;"))
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            serde_json::to_value(&param).unwrap()["prediction"],
+            serde_json::json!({"type": "content", "content": "let x = 1;"})
+        );
+
+        let usage: TokenUsage = serde_json::from_str(
+            r#"{"completion_tokens_details": {"accepted_prediction_tokens": 18, "rejected_prediction_tokens": 2}}"#,
+        )
+        .unwrap();
+        let details = usage.completion_tokens_details.unwrap();
+        assert_eq!(details.accepted_prediction_tokens, 18);
+        assert_eq!(details.rejected_prediction_tokens, 2);
+    }
+
+    #[test]
+    fn test_stored_chat_completions() {
+        let param = ListChatParamBuilder::default()
+            .limit(10u32)
+            .order(Order::Desc)
+            .build()
+            .unwrap();
+        assert_eq!(
+            serde_json::to_value(&param).unwrap(),
+            serde_json::json!({"limit": 10, "order": "desc"})
+        );
+
+        let resp: ChatList = serde_json::from_str(
+            r#"
+            {
+                "object": "list",
+                "data": [
+                    {
+                        "id": "chatcmpl-AyPNinnUqUDYo9SAdA52NobMflmj2",
+                        "object": "chat.completion",
+                        "created": 1738960610,
+                        "model": "gpt-4o-2024-08-06",
+                        "choices": [],
+                        "metadata": {"project": "evals"}
+                    }
+                ],
+                "first_id": "chatcmpl-AyPNinnUqUDYo9SAdA52NobMflmj2",
+                "last_id": "chatcmpl-AyPNinnUqUDYo9SAdA52NobMflmj2",
+                "has_more": false
+            }
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(resp.data[0].metadata.as_ref().unwrap()["project"], "evals");
+        assert!(!resp.has_more);
+    }
+
+    #[test]
+    fn test_service_tier() {
+        let param = ChatParamBuilder::new("gpt-4o", vec![ChatMessage::user("Hello!")])
+            .service_tier(ServiceTier::Flex)
+            .build()
+            .unwrap();
+        assert_eq!(
+            serde_json::to_value(&param).unwrap()["service_tier"],
+            "flex"
+        );
+
+        let resp: Chat = serde_json::from_str(
+            r#"{"id": "chatcmpl-1", "object": "chat.completion", "created": 1, "model": "gpt-4o", "choices": [], "service_tier": "scale"}"#,
+        )
+        .unwrap();
+        assert_eq!(resp.service_tier, Some(ServiceTier::Scale));
+
+        let resp: Chat = serde_json::from_str(
+            r#"{"id": "chatcmpl-1", "object": "chat.completion", "created": 1, "model": "gpt-4o", "choices": [], "service_tier": "turbo"}"#,
+        )
+        .unwrap();
+        assert_eq!(resp.service_tier, Some(ServiceTier::Other));
+    }
+
+    #[test]
+    fn test_embedding_batch() {
+        let param = EmbeddingParamBuilder::new("text-embedding-3-small", vec!["foo", "bar"])
+            .build()
+            .unwrap();
+        assert_eq!(
+            serde_json::to_value(&param).unwrap()["input"],
+            serde_json::json!(["foo", "bar"])
+        );
+
+        let param =
+            EmbeddingParamBuilder::new("text-embedding-3-small", vec![vec![1u32, 2], vec![3]])
+                .build()
+                .unwrap();
+        assert_eq!(
+            serde_json::to_value(&param).unwrap()["input"],
+            serde_json::json!([[1, 2], [3]])
+        );
+
+        let resp: Embedding = serde_json::from_str(
+            r#"
+            {
+                "object": "list",
+                "data": [
+                    {"object": "embedding", "embedding": [0.1, 0.2], "index": 0},
+                    {"object": "embedding", "embedding": [0.3, 0.4], "index": 1}
+                ],
+                "model": "text-embedding-3-small",
+                "usage": {"prompt_tokens": 2, "total_tokens": 2}
+            }
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(resp.model, "text-embedding-3-small");
+        assert_eq!(resp.data[1].index, 1);
+        assert_eq!(resp.data[1].embedding, vec![0.3, 0.4]);
+    }
+
+    #[test]
+    fn test_embedding_base64() {
+        let param = EmbeddingParamBuilder::new("text-embedding-3-small", "Hello world!")
+            .encoding_format(EncodingFormat::Base64)
+            .dimensions(256u32)
+            .build()
+            .unwrap();
+        let value = serde_json::to_value(&param).unwrap();
+        assert_eq!(value["encoding_format"], "base64");
+        assert_eq!(value["dimensions"], 256);
+
+        // [1.0, -0.5] as little-endian f32.
+        let data: EmbeddingData = serde_json::from_str(
+            r#"{"object": "embedding", "embedding": "AACAPwAAAL8=", "index": 0}"#,
+        )
+        .unwrap();
+        assert_eq!(data.embedding, vec![1.0, -0.5]);
+
+        // 6 bytes, not a whole number of f32.
+        assert!(serde_json::from_str::<EmbeddingData>(
+            r#"{"object": "embedding", "embedding": "AACAPwAA", "index": 0}"#
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_embed_many_options() {
+        let opts = EmbedManyOptionsBuilder::new("text-embedding-3-small")
+            .concurrency(8usize)
+            .build()
+            .unwrap();
+
+        assert_eq!(opts.model, "text-embedding-3-small");
+        assert_eq!(opts.concurrency, 8);
+        assert_eq!(opts.batch_size, 2048);
+    }
+
+    #[cfg(feature = "ndarray")]
+    #[test]
+    fn test_embedding_ndarray() {
+        let resp: Embedding = serde_json::from_str(
+            r#"
+            {
+                "object": "list",
+                "data": [
+                    {"object": "embedding", "embedding": [0.3, 0.4], "index": 1},
+                    {"object": "embedding", "embedding": [0.1, 0.2], "index": 0}
+                ],
+                "model": "text-embedding-3-small"
+            }
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(resp.to_array1().unwrap(), ndarray::array![0.3, 0.4]);
+        assert_eq!(
+            resp.to_array2().unwrap(),
+            ndarray::array![[0.1, 0.2], [0.3, 0.4]]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_embedding_cache() {
+        let param = EmbeddingParamBuilder::new("text-embedding-3-small", vec!["foo", "bar"])
+            .build()
+            .unwrap();
+        assert_eq!(
+            param.cache_key("foo"),
+            EmbeddingCacheKey::new("text-embedding-3-small", None, "foo")
+        );
+        assert_ne!(param.cache_key("foo"), param.cache_key("bar"));
+        assert_eq!(param.cache_key("foo").input, "foo");
+
+        let cache = InMemoryEmbeddingCache::default();
+        cache.insert(param.cache_key("foo"), vec![1.0]).await;
+        cache.insert(param.cache_key("bar"), vec![2.0]).await;
+        assert_eq!(cache.get(&param.cache_key("foo")).await, Some(vec![1.0]));
+        assert_eq!(cache.get(&param.cache_key("baz")).await, None);
+
+        // Fully cached, so no request is sent.
+        let resp = crate::embedding::create_cached(&crate::Client::new(), &param, &cache)
+            .await
+            .unwrap();
+        assert_eq!(resp.data[0].embedding, vec![1.0]);
+        assert_eq!(resp.data[1].embedding, vec![2.0]);
+        assert_eq!(resp.data[1].index, 1);
+    }
+
+    #[test]
+    fn test_generate_image_validation() {
+        let param = GenerateImageParamBuilder::new("A cat")
+            .model("dall-e-3")
+            .size(ImageSize::S1024x1792)
+            .quality(ImageQuality::Hd)
+            .style(ImageStyle::Natural)
+            .build()
+            .unwrap();
+        let value = serde_json::to_value(&param).unwrap();
+        assert_eq!(value["size"], "1024x1792");
+        assert_eq!(value["quality"], "hd");
+        assert_eq!(value["style"], "natural");
+
+        assert!(GenerateImageParamBuilder::new("A cat")
+            .size(ImageSize::S1792x1024)
+            .build()
+            .is_err());
+        assert!(GenerateImageParamBuilder::new("A cat")
+            .model("dall-e-3")
+            .n(2)
+            .build()
+            .is_err());
+        assert!(GenerateImageParamBuilder::new("A cat")
+            .style(ImageStyle::Vivid)
+            .build()
+            .is_err());
+        assert!(GenerateImageParamBuilder::new("a".repeat(1001))
+            .build()
+            .is_err());
+        assert!(GenerateImageParamBuilder::new("A cat")
+            .size(ImageSize::S512x512)
+            .n(2)
+            .build()
+            .is_ok());
+    }
+
+    #[test]
+    fn test_file_upload() {
+        assert_eq!(
+            FileUpload::from("images/cat.png"),
+            FileUpload::Path(PathBuf::from("images/cat.png"))
+        );
+
+        assert_eq!(
+            FileUpload::reader("cat.png", &[1u8, 2][..]).unwrap(),
+            FileUpload::bytes("cat.png", vec![1, 2])
+        );
+
+        let part = FileUpload::bytes("cat.png", vec![0u8; 4]).into_part();
+        assert!(part.is_ok());
+        assert!(matches!(
+            FileUpload::from("/nonexistent/cat.png").into_part(),
+            Err(Error::FileError(_))
+        ));
+
+        let upload = FileUpload::async_reader("cat.png", &b"meow"[..]);
+        assert!(upload.clone().into_part().is_ok());
+        assert!(matches!(upload.into_part(), Err(Error::FileError(_))));
+
+        let param = EditImageParamBuilder::new("Add a hat.")
+            .mask(FileUpload::bytes("mask.png", vec![0u8; 4]))
+            .build()
+            .unwrap();
+        assert!(param.mask.is_some());
+        assert!(serde_json::to_value(&param).unwrap().get("mask").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_file_upload_from_tokio_file() {
+        let path = std::env::temp_dir().join("fieri_test_file_upload.jsonl");
+        tokio::fs::write(&path, b"{\"prompt\": \"\", \"completion\": \"\"}\n")
+            .await
+            .unwrap();
+
+        let file = tokio::fs::File::open(&path).await.unwrap();
+        let upload = FileUpload::file("train.jsonl", file).await.unwrap();
+        assert!(matches!(
+            &upload,
+            FileUpload::Stream { file_name, length: Some(33), .. } if file_name == "train.jsonl"
+        ));
+        assert!(upload.into_part().is_ok());
+
+        tokio::fs::remove_file(&path).await.unwrap();
+    }
+
+    #[test]
+    fn test_image_param_errors() {
+        assert_eq!(
+            GenerateImageParamBuilder::new("A cat")
+                .quality(ImageQuality::Hd)
+                .build()
+                .unwrap_err(),
+            ImageParamError::UnsupportedQuality {
+                quality: ImageQuality::Hd,
+                model: "dall-e-2".into()
+            }
+        );
+        assert_eq!(
+            GenerateImageParamBuilder::new("A cat")
+                .model("dall-e-3")
+                .n(2)
+                .build()
+                .unwrap_err(),
+            ImageParamError::UnsupportedN {
+                n: 2,
+                max: 1,
+                model: "dall-e-3".into()
+            }
+        );
+        assert_eq!(
+            GenerateImageParamBuilder::new("A cat")
+                .style(ImageStyle::Vivid)
+                .build()
+                .unwrap_err(),
+            ImageParamError::UnsupportedParameter {
+                param: "style",
+                model: "dall-e-2".into()
+            }
+        );
+        assert!(matches!(
+            GenerateImageParamBuilder::new("a".repeat(1001)).build(),
+            Err(ImageParamError::PromptTooLong { max: 1000, .. })
+        ));
+
+        let err: Error = GenerateImageParamBuilder::new("A cat")
+            .size(ImageSize::S1792x1024)
+            .build()
+            .unwrap_err()
+            .into();
+        assert_eq!(
+            err.to_string(),
+            "Invalid values provided. The size 1792x1024 is not supported by dall-e-2."
+        );
+
+        for model in [
+            "my-dalle3-deployment",
+            "gpt-image-1-2025-04-23",
+            "flux-schnell",
+        ] {
+            GenerateImageParamBuilder::new("A cat")
+                .model(model)
+                .size(ImageSize::S1792x1024)
+                .quality(ImageQuality::Hd)
+                .style(ImageStyle::Vivid)
+                .n(4)
+                .build()
+                .unwrap();
+            assert!(ImageSize::S1536x1024.is_supported_by(model));
+            assert!(ImageQuality::High.is_supported_by(model));
+        }
+    }
+
+    #[test]
+    fn test_gpt_image_params() {
+        let param = GenerateImageParamBuilder::new("A cat")
+            .model("gpt-image-1")
+            .size(ImageSize::S1536x1024)
+            .quality(ImageQuality::High)
+            .background(ImageBackground::Opaque)
+            .moderation(ImageModeration::Low)
+            .output_format(ImageOutputFormat::Webp)
+            .output_compression(80)
+            .build()
+            .unwrap();
+        let value = serde_json::to_value(&param).unwrap();
+        assert_eq!(value["size"], "1536x1024");
+        assert_eq!(value["quality"], "high");
+        assert_eq!(value["output_format"], "webp");
+        assert_eq!(value["output_compression"], 80);
+
+        let gpt_image = || {
+            let mut builder = GenerateImageParamBuilder::new("A cat");
+            builder.model("gpt-image-1");
+            builder
+        };
+        assert!(gpt_image()
+            .response_format(ImageResponseFormat::Url)
+            .build()
+            .is_err());
+        assert!(gpt_image().quality(ImageQuality::Hd).build().is_err());
+        assert!(gpt_image().output_compression(80).build().is_err());
+        assert!(gpt_image()
+            .background(ImageBackground::Transparent)
+            .output_format(ImageOutputFormat::Jpeg)
+            .build()
+            .is_err());
+        assert!(GenerateImageParamBuilder::new("A cat")
+            .output_format(ImageOutputFormat::Png)
+            .build()
+            .is_err());
+    }
+
+    #[test]
+    fn test_image_stream_event_deserialization() {
+        let partial = r#"{
+            "type": "image_generation.partial_image",
+            "b64_json": "aGk=",
+            "created_at": 1620000000,
+            "size": "1024x1024",
+            "quality": "high",
+            "background": "opaque",
+            "output_format": "png",
+            "partial_image_index": 1
+        }"#;
+        let event: ImageStreamEvent = serde_json::from_str(partial).unwrap();
+        assert!(matches!(
+            event,
+            ImageStreamEvent::PartialImage {
+                partial_image_index: 1,
+                ..
+            }
+        ));
+        assert_eq!(event.image().unwrap().quality, Some(ImageQuality::High));
+        assert_eq!(event.image().unwrap().bytes().unwrap(), b"hi");
+
+        let completed = r#"{
+            "type": "image_generation.completed",
+            "b64_json": "aGk=",
+            "output_format": "webp",
+            "usage": {
+                "input_tokens": 10,
+                "output_tokens": 100,
+                "total_tokens": 110,
+                "input_tokens_details": {"text_tokens": 10, "image_tokens": 0}
+            }
+        }"#;
+        let event: ImageStreamEvent = serde_json::from_str(completed).unwrap();
+        assert!(event.is_completed());
+        match event {
+            ImageStreamEvent::Completed { usage, .. } => {
+                assert_eq!(usage.unwrap().total_tokens, 110)
+            }
+            _ => unreachable!(),
+        }
+        let event: ImageStreamEvent =
+            serde_json::from_str(r#"{"type": "image_generation.progress", "progress": 0.5}"#)
+                .unwrap();
+        assert!(matches!(event, ImageStreamEvent::Other));
+        assert!(event.image().is_none());
+
+        assert!(GenerateImageParamBuilder::new("A cat")
+            .partial_images(2)
+            .build()
+            .is_err());
+        assert!(GenerateImageParamBuilder::new("A cat")
+            .model("gpt-image-1")
+            .partial_images(4)
+            .build()
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_image_b64_json() {
+        let resp: Image = serde_json::from_str(
+            r#"{"created": 1589478378, "data": [{"b64_json": "iVBORw==", "revised_prompt": "A fluffy cat."}, {"b64_json": "AQI="}]}"#,
+        )
+        .unwrap();
+
+        let data = resp.data.as_ref().unwrap();
+        assert_eq!(data[0].bytes().unwrap(), [0x89, b'P', b'N', b'G']);
+        assert_eq!(data[0].revised_prompt.as_deref(), Some("A fluffy cat."));
+        assert!(data[0].url.is_none());
+        assert_eq!(
+            data[1].download(&crate::Client::new()).await.unwrap(),
+            [1, 2]
+        );
+
+        let dir = std::env::temp_dir().join("fieri_test_image_b64_json");
+        fs::create_dir_all(&dir).unwrap();
+        let paths = resp.save_all(&crate::Client::new(), &dir).await.unwrap();
+        assert_eq!(paths, [dir.join("image_0.png"), dir.join("image_1.png")]);
+        assert_eq!(fs::read(&paths[1]).unwrap(), [1, 2]);
+
+        let resp: Image = serde_json::from_str(
+            r#"{"created": 1589478378, "data": [{"url": "https://example.com/img-1.png"}]}"#,
+        )
+        .unwrap();
+        let data = resp.data.as_ref().unwrap();
+        assert!(matches!(data[0].bytes(), Err(Error::ImageUrlError)));
+
+        // The URL isn't mocked, so it's downloaded as a `404` that fails the save.
+        let client = crate::Client::new()
+            .transport(std::sync::Arc::new(crate::transport::MockTransport::new()));
+        assert!(matches!(
+            resp.save_all(&client, &dir).await,
+            Err(Error::APIError(err)) if err.error.message.starts_with("No mock response")
+        ));
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_speech_param() {
+        let param = SpeechParamBuilder::new("tts-1", "Hello there.", Voice::Alloy)
+            .response_format(SpeechFormat::Opus)
+            .speed(1.5)
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            serde_json::to_value(&param).unwrap(),
+            serde_json::json!({
+                "model": "tts-1",
+                "input": "Hello there.",
+                "voice": "alloy",
+                "response_format": "opus",
+                "speed": 1.5
+            })
+        );
+
+        assert!(
+            SpeechParamBuilder::new("tts-1", "Hello there.", Voice::Nova)
+                .speed(4.5)
+                .build()
+                .is_err()
+        );
+        assert!(
+            SpeechParamBuilder::new("tts-1", "a".repeat(4097), Voice::Nova)
+                .build()
+                .is_err()
+        );
+
+        assert_eq!(
+            serde_json::from_str::<Voice>("\"marin\"").unwrap(),
+            Voice::Other("marin".to_string())
+        );
+        assert_eq!(
+            serde_json::from_str::<Voice>("\"nova\"").unwrap(),
+            Voice::Nova
+        );
+        assert_eq!(
+            serde_json::to_string(&Voice::Other("marin".to_string())).unwrap(),
+            "\"marin\""
+        );
+    }
+
+    #[test]
+    fn test_assistant() {
+        let param = AssistantParamBuilder::new("gpt-4o")
+            .name("Math Tutor")
+            .tools(vec![
+                AssistantTool::CodeInterpreter,
+                AssistantTool::file_search(),
+            ])
+            .tool_resources(ToolResources {
+                file_search: Some(FileSearchResources {
+                    vector_store_ids: vec!["vs_123".into()],
+                    ..Default::default()
+                }),
+                ..Default::default()
+            })
+            .build()
+            .unwrap();
+        assert_eq!(
+            serde_json::to_value(&param).unwrap(),
+            serde_json::json!({
+                "model": "gpt-4o",
+                "name": "Math Tutor",
+                "tools": [{"type": "code_interpreter"}, {"type": "file_search"}],
+                "tool_resources": {"file_search": {"vector_store_ids": ["vs_123"]}}
+            })
+        );
+
+        let resp: ListAssistants = serde_json::from_str(
+            r#"
+            {
+                "object": "list",
+                "data": [
+                    {
+                        "id": "asst_abc123",
+                        "object": "assistant",
+                        "created_at": 1698982736,
+                        "name": "Coding Tutor",
+                        "description": null,
+                        "model": "gpt-4o",
+                        "instructions": "You are a helpful assistant designed to make me better at coding!",
+                        "tools": [
+                            {"type": "file_search", "file_search": {"max_num_results": 20}},
+                            {"type": "function", "function": {"name": "get_weather", "parameters": {"type": "object"}}}
+                        ],
+                        "tool_resources": {"code_interpreter": {"file_ids": []}},
+                        "metadata": {},
+                        "top_p": 1.0,
+                        "temperature": 1.0,
+                        "response_format": "auto"
+                    }
+                ],
+                "first_id": "asst_abc123",
+                "last_id": "asst_abc123",
+                "has_more": false
+            }
+            "#,
+        )
+        .unwrap();
+
+        let assistant = &resp.data[0];
+        assert_eq!(assistant.id, "asst_abc123");
+        assert!(matches!(
+            &assistant.tools[0],
+            AssistantTool::FileSearch { file_search: Some(options) } if options.max_num_results == Some(20)
+        ));
+        assert!(matches!(
+            &assistant.tools[1],
+            AssistantTool::Function { function } if function.name == "get_weather"
+        ));
+        assert_eq!(assistant.response_format, Some(serde_json::json!("auto")));
+    }
+
+    #[test]
+    fn test_thread() {
+        let param = ThreadParamBuilder::default()
+            .messages(vec![
+                CreateMessageParam::user("Hello, what is AI?"),
+                CreateMessageParamBuilder::new(MessageRole::User, "Summarize this file.")
+                    .attachments(vec![Attachment::new(
+                        "file-abc123",
+                        vec![AssistantTool::file_search()],
+                    )])
+                    .build()
+                    .unwrap(),
+            ])
+            .build()
+            .unwrap();
+        assert_eq!(
+            serde_json::to_value(&param).unwrap(),
+            serde_json::json!({
+                "messages": [
+                    {"role": "user", "content": "Hello, what is AI?"},
+                    {
+                        "role": "user",
+                        "content": "Summarize this file.",
+                        "attachments": [{"file_id": "file-abc123", "tools": [{"type": "file_search"}]}]
+                    }
+                ]
+            })
+        );
+
+        let resp: Thread = serde_json::from_str(
+            r#"
+            {
+                "id": "thread_abc123",
+                "object": "thread",
+                "created_at": 1699012949,
+                "metadata": {"user": "abc123"},
+                "tool_resources": {}
+            }
+            "#,
+        )
+        .unwrap();
+        assert_eq!(resp.id, "thread_abc123");
+        assert_eq!(resp.metadata["user"], "abc123");
+    }
+
+    #[test]
+    fn test_thread_message() {
+        let param = CreateMessageParam::user(vec![
+            MessageContentPart::text("What's in this image?"),
+            MessageContentPart::image_file("file-abc123"),
+        ]);
+        assert_eq!(
+            serde_json::to_value(&param).unwrap(),
+            serde_json::json!({
+                "role": "user",
+                "content": [
+                    {"type": "text", "text": "What's in this image?"},
+                    {"type": "image_file", "image_file": {"file_id": "file-abc123"}}
+                ]
+            })
+        );
+
+        let resp: ListMessages = serde_json::from_str(
+            r#"
+            {
+                "object": "list",
+                "data": [
+                    {
+                        "id": "msg_abc123",
+                        "object": "thread.message",
+                        "created_at": 1699016383,
+                        "assistant_id": "asst_abc123",
+                        "thread_id": "thread_abc123",
+                        "run_id": "run_abc123",
+                        "role": "assistant",
+                        "status": "completed",
+                        "content": [
+                            {
+                                "type": "text",
+                                "text": {
+                                    "value": "The report shows growth【4:0†source】.",
+                                    "annotations": [
+                                        {
+                                            "type": "file_citation",
+                                            "text": "【4:0†source】",
+                                            "file_citation": {"file_id": "file-xyz"},
+                                            "start_index": 25,
+                                            "end_index": 37
+                                        }
+                                    ]
+                                }
+                            },
+                            {"type": "image_file", "image_file": {"file_id": "file-chart", "detail": "auto"}}
+                        ],
+                        "attachments": [],
+                        "metadata": {}
+                    }
+                ],
+                "first_id": "msg_abc123",
+                "last_id": "msg_abc123",
+                "has_more": false
+            }
+            "#,
+        )
+        .unwrap();
+
+        let message = &resp.data[0];
+        assert_eq!(message.role, MessageRole::Assistant);
+        assert_eq!(message.status, Some(MessageStatus::Completed));
+        assert_eq!(message.text(), "The report shows growth【4:0†source】.");
+        assert_eq!(message.file_ids(), vec!["file-chart"]);
+        assert!(matches!(
+            &message.content[0],
+            MessageContent::Text { text } if matches!(
+                &text.annotations[0],
+                Annotation::FileCitation { file_citation, .. } if file_citation.file_id == "file-xyz"
+            )
+        ));
+        assert!(matches!(
+            &message.content[1],
+            MessageContent::ImageFile { image_file } if image_file.detail == Some(ImageDetail::Auto)
+        ));
+
+        assert_eq!(
+            serde_json::from_str::<MessageStatus>("\"queued\"").unwrap(),
+            MessageStatus::Other
+        );
+        let content: Vec<MessageContent> = serde_json::from_str(
+            r#"[{"type": "audio"}, {"type": "text", "text": {"value": "Hi", "annotations": [{"type": "url_citation"}]}}]"#,
+        )
+        .unwrap();
+        assert_eq!(content[0], MessageContent::Other);
+        assert!(matches!(
+            &content[1],
+            MessageContent::Text { text } if text.annotations == [Annotation::Other]
+        ));
+    }
+
+    #[test]
+    fn test_run() {
+        let param = RunParamBuilder::new("asst_abc123")
+            .additional_instructions("Address the user as Jane.")
+            .build()
+            .unwrap();
+        assert_eq!(
+            serde_json::to_value(&param).unwrap(),
+            serde_json::json!({
+                "assistant_id": "asst_abc123",
+                "additional_instructions": "Address the user as Jane."
+            })
+        );
+
+        let resp: Run = serde_json::from_str(
+            r#"
+            {
+                "id": "run_abc123",
+                "object": "thread.run",
+                "created_at": 1699075592,
+                "assistant_id": "asst_abc123",
+                "thread_id": "thread_abc123",
+                "status": "requires_action",
+                "started_at": 1699075592,
+                "expires_at": 1699076192,
+                "cancelled_at": null,
+                "failed_at": null,
+                "completed_at": null,
+                "last_error": null,
+                "model": "gpt-4o",
+                "instructions": "You tell the weather.",
+                "tools": [{"type": "function", "function": {"name": "get_weather", "parameters": {"type": "object"}}}],
+                "metadata": {},
+                "usage": null,
+                "required_action": {
+                    "type": "submit_tool_outputs",
+                    "submit_tool_outputs": {
+                        "tool_calls": [
+                            {
+                                "id": "call_abc123",
+                                "type": "function",
+                                "function": {"name": "get_weather", "arguments": "{\"location\":\"Paris\"}"}
+                            }
+                        ]
+                    }
+                },
+                "truncation_strategy": {"type": "auto", "last_messages": null},
+                "tool_choice": "auto",
+                "parallel_tool_calls": true
+            }
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(resp.status, RunStatus::RequiresAction);
+        assert!(!resp.status.is_terminal());
+        assert!(!resp.status.is_pending());
+        let calls = resp.required_action.as_ref().unwrap().tool_calls();
+        assert_eq!(calls[0].id, "call_abc123");
+        assert_eq!(calls[0].function.name, "get_weather");
+
+        let status: RunStatus = serde_json::from_str("\"paused\"").unwrap();
+        assert_eq!(status, RunStatus::Other);
+        assert!(status.is_pending());
+        let action: RequiredAction =
+            serde_json::from_str(r#"{"type": "approve", "approve": {}}"#).unwrap();
+        assert!(action.tool_calls().is_empty());
+
+        let param = SubmitToolOutputsParam {
+            tool_outputs: vec![ToolOutput::new("call_abc123", "22C")],
+            ..Default::default()
+        };
+        assert_eq!(
+            serde_json::to_value(&param).unwrap(),
+            serde_json::json!({"tool_outputs": [{"tool_call_id": "call_abc123", "output": "22C"}]})
+        );
+    }
+
+    #[test]
+    fn test_assistant_stream_events() {
+        let events: Vec<AssistantStreamEvent> = serde_json::from_value(serde_json::json!([
+            {
+                "event": "thread.run.created",
+                "data": {"id": "run_abc123", "object": "thread.run", "thread_id": "thread_abc123", "status": "queued"}
+            },
+            {
+                "event": "thread.message.delta",
+                "data": {
+                    "id": "msg_abc123",
+                    "object": "thread.message.delta",
+                    "delta": {"content": [{"index": 0, "type": "text", "text": {"value": "Hello", "annotations": []}}]}
+                }
+            },
+            {
+                "event": "thread.run.step.delta",
+                "data": {
+                    "id": "step_abc123",
+                    "object": "thread.run.step.delta",
+                    "delta": {"step_details": {"type": "tool_calls", "tool_calls": []}}
+                }
+            },
+            {
+                "event": "thread.message.delta",
+                "data": {"id": "msg_abc123", "delta": {"content": [{"index": 1, "type": "audio"}]}}
+            },
+            {
+                "event": "thread.run.step.delta",
+                "data": {
+                    "id": "step_abc123",
+                    "delta": {
+                        "step_details": {
+                            "type": "tool_calls",
+                            "tool_calls": [
+                                {"index": 0, "type": "computer_use"},
+                                {
+                                    "index": 1,
+                                    "type": "code_interpreter",
+                                    "code_interpreter": {"outputs": [{"index": 0, "type": "chart"}]}
+                                }
+                            ]
+                        }
+                    }
+                }
+            },
+            {"event": "thread.run.step.delta", "data": {"id": "step_abc123", "delta": {"step_details": {"type": "reflection"}}}},
+            {"event": "thread.run.step.created", "data": {"id": "step_abc123", "status": "in_progress"}},
+            {"event": "thread.run.unheard_of", "data": {"id": "run_abc123"}},
+            {"event": "done"}
+        ]))
+        .unwrap();
+
+        assert_eq!(events[0].run().unwrap().status, RunStatus::Queued);
+        assert!(
+            matches!(&events[1], AssistantStreamEvent::MessageDelta(delta) if delta.text() == "Hello")
+        );
+        assert!(matches!(
+            &events[2],
+            AssistantStreamEvent::RunStepDelta(RunStepDelta {
+                delta: RunStepDeltaBody {
+                    step_details: Some(StepDetailsDelta::ToolCalls { .. })
+                },
+                ..
+            })
+        ));
+        assert!(matches!(
+            &events[3],
+            AssistantStreamEvent::MessageDelta(delta) if matches!(delta.delta.content[..], [MessageContentDelta::Other])
+        ));
+        let AssistantStreamEvent::RunStepDelta(RunStepDelta {
+            delta:
+                RunStepDeltaBody {
+                    step_details:
+                        Some(StepDetailsDelta::ToolCalls {
+                            tool_calls: Some(tool_calls),
+                        }),
+                },
+            ..
+        }) = &events[4]
+        else {
+            panic!("expected tool call deltas");
+        };
+        assert!(matches!(tool_calls[0], StepToolCallDelta::Other));
+        assert!(matches!(
+            &tool_calls[1],
+            StepToolCallDelta::CodeInterpreter { code_interpreter: Some(CodeInterpreterCallDelta { outputs: Some(outputs), .. }), .. }
+                if matches!(outputs[..], [CodeInterpreterOutputDelta::Other])
+        ));
+        assert!(matches!(
+            &events[5],
+            AssistantStreamEvent::RunStepDelta(RunStepDelta {
+                delta: RunStepDeltaBody {
+                    step_details: Some(StepDetailsDelta::Other)
+                },
+                ..
+            })
+        ));
+        assert!(
+            matches!(&events[6], AssistantStreamEvent::RunStepCreated(step) if step.status == RunStepStatus::InProgress)
+        );
+        assert!(
+            matches!(&events[7], AssistantStreamEvent::Unknown { event, .. } if event == "thread.run.unheard_of")
+        );
+        assert!(matches!(events[8], AssistantStreamEvent::Done));
+    }
+
+    #[test]
+    fn test_run_steps() {
+        let resp: ListRunSteps = serde_json::from_value(serde_json::json!({
+            "object": "list",
+            "data": [
+                {
+                    "id": "step_abc123",
+                    "object": "thread.run.step",
+                    "created_at": 1699063291,
+                    "run_id": "run_abc123",
+                    "assistant_id": "asst_abc123",
+                    "thread_id": "thread_abc123",
+                    "type": "tool_calls",
+                    "status": "completed",
+                    "step_details": {
+                        "type": "tool_calls",
+                        "tool_calls": [
+                            {
+                                "id": "call_abc123",
+                                "type": "code_interpreter",
+                                "code_interpreter": {
+                                    "input": "print(1 + 1)",
+                                    "outputs": [
+                                        {"type": "logs", "logs": "2"},
+                                        {"type": "image", "image": {"file_id": "file-abc123"}}
+                                    ]
+                                }
+                            },
+                            {
+                                "id": "call_def456",
+                                "type": "function",
+                                "function": {"name": "get_weather", "arguments": "{}", "output": null}
+                            }
+                        ]
+                    },
+                    "usage": {"prompt_tokens": 123, "completion_tokens": 456, "total_tokens": 579}
+                },
+                {
+                    "id": "step_def456",
+                    "status": "completed",
+                    "step_details": {"type": "message_creation", "message_creation": {"message_id": "msg_abc123"}}
+                }
+            ],
+            "first_id": "step_abc123",
+            "last_id": "step_def456",
+            "has_more": false
+        }))
+        .unwrap();
+
+        let calls = resp.data[0].code_interpreter_calls();
+        assert_eq!(calls.len(), 1);
+        let images = calls[0]
+            .outputs
+            .iter()
+            .filter_map(|output| output.image_file_id())
+            .collect::<Vec<_>>();
+        assert_eq!(images, vec!["file-abc123"]);
+        assert!(resp.data[1].code_interpreter_calls().is_empty());
+        let output: CodeInterpreterOutput =
+            serde_json::from_str(r#"{"type": "chart", "chart": {}}"#).unwrap();
+        assert!(matches!(output, CodeInterpreterOutput::Other));
+        assert_eq!(output.image_file_id(), None);
+
+        let Some(StepDetails::ToolCalls { tool_calls }) = &resp.data[0].step_details else {
+            panic!("expected tool calls");
+        };
+        assert!(matches!(
+            &tool_calls[0],
+            StepToolCall::CodeInterpreter { code_interpreter, .. }
+                if code_interpreter.input == "print(1 + 1)"
+                    && matches!(&code_interpreter.outputs[1], CodeInterpreterOutput::Image { image } if image.file_id == "file-abc123")
+        ));
+        assert!(matches!(
+            &tool_calls[1],
+            StepToolCall::Function { function, .. } if function.name == "get_weather" && function.output.is_none()
+        ));
+        assert!(matches!(
+            &resp.data[1].step_details,
+            Some(StepDetails::MessageCreation { message_creation }) if message_creation.message_id == "msg_abc123"
+        ));
+
+        let step: RunStep = serde_json::from_value(serde_json::json!({
+            "id": "step_ghi789",
+            "status": "paused",
+            "step_details": {"type": "tool_calls", "tool_calls": [{"id": "call_ghi789", "type": "computer_use"}]}
+        }))
+        .unwrap();
+        assert_eq!(step.status, RunStepStatus::Other);
+        assert!(matches!(
+            &step.step_details,
+            Some(StepDetails::ToolCalls { tool_calls }) if matches!(tool_calls[..], [StepToolCall::Other])
+        ));
+        let step: RunStep = serde_json::from_value(
+            serde_json::json!({"id": "step_jkl012", "step_details": {"type": "reflection"}}),
+        )
+        .unwrap();
+        assert!(matches!(step.step_details, Some(StepDetails::Other)));
+    }
+
+    #[test]
+    fn test_vector_store_files() {
+        let param = CreateFileBatchParamBuilder::new(["file-abc123", "file-def456"])
+            .build()
+            .unwrap();
+        assert_eq!(
+            serde_json::to_value(&param).unwrap(),
+            serde_json::json!({"file_ids": ["file-abc123", "file-def456"]})
+        );
+
+        let resp: VectorStoreFileBatch = serde_json::from_str(
+            r#"
+            {
+                "id": "vsfb_abc123",
+                "object": "vector_store.file_batch",
+                "created_at": 1699061776,
+                "vector_store_id": "vs_abc123",
+                "status": "in_progress",
+                "file_counts": {"in_progress": 1, "completed": 1, "failed": 0, "cancelled": 0, "total": 2}
+            }
+            "#,
+        )
+        .unwrap();
+        assert_eq!(resp.status, VectorStoreFileStatus::InProgress);
+        assert!(!resp.status.is_terminal());
+        assert_eq!(resp.file_counts.total, 2);
+        let status: VectorStoreFileStatus = serde_json::from_str("\"queued\"").unwrap();
+        assert_eq!(status, VectorStoreFileStatus::Other);
+        assert!(!status.is_terminal());
+
+        let resp: ListVectorStoreFiles = serde_json::from_str(
+            r#"
+            {
+                "object": "list",
+                "data": [
+                    {
+                        "id": "file-abc123",
+                        "object": "vector_store.file",
+                        "created_at": 1699061776,
+                        "vector_store_id": "vs_abc123",
+                        "status": "failed",
+                        "usage_bytes": 0,
+                        "last_error": {"code": "unsupported_file", "message": "The file type is not supported."}
+                    }
+                ],
+                "first_id": "file-abc123",
+                "last_id": "file-abc123",
+                "has_more": false
+            }
+            "#,
+        )
+        .unwrap();
+        assert_eq!(resp.data[0].status, VectorStoreFileStatus::Failed);
+        assert_eq!(
+            resp.data[0].last_error.as_ref().unwrap().code,
+            "unsupported_file"
+        );
+    }
+
+    #[test]
+    fn test_file_search_options() {
+        let tool = AssistantTool::file_search_with(FileSearchOptions {
+            max_num_results: Some(5),
+            ranking_options: Some(RankingOptions {
+                ranker: Some("auto".to_string()),
+                score_threshold: Some(0.5),
+            }),
+        });
+        assert_eq!(
+            serde_json::to_value(&tool).unwrap(),
+            serde_json::json!({
+                "type": "file_search",
+                "file_search": {
+                    "max_num_results": 5,
+                    "ranking_options": {"ranker": "auto", "score_threshold": 0.5}
+                }
+            })
+        );
+
+        let param = CreateVectorStoreFileParamBuilder::new("file-abc123")
+            .chunking_strategy(ChunkingStrategy::fixed(1200, 200))
+            .build()
+            .unwrap();
+        assert_eq!(
+            serde_json::to_value(&param).unwrap(),
+            serde_json::json!({
+                "file_id": "file-abc123",
+                "chunking_strategy": {
+                    "type": "static",
+                    "static": {"max_chunk_size_tokens": 1200, "chunk_overlap_tokens": 200}
+                }
+            })
+        );
+
+        let resources = ToolResources {
+            file_search: Some(FileSearchResources {
+                vector_stores: Some(vec![NewVectorStore {
+                    file_ids: vec!["file-abc123".to_string()],
+                    chunking_strategy: Some(ChunkingStrategy::Auto),
+                    ..Default::default()
+                }]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        assert_eq!(
+            serde_json::to_value(&resources).unwrap(),
+            serde_json::json!({
+                "file_search": {
+                    "vector_stores": [{"file_ids": ["file-abc123"], "chunking_strategy": {"type": "auto"}}]
+                }
+            })
+        );
+
+        let strategy: ChunkingStrategy = serde_json::from_str(r#"{"type": "other"}"#).unwrap();
+        assert_eq!(strategy, ChunkingStrategy::Other);
+    }
+
+    #[test]
+    fn test_responses() {
+        let param = ResponseParamBuilder::new(
+            "gpt-4o",
+            vec![
+                InputItem::developer("Talk like a pirate."),
+                InputItem::user(vec![
+                    InputContentPart::text("What's in this image?"),
+                    InputContentPart::image_url("https://example.com/image.png"),
+                ]),
+                InputItem::function_call_output("call_abc123", "22C"),
+            ],
+        )
+        .previous_response_id("resp_abc123")
+        .tools(vec![ResponseTool::function(
+            FunctionSpecBuilder::new("get_weather", serde_json::json!({"type": "object"}))
+                .build()
+                .unwrap(),
+        )])
+        .tool_choice(ResponseToolChoice::Function("get_weather".into()))
+        .build()
+        .unwrap();
+        assert_eq!(
+            serde_json::to_value(&param).unwrap(),
+            serde_json::json!({
+                "model": "gpt-4o",
+                "input": [
+                    {"type": "message", "role": "developer", "content": "Talk like a pirate."},
+                    {
+                        "type": "message",
+                        "role": "user",
+                        "content": [
+                            {"type": "input_text", "text": "What's in this image?"},
+                            {"type": "input_image", "image_url": "https://example.com/image.png"}
+                        ]
+                    },
+                    {"type": "function_call_output", "call_id": "call_abc123", "output": "22C"}
+                ],
+                "previous_response_id": "resp_abc123",
+                "tools": [{"type": "function", "name": "get_weather", "parameters": {"type": "object"}}],
+                "tool_choice": {"type": "function", "name": "get_weather"}
+            })
+        );
+
+        let resp: Response = serde_json::from_str(
+            r#"
+            {
+                "id": "resp_def456",
+                "object": "response",
+                "created_at": 1741476542,
+                "status": "completed",
+                "error": null,
+                "model": "gpt-4o-2024-08-06",
+                "output": [
+                    {"type": "reasoning", "id": "rs_abc123", "summary": [{"type": "summary_text", "text": "Thinking."}]},
+                    {
+                        "type": "message",
+                        "id": "msg_abc123",
+                        "status": "completed",
+                        "role": "assistant",
+                        "content": [
+                            {
+                                "type": "output_text",
+                                "text": "Arr, it be sunny.",
+                                "annotations": [{"type": "file_citation", "file_id": "file-abc123", "index": 4}]
+                            }
+                        ]
+                    },
+                    {
+                        "type": "function_call",
+                        "id": "fc_abc123",
+                        "call_id": "call_def456",
+                        "name": "get_weather",
+                        "arguments": "{\"location\":\"Paris\"}",
+                        "status": "completed"
+                    },
+                    {"type": "computer_call", "id": "cu_abc123"}
+                ],
+                "parallel_tool_calls": true,
+                "previous_response_id": "resp_abc123",
+                "tools": [],
+                "usage": {
+                    "input_tokens": 36,
+                    "input_tokens_details": {"cached_tokens": 0},
+                    "output_tokens": 87,
+                    "output_tokens_details": {"reasoning_tokens": 0},
+                    "total_tokens": 123
+                },
+                "metadata": {}
+            }
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(resp.status, ResponseStatus::Completed);
+        assert_eq!(resp.output_text(), "Arr, it be sunny.");
+        let message: OutputMessage = serde_json::from_str(
+            r#"{"id": "msg_abc123", "role": "assistant", "status": "searching", "content": [{"type": "output_audio"}]}"#,
+        )
+        .unwrap();
+        assert_eq!(message.status, Some(ItemStatus::Other));
+        assert!(matches!(message.content[0], OutputContent::Other));
+        assert_eq!(
+            serde_json::from_str::<ResponseStatus>("\"paused\"").unwrap(),
+            ResponseStatus::Other
+        );
+        assert!(matches!(
+            serde_json::from_str::<ReasoningSummary>(r#"{"type": "summary_image"}"#).unwrap(),
+            ReasoningSummary::Other
+        ));
+        assert_eq!(resp.function_calls()[0].call_id, "call_def456");
+        assert!(
+            matches!(&resp.output[0], OutputItem::Reasoning(reasoning) if reasoning.summary.len() == 1)
+        );
+        assert!(
+            matches!(&resp.output[3], OutputItem::Other(item) if item["type"] == "computer_call")
+        );
+        assert_eq!(resp.usage.unwrap().total_tokens, 123);
+
+        let item = InputItem::from(resp.output[2].clone());
+        assert_eq!(
+            serde_json::to_value(&item).unwrap()["type"],
+            "function_call"
+        );
+    }
+
+    #[test]
+    fn test_response_web_search() {
+        let tool = ResponseTool::WebSearch {
+            search_context_size: Some(SearchContextSize::Low),
+            user_location: Some(UserLocation {
+                country: Some("GB".into()),
+                city: Some("London".into()),
+                ..Default::default()
+            }),
+            filters: None,
+        };
+        assert_eq!(
+            serde_json::to_value(&tool).unwrap(),
+            serde_json::json!({
+                "type": "web_search",
+                "search_context_size": "low",
+                "user_location": {"type": "approximate", "country": "GB", "city": "London"}
+            })
+        );
+
+        let tool: ResponseTool = serde_json::from_str(r#"{"type": "web_search_preview"}"#).unwrap();
+        assert!(matches!(tool, ResponseTool::WebSearch { .. }));
+
+        let output: Vec<OutputItem> = serde_json::from_str(
+            r#"
+            [
+                {
+                    "type": "web_search_call",
+                    "id": "ws_abc123",
+                    "status": "completed",
+                    "action": {"type": "search", "query": "positive news today"}
+                },
+                {
+                    "type": "message",
+                    "id": "msg_abc123",
+                    "status": "completed",
+                    "role": "assistant",
+                    "content": [
+                        {
+                            "type": "output_text",
+                            "text": "A turtle was saved today.",
+                            "annotations": [
+                                {
+                                    "type": "url_citation",
+                                    "start_index": 0,
+                                    "end_index": 25,
+                                    "url": "https://example.com/turtle",
+                                    "title": "Turtle saved"
+                                }
+                            ]
+                        }
+                    ]
+                }
+            ]
+            "#,
+        )
+        .unwrap();
+
+        assert!(matches!(
+            &output[0],
+            OutputItem::WebSearchCall(WebSearchToolCall {
+                action: Some(WebSearchAction::Search { query: Some(query) }),
+                ..
+            }) if query == "positive news today"
+        ));
+        let OutputItem::Message(message) = &output[1] else {
+            panic!("expected a message");
+        };
+        assert!(matches!(
+            &message.content[0],
+            OutputContent::OutputText { annotations, .. } if matches!(
+                &annotations[0],
+                OutputAnnotation::UrlCitation { url, end_index: 25, .. } if url == "https://example.com/turtle"
+            )
+        ));
+    }
+
+    #[test]
+    fn test_response_mcp() {
+        let tool = ResponseTool::Mcp {
+            server_label: "deepwiki".into(),
+            server_url: Some("https://mcp.deepwiki.com/mcp".into()),
+            server_description: None,
+            headers: None,
+            allowed_tools: Some(vec!["ask_question".into()]),
+            require_approval: Some(McpApproval::Tools {
+                always: vec![],
+                never: vec!["ask_question".into()],
+            }),
+        };
+        assert_eq!(
+            serde_json::to_value(&tool).unwrap(),
+            serde_json::json!({
+                "type": "mcp",
+                "server_label": "deepwiki",
+                "server_url": "https://mcp.deepwiki.com/mcp",
+                "allowed_tools": ["ask_question"],
+                "require_approval": {"always": {"tool_names": []}, "never": {"tool_names": ["ask_question"]}}
+            })
+        );
+        assert_eq!(
+            serde_json::from_str::<McpApproval>(r#""never""#).unwrap(),
+            McpApproval::Never
+        );
+
+        let resp: Response = serde_json::from_str(
+            r#"
+            {
+                "id": "resp_abc123",
+                "object": "response",
+                "status": "completed",
+                "output": [
+                    {
+                        "type": "mcp_list_tools",
+                        "id": "mcpl_abc123",
+                        "server_label": "deepwiki",
+                        "tools": [{"name": "ask_question", "input_schema": {"type": "object"}}]
+                    },
+                    {
+                        "type": "mcp_call",
+                        "id": "mcp_abc123",
+                        "server_label": "deepwiki",
+                        "name": "ask_question",
+                        "arguments": "{\"question\": \"What is MCP?\"}",
+                        "output": "A protocol.",
+                        "error": null
+                    },
+                    {
+                        "type": "mcp_approval_request",
+                        "id": "mcpr_abc123",
+                        "server_label": "deepwiki",
+                        "name": "read_wiki_structure",
+                        "arguments": "{}"
+                    }
+                ]
+            }
+            "#,
+        )
+        .unwrap();
+
+        assert!(
+            matches!(&resp.output[0], OutputItem::McpListTools(list) if list.tools[0].name == "ask_question")
+        );
+        assert!(
+            matches!(&resp.output[1], OutputItem::McpCall(call) if call.output.as_deref() == Some("A protocol."))
+        );
+
+        let requests = resp.mcp_approval_requests();
+        assert_eq!(requests[0].name, "read_wiki_structure");
+        assert_eq!(
+            serde_json::to_value(InputItem::mcp_approval_response(&requests[0].id, true)).unwrap(),
+            serde_json::json!({"type": "mcp_approval_response", "approval_request_id": "mcpr_abc123", "approve": true})
+        );
+    }
+
+    #[test]
+    fn test_batch() {
+        let param = CreateBatchParamBuilder::new("file-abc123", BatchEndpoint::ChatCompletions)
+            .build()
+            .unwrap();
+        assert_eq!(
+            serde_json::to_value(&param).unwrap(),
+            serde_json::json!({
+                "input_file_id": "file-abc123",
+                "endpoint": "/v1/chat/completions",
+                "completion_window": "24h"
+            })
+        );
+
+        let resp: Batch = serde_json::from_str(
+            r#"
+            {
+                "id": "batch_abc123",
+                "object": "batch",
+                "endpoint": "/v1/chat/completions",
+                "errors": null,
+                "input_file_id": "file-abc123",
+                "completion_window": "24h",
+                "status": "completed",
+                "output_file_id": "file-cvaTdG",
+                "error_file_id": "file-HOWS94",
+                "created_at": 1711471533,
+                "in_progress_at": 1711471538,
+                "expires_at": 1711557933,
+                "finalizing_at": 1711493133,
+                "completed_at": 1711493163,
+                "failed_at": null,
+                "expired_at": null,
+                "cancelling_at": null,
+                "cancelled_at": null,
+                "request_counts": {"total": 100, "completed": 95, "failed": 5},
+                "metadata": {"customer_id": "user_123456789"}
+            }
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(resp.status, BatchStatus::Completed);
+        assert!(resp.status.is_terminal());
+        assert!(!BatchStatus::Finalizing.is_terminal());
+        let status: BatchStatus = serde_json::from_str("\"paused\"").unwrap();
+        assert_eq!(status, BatchStatus::Other);
+        assert!(!status.is_terminal());
+        let endpoint: BatchEndpoint = serde_json::from_str("\"/v1/videos\"").unwrap();
+        assert_eq!(endpoint, BatchEndpoint::Other);
+        assert!(serde_json::to_string(&endpoint).is_err());
+        assert_eq!(
+            resp.request_counts,
+            BatchRequestCounts {
+                total: 100,
+                completed: 95,
+                failed: 5
+            }
+        );
+        assert_eq!(resp.output_file_id.as_deref(), Some("file-cvaTdG"));
+    }
+
+    #[test]
+    fn test_poll_options() {
+        let opts = PollOptions {
+            interval: Duration::from_secs(10),
+            max_interval: Duration::from_secs(60),
+            ..PollOptions::default()
+        };
+        for _ in 0..100 {
+            let delay = opts.delay(1);
+            assert!(delay >= Duration::from_secs(9) && delay <= Duration::from_secs(11));
+        }
+
+        let opts = PollOptions {
+            jitter: 0.0,
+            ..PollOptions::default()
+        };
+        assert_eq!(opts.delay(1), Duration::from_millis(500));
+        assert_eq!(opts.delay(2), Duration::from_millis(750));
+        assert_eq!(opts.delay(100), Duration::from_secs(5));
+
+        // Out of range delays are capped instead of overflowing.
+        let opts = PollOptions {
+            interval: Duration::MAX,
+            max_interval: Duration::from_secs(3600),
+            multiplier: f32::MAX,
+            jitter: 1.0,
+        };
+        assert!(opts.delay(u32::MAX) <= Duration::from_secs(7200));
+    }
+
+    #[test]
+    fn test_moderation_policy() {
+        let result = ModerationResult {
+            flagged: true,
+            categories: Categories {
+                harassment: true,
+                violence: true,
+                extra: HashMap::from([("fraud".to_string(), true)]),
+                ..Default::default()
+            },
+            category_scores: CategoryScores {
+                harassment: 0.8,
+                violence: 0.6,
+                hate: 0.3,
+                extra: HashMap::from([("fraud".to_string(), 0.9)]),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let categories = |verdict: &ModerationVerdict| {
+            let mut categories = verdict
+                .violations
+                .iter()
+                .map(|violation| violation.category.clone())
+                .collect::<Vec<_>>();
+            categories.sort();
+            categories
+        };
+
+        let verdict = ModerationPolicy::new().evaluate(&result);
+        assert!(!verdict.allowed);
+        assert_eq!(categories(&verdict), ["fraud", "harassment", "violence"]);
+
+        let verdict = ModerationPolicy::new()
+            .allow("harassment")
+            .allow("fraud")
+            .threshold("violence", 0.7)
+            .threshold("hate", 0.25)
+            .evaluate(&result);
+        assert_eq!(
+            verdict.violations,
+            [PolicyViolation {
+                category: "hate".into(),
+                score: 0.3,
+                flagged: false
+            }]
+        );
+
+        let verdict = ModerationPolicy::new()
+            .default_rule(CategoryRule::Allow)
+            .evaluate(&result);
+        assert!(verdict.allowed);
+
+        let resp = Moderation {
+            results: vec![result, ModerationResult::default()],
+            ..Default::default()
+        };
+        let verdicts = resp.evaluate(&ModerationPolicy::new());
+        assert!(!verdicts[0].allowed && verdicts[1].allowed);
+    }
+
+    #[test]
+    fn test_model_names() {
+        for model in ModelName::KNOWN {
+            assert_eq!(model.as_str().parse::<ModelName>(), Ok(model.clone()));
+            assert_eq!(
+                serde_json::from_value::<ModelName>(serde_json::to_value(&model).unwrap()).unwrap(),
+                model
+            );
+        }
+
+        assert_eq!(
+            serde_json::to_value(ModelName::Gpt4_1Mini).unwrap(),
+            "gpt-4.1-mini"
+        );
+        assert_eq!(
+            ModelName::from("ft:gpt-4o-mini:org::abc"),
+            ModelName::Other("ft:gpt-4o-mini:org::abc".into())
+        );
+        assert_eq!(String::from(ModelName::DallE3), "dall-e-3");
+
+        let param = EmbeddingParamBuilder::new(ModelName::TextEmbedding3Small, "Hi!")
+            .build()
+            .unwrap();
+        assert_eq!(param.model(), "text-embedding-3-small");
+    }
+
+    #[test]
+    fn test_realtime_sessions() {
+        let session: RealtimeSession = serde_json::from_str(
+            r#"{
+                "id": "sess_001",
+                "object": "realtime.session",
+                "model": "gpt-4o-realtime-preview",
+                "modalities": ["audio", "text"],
+                "voice": "alloy",
+                "input_audio_format": "pcm16",
+                "turn_detection": {"type": "server_vad", "threshold": 0.5, "prefix_padding_ms": 300, "silence_duration_ms": 200},
+                "tools": [],
+                "max_response_output_tokens": "inf",
+                "client_secret": {"value": "ek_abc123", "expires_at": 1234567890}
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(session.id.as_deref(), Some("sess_001"));
+        assert_eq!(session.config.voice, Some(Voice::Alloy));
+        assert_eq!(session.client_secret.value, "ek_abc123");
+        assert!(session.client_secret.is_expired());
+
+        let config = RealtimeTranscriptionSessionConfig {
+            input_audio_transcription: Some(InputAudioTranscription::new("gpt-4o-transcribe")),
+            input_audio_noise_reduction: Some(NoiseReduction::NearField),
+            ..Default::default()
+        };
+        assert_eq!(
+            serde_json::to_value(&config).unwrap(),
+            serde_json::json!({
+                "input_audio_transcription": {"model": "gpt-4o-transcribe"},
+                "input_audio_noise_reduction": {"type": "near_field"}
+            })
+        );
+        let reduction = serde_json::json!({"type": "adaptive", "level": 2});
+        assert_eq!(
+            serde_json::from_value::<NoiseReduction>(reduction.clone()).unwrap(),
+            NoiseReduction::Other(reduction)
+        );
+    }
+
+    #[test]
+    fn test_realtime_events() {
+        let event = ClientEvent::SessionUpdate {
+            session: RealtimeSessionConfig {
+                instructions: Some("Be brief.".into()),
+                voice: Some(Voice::Verse),
+                input_audio_transcription: Some(InputAudioTranscription::new("whisper-1")),
+                turn_detection: Some(TurnDetection::ServerVad {
+                    threshold: Some(0.5),
+                    prefix_padding_ms: None,
+                    silence_duration_ms: Some(500),
+                    create_response: None,
+                }),
+                ..Default::default()
+            },
+        };
+        assert_eq!(
+            serde_json::to_value(&event).unwrap(),
+            serde_json::json!({
+                "type": "session.update",
+                "session": {
+                    "instructions": "Be brief.",
+                    "voice": "verse",
+                    "input_audio_transcription": {"model": "whisper-1"},
+                    "turn_detection": {"type": "server_vad", "threshold": 0.5, "silence_duration_ms": 500}
+                }
+            })
+        );
+        let detection = serde_json::json!({"type": "push_to_talk", "key": "space"});
+        assert_eq!(
+            serde_json::to_value(
+                serde_json::from_value::<TurnDetection>(detection.clone()).unwrap()
+            )
+            .unwrap(),
+            detection
+        );
+        assert_eq!(
+            serde_json::to_value(ClientEvent::append_audio(b"hi")).unwrap(),
+            serde_json::json!({"type": "input_audio_buffer.append", "audio": "aGk="})
+        );
+        assert_eq!(
+            serde_json::to_value(ClientEvent::ResponseCreate { response: None }).unwrap(),
+            serde_json::json!({"type": "response.create"})
+        );
 
-    /// The input text to classify.
-    input: String,
-}
+        let event: ServerEvent = serde_json::from_str(
+            r#"{"type": "response.audio.delta", "event_id": "event_1", "response_id": "resp_1", "item_id": "item_1", "output_index": 0, "content_index": 0, "delta": "aGk="}"#,
+        )
+        .unwrap();
+        assert_eq!(event.audio().unwrap().unwrap(), b"hi");
 
-impl ModerationParamBuilder {
-    pub fn new(input: impl Into<String>) -> Self {
-        Self {
-            input: Some(input.into()),
-            ..Self::default()
-        }
-    }
-}
+        let event: ServerEvent = serde_json::from_str(
+            r#"{"type": "error", "event_id": "event_2", "error": {"type": "invalid_request_error", "code": "invalid_value", "message": "Invalid voice.", "param": null}}"#,
+        )
+        .unwrap();
+        assert!(matches!(event, ServerEvent::Error { error } if error.message == "Invalid voice."));
 
-/// Response from [`Create Moderation`](create) request.
-#[derive(Clone, Debug, Default, Deserialize, Serialize)]
-#[serde(default)]
-pub struct Moderation {
-    pub id: String,
-    pub model: String,
-    pub flagged: bool,
-    pub results: Vec<ModerationResult>,
+        let event: ServerEvent =
+            serde_json::from_str(r#"{"type": "rate_limits.updated", "rate_limits": []}"#).unwrap();
+        assert!(matches!(event, ServerEvent::Other));
+    }
 
-    pub token_usage: Option<TokenUsage>,
-}
+    #[test]
+    fn test_transcription_event_deserialization() {
+        let event: TranscriptionEvent =
+            serde_json::from_str(r#"{"type": "transcript.text.delta", "delta": "Hello"}"#).unwrap();
+        assert!(matches!(event, TranscriptionEvent::Delta { delta } if delta == "Hello"));
 
-/// The result of the content moderation request.
-#[derive(Clone, Debug, Default, Deserialize, Serialize)]
-#[serde(default)]
-pub struct ModerationResult {
-    pub categories: Categories,
-    pub category_scores: CategoryScores,
-}
+        let event: TranscriptionEvent = serde_json::from_str(
+            r#"{"type": "transcript.text.done", "text": "Hello there.", "usage": {"type": "tokens", "input_tokens": 14, "output_tokens": 4, "total_tokens": 18}}"#,
+        )
+        .unwrap();
+        match event {
+            TranscriptionEvent::Done { text, usage } => {
+                assert_eq!(text, "Hello there.");
+                assert_eq!(usage.unwrap().total_tokens, 18);
+            }
+            _ => unreachable!(),
+        }
 
-/// Contains a per-category binary content policy violation flags.
-///
-/// For each category, the value is `true` if the model flags the corresponding category as violated, `false` otherwise.
-#[derive(Clone, Debug, Default, Deserialize, Serialize)]
-#[serde(default)]
-pub struct Categories {
-    pub hate: bool,
-    #[serde(rename = "hate/threatening")]
-    pub hate_threatening: bool,
-    #[serde(rename = "self-harm")]
-    pub self_harm: bool,
-    pub sexual: bool,
-    #[serde(rename = "sexual/minors")]
-    pub sexual_minors: bool,
-    pub violence: bool,
-    #[serde(rename = "violence/graphic")]
-    pub violence_graphic: bool,
-}
+        let event: TranscriptionEvent =
+            serde_json::from_str(r#"{"type": "transcript.text.segment", "id": "seg_0"}"#).unwrap();
+        assert!(matches!(event, TranscriptionEvent::Other));
+    }
 
-/// Contains a per-category raw scores output by the model, denoting the model's confidence that the input violates the OpenAI's policy for the category.
-///
-/// The value is between 0 and 1, where higher values denote higher confidence.
-///
-/// The scores should not be interpreted as probabilities.
-#[derive(Clone, Debug, Default, Deserialize, Serialize)]
-#[serde(default)]
-pub struct CategoryScores {
-    pub hate: f64,
-    #[serde(rename = "hate/threatening")]
-    pub hate_threatening: f64,
-    #[serde(rename = "self-harm")]
-    pub self_harm: f64,
-    pub sexual: f64,
-    #[serde(rename = "sexual/minors")]
-    pub sexual_minors: f64,
-    pub violence: f64,
-    #[serde(rename = "violence/graphic")]
-    pub violence_graphic: f64,
-}
+    #[test]
+    fn test_caption_parsing() {
+        let srt = "1\r\n00:00:00,000 --> 00:00:01,500\r\nHello there.\r\n\r\n2\r\n00:00:01,500 --> 00:01:02,250\r\nGeneral\r\nKenobi.\r\n";
+        assert_eq!(
+            Caption::parse(srt),
+            [
+                Caption {
+                    index: 1,
+                    start: Duration::ZERO,
+                    end: Duration::from_millis(1500),
+                    text: "Hello there.".into()
+                },
+                Caption {
+                    index: 2,
+                    start: Duration::from_millis(1500),
+                    end: Duration::from_millis(62250),
+                    text: "General\nKenobi.".into()
+                }
+            ]
+        );
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+        let vtt = "WEBVTT\n\nNOTE A comment.\n\n00:00.000 --> 00:01.500 align:start\nHello there.\n\nintro\n01:00:00.000 --> 01:00:01.000\nBye.\n";
+        let captions = Caption::parse(vtt);
+        assert_eq!(captions.len(), 2);
+        assert_eq!(captions[0].index, 1);
+        assert_eq!(captions[0].end, Duration::from_millis(1500));
+        assert_eq!(captions[1].index, 2);
+        assert_eq!(captions[1].start, Duration::from_secs(3600));
+        assert_eq!(captions[1].text, "Bye.");
+    }
 
     #[test]
-    fn test_chat_deserialization() {
-        let param: ChatParam = serde_json::from_str(
-            r#"
-            {
-                "model": "gpt-3.5-turbo",
-                "messages": [{"role": "user", "content": "Hello!"}]
-            }
-            "#,
-        )
-        .unwrap();
-
-        let resp: Chat = serde_json::from_str(
-            r#"
-            {
-                "id": "chatcmpl-123",
-                "object": "chat.completion",
-                "created": 1677652288,
-                "choices": [{
-                  "index": 0,
-                  "message": {
-                    "role": "assistant",
-                    "content": "\n\nHello there, how may I assist you today?"
-                  },
-                  "finish_reason": "stop"
+    fn test_transcription() {
+        let param = TranscriptionParamBuilder::new("whisper-1")
+            .language("en")
+            .response_format(TranscriptionFormat::Srt)
+            .temperature(0.2)
+            .build()
+            .unwrap();
+        assert_eq!(param.response_format.unwrap().to_string(), "srt");
+        assert!(!param.response_format.unwrap().is_json());
+        assert!(param
+            .to_form(FileUpload::bytes("audio.mp3", vec![0u8; 4]))
+            .is_ok());
+
+        let resp: Transcription = serde_json::from_str(r#"{"text": "Hello there."}"#).unwrap();
+        assert_eq!(resp.text, "Hello there.");
+        assert!(resp.segments.is_none());
+
+        let resp: Transcription = serde_json::from_str(
+            r#"{
+                "task": "transcribe",
+                "language": "english",
+                "duration": 1.5,
+                "text": "Hello there.",
+                "segments": [{
+                    "id": 0,
+                    "seek": 0,
+                    "start": 0.0,
+                    "end": 1.5,
+                    "text": " Hello there.",
+                    "tokens": [50364, 2425, 456, 13],
+                    "temperature": 0.0,
+                    "avg_logprob": -0.3,
+                    "compression_ratio": 0.8,
+                    "no_speech_prob": 0.01
                 }],
-                "usage": {
-                  "prompt_tokens": 9,
-                  "completion_tokens": 12,
-                  "total_tokens": 21
-                }
-              }
-            "#,
+                "words": [{"word": "Hello", "start": 0.0, "end": 0.6}, {"word": "there", "start": 0.6, "end": 1.2}]
+            }"#,
         )
         .unwrap();
+        assert_eq!(resp.language.as_deref(), Some("english"));
+        assert_eq!(resp.segments.unwrap()[0].tokens.len(), 4);
+        assert_eq!(
+            resp.words.unwrap()[1],
+            TranscriptionWord {
+                word: "there".into(),
+                start: 0.6,
+                end: 1.2
+            }
+        );
 
-        assert_eq!(param.model, "gpt-3.5-turbo");
-        assert_eq!(param.messages.len(), 1);
-        assert_eq!(resp.choices.len(), 1);
+        let param = TranscriptionParamBuilder::new("whisper-1")
+            .response_format(TranscriptionFormat::VerboseJson)
+            .timestamp_granularities(vec![
+                TimestampGranularity::Word,
+                TimestampGranularity::Segment,
+            ])
+            .build()
+            .unwrap();
+        assert!(param.response_format.unwrap().is_json());
         assert_eq!(
-            resp.choices[0].message.content,
-            "\n\nHello there, how may I assist you today?"
+            serde_json::to_value(&param).unwrap()["timestamp_granularities"],
+            serde_json::json!(["word", "segment"])
         );
-        assert_eq!(resp.choices[0].finish_reason, Some("stop".to_string()));
-        assert_eq!(resp.usage.prompt_tokens, 9);
+
+        let param = TranslationParamBuilder::new("whisper-1")
+            .prompt("Hello.")
+            .build()
+            .unwrap();
+        assert!(param
+            .to_form(FileUpload::bytes("audio.mp3", vec![0u8; 4]))
+            .is_ok());
     }
 
     #[test]
@@ -1216,6 +10930,91 @@ mod tests {
         assert_eq!(resp.data.len(), 2);
         assert_eq!(resp.data[0].id, "file-ccdDZrC3iZVNiQVeEA6Z66wf");
         assert_eq!(resp.data[1].object, "file");
+        assert!(!resp.has_more);
+
+        let param = ListFilesParamBuilder::default()
+            .purpose(Purpose::Batch)
+            .order(Order::Asc)
+            .after("file-abc123")
+            .build()
+            .unwrap();
+        assert_eq!(
+            serde_json::to_value(&param).unwrap(),
+            serde_json::json!({"purpose": "batch", "order": "asc", "after": "file-abc123"})
+        );
+        assert_eq!(Purpose::UserData.to_string(), "user_data");
+    }
+
+    #[test]
+    fn test_upload_deserialization() {
+        let param = CreateUploadParamBuilder::new(
+            "training_examples.jsonl",
+            Purpose::FineTune,
+            2147483648,
+            "text/jsonl",
+        )
+        .build()
+        .unwrap();
+        assert_eq!(
+            serde_json::to_value(&param).unwrap(),
+            serde_json::json!({
+                "filename": "training_examples.jsonl",
+                "purpose": "fine-tune",
+                "bytes": 2147483648u64,
+                "mime_type": "text/jsonl"
+            })
+        );
+
+        let resp: Upload = serde_json::from_str(
+            r#"
+            {
+                "id": "upload_abc123",
+                "object": "upload",
+                "bytes": 2147483648,
+                "created_at": 1719184911,
+                "filename": "training_examples.jsonl",
+                "purpose": "fine-tune",
+                "status": "completed",
+                "expires_at": 1719127296,
+                "file": {
+                    "id": "file-xyz321",
+                    "object": "file",
+                    "bytes": 2147483648,
+                    "created_at": 1719186911,
+                    "filename": "training_examples.jsonl",
+                    "purpose": "fine-tune"
+                }
+            }
+            "#,
+        )
+        .unwrap();
+        assert_eq!(resp.status, UploadStatus::Completed);
+        assert_eq!(resp.file.unwrap().id, "file-xyz321");
+    }
+
+    #[test]
+    fn test_upload_session() {
+        let mut session = UploadSession {
+            upload_id: "upload_abc123".into(),
+            filename: "train.jsonl".into(),
+            bytes: 10,
+            part_size: 4,
+            part_ids: vec!["part_1".into(), "part_2".into()],
+            sha1: String::new(),
+            expires_at: u64::MAX,
+        };
+        assert_eq!(session.offset(), 8);
+        assert!(!session.is_uploaded());
+        assert!(!session.is_expired());
+
+        session.part_ids.push("part_3".into());
+        assert_eq!(session.offset(), 10);
+        assert!(session.is_uploaded());
+
+        let path = std::env::temp_dir().join("fieri_test_upload_session.json");
+        session.save(&path).unwrap();
+        assert_eq!(UploadSession::load(&path).unwrap(), session);
+        fs::remove_file(&path).unwrap();
     }
 
     #[test]
@@ -1351,6 +11150,141 @@ mod tests {
         assert_eq!(resp.id, "curie:ft-acmeco-2021-03-03-21-44-20");
     }
 
+    #[test]
+    fn test_fine_tuning_job() {
+        let param = CreateFineTuningJobParamBuilder::new("gpt-4o-mini-2024-07-18", "file-abc123")
+            .suffix("custom")
+            .hyperparameters(FineTuningHyperparameters {
+                n_epochs: Some(3.into()),
+                batch_size: Some(Hyperparameter::Auto),
+                ..Default::default()
+            })
+            .build()
+            .unwrap();
+        assert_eq!(
+            serde_json::to_value(&param).unwrap(),
+            serde_json::json!({
+                "model": "gpt-4o-mini-2024-07-18",
+                "training_file": "file-abc123",
+                "suffix": "custom",
+                "hyperparameters": {"batch_size": "auto", "n_epochs": 3}
+            })
+        );
+
+        let resp: FineTuningJob = serde_json::from_str(
+            r#"
+            {
+                "object": "fine_tuning.job",
+                "id": "ftjob-abc123",
+                "model": "gpt-4o-mini-2024-07-18",
+                "created_at": 1721764800,
+                "finished_at": 1721767800,
+                "fine_tuned_model": "ft:gpt-4o-mini:my-org:custom_suffix:id",
+                "organization_id": "org-123",
+                "result_files": ["file-abc123"],
+                "status": "succeeded",
+                "validation_file": null,
+                "training_file": "file-abc123",
+                "hyperparameters": {"n_epochs": 4, "batch_size": 1, "learning_rate_multiplier": "auto"},
+                "trained_tokens": 5768,
+                "error": null,
+                "seed": 42
+            }
+            "#,
+        )
+        .unwrap();
+
+        assert!(resp.status.is_terminal());
+        assert_eq!(resp.result_files, vec!["file-abc123"]);
+        assert_eq!(
+            resp.hyperparameters.n_epochs,
+            Some(Hyperparameter::Value(4))
+        );
+        assert_eq!(
+            resp.hyperparameters.learning_rate_multiplier,
+            Some(Hyperparameter::Auto)
+        );
+        assert!(!FineTuningJobStatus::Queued.is_terminal());
+        let status: FineTuningJobStatus = serde_json::from_str("\"pausing\"").unwrap();
+        assert_eq!(status, FineTuningJobStatus::Other);
+        assert!(!status.is_terminal());
+        let method: FineTuningMethod =
+            serde_json::from_str(r#"{"type": "reinforcement", "reinforcement": {}}"#).unwrap();
+        assert_eq!(method, FineTuningMethod::Other);
+        assert_eq!(method.hyperparameters(), None);
+        assert!(serde_json::from_str::<Hyperparameter<u32>>(r#""manual""#).is_err());
+    }
+
+    #[test]
+    fn test_fine_tuning_method() {
+        let method = FineTuningMethod::dpo(DpoHyperparameters {
+            beta: Some(0.5.into()),
+            n_epochs: Some(Hyperparameter::Auto),
+            ..Default::default()
+        });
+        assert_eq!(
+            serde_json::to_value(&method).unwrap(),
+            serde_json::json!({
+                "type": "dpo",
+                "dpo": {"hyperparameters": {"beta": 0.5, "n_epochs": "auto"}}
+            })
+        );
+        assert_eq!(
+            method.hyperparameters().unwrap().n_epochs,
+            Some(Hyperparameter::Auto)
+        );
+
+        let method: FineTuningMethod = serde_json::from_str(
+            r#"{"type": "supervised", "supervised": {"hyperparameters": {"batch_size": 4, "learning_rate_multiplier": 1.8, "n_epochs": 3}}}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            method,
+            FineTuningMethod::supervised(FineTuningHyperparameters {
+                batch_size: Some(4.into()),
+                learning_rate_multiplier: Some(1.8.into()),
+                n_epochs: Some(3.into()),
+            })
+        );
+    }
+
+    #[test]
+    fn test_list_fine_tuning_job_events_deserialization() {
+        let resp: ListFineTuningJobEvents = serde_json::from_str(
+            r#"
+            {
+                "object": "list",
+                "data": [
+                    {
+                        "object": "fine_tuning.job.event",
+                        "id": "ftevent-def456",
+                        "created_at": 1721764900,
+                        "level": "info",
+                        "message": "Step 1/100: training loss=2.84",
+                        "type": "metrics",
+                        "data": {"step": 1, "train_loss": 2.84}
+                    },
+                    {
+                        "object": "fine_tuning.job.event",
+                        "id": "ftevent-abc123",
+                        "created_at": 1721764800,
+                        "level": "info",
+                        "message": "Fine tuning job started",
+                        "type": "message"
+                    }
+                ],
+                "has_more": true
+            }
+            "#,
+        )
+        .unwrap();
+
+        assert!(resp.has_more);
+        assert_eq!(resp.data[0].data.as_ref().unwrap()["step"], 1);
+        assert_eq!(resp.data[1].r#type.as_deref(), Some("message"));
+        assert!(resp.data[1].data.is_none());
+    }
+
     #[test]
     fn test_parse_image_response_deserialization() {
         let param: GenerateImageParam = serde_json::from_str(
@@ -1424,7 +11358,41 @@ mod tests {
 
         assert_eq!(resp.data.len(), 3);
         assert_eq!(resp.data[0].id, "model-id-0");
+        assert_eq!(
+            resp.data[0].owned_by,
+            ModelOwner::Organization("organization-owner".into())
+        );
+        assert_eq!(resp.data[2].owned_by, ModelOwner::OpenAI);
         assert!(resp.token_usage.is_none());
+
+        let resp: Models = serde_json::from_str(
+            r#"
+            {
+                "object": "list",
+                "data": [
+                  {"id": "gpt-4o-mini", "object": "model", "created": 1721172741, "owned_by": "system"},
+                  {"id": "ft:gpt-4o-mini-2024-07-18:org-abc123::9wPZnbkk", "object": "model", "created": 1724091603, "owned_by": "org-abc123"}
+                ]
+              }
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(resp.data[0].owned_by, ModelOwner::System);
+        assert!(!resp.data[0].is_fine_tuned());
+        assert_eq!(
+            resp.data[0]
+                .created_at()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_secs(),
+            1721172741
+        );
+        assert!(resp.data[1].is_fine_tuned());
+        assert_eq!(
+            serde_json::to_value(&resp.data[1].owned_by).unwrap(),
+            "org-abc123"
+        );
     }
 
     #[test]
@@ -1491,9 +11459,126 @@ mod tests {
         )
         .unwrap();
 
-        assert_eq!(param.input, "I want to kill them.");
+        assert_eq!(
+            param.input,
+            ModerationInput::Text("I want to kill them.".into())
+        );
         assert_eq!(resp.id, "modr-5MWoLO");
         assert_eq!(resp.model, "text-moderation-001");
         assert_eq!(resp.results.len(), 1);
+        assert!(resp.results[0].categories.extra.is_empty());
+        assert_eq!(
+            resp.flagged_results()
+                .map(|(index, _)| index)
+                .collect::<Vec<_>>(),
+            [0]
+        );
+
+        let param = ModerationParamBuilder::new(vec!["Hello!", "I want to kill them."])
+            .build()
+            .unwrap();
+        assert_eq!(
+            serde_json::to_value(&param).unwrap(),
+            serde_json::json!({"input": ["Hello!", "I want to kill them."]})
+        );
+    }
+
+    #[test]
+    fn test_omni_moderation_categories() {
+        let resp: Moderation = serde_json::from_str(
+            r#"
+            {
+                "id": "modr-0d9740456c391e43c445bf0f010940c7",
+                "model": "omni-moderation-latest",
+                "results": [
+                  {
+                    "flagged": true,
+                    "categories": {
+                      "harassment": true,
+                      "harassment/threatening": true,
+                      "sexual": false,
+                      "hate": false,
+                      "hate/threatening": false,
+                      "illicit": false,
+                      "illicit/violent": null,
+                      "self-harm/intent": false,
+                      "self-harm/instructions": false,
+                      "self-harm": false,
+                      "sexual/minors": false,
+                      "violence": true,
+                      "violence/graphic": true,
+                      "fraud": true
+                    },
+                    "category_scores": {
+                      "harassment": 0.8189693396524255,
+                      "harassment/threatening": 0.804985420696006,
+                      "sexual": 1.573112165348997e-6,
+                      "hate": 0.007562942636942845,
+                      "hate/threatening": 0.004208854591835476,
+                      "illicit": 0.030535955153511665,
+                      "illicit/violent": 0.008925306722380033,
+                      "self-harm/intent": 0.00023023930975076432,
+                      "self-harm/instructions": 0.0002293869201073356,
+                      "self-harm": 0.012598046106750154,
+                      "sexual/minors": 2.212566909570261e-8,
+                      "violence": 0.9999992735124786,
+                      "violence/graphic": 0.843064871157054,
+                      "fraud": 0.25
+                    },
+                    "category_applied_input_types": {
+                      "harassment": ["text"],
+                      "violence": ["text", "image"]
+                    }
+                  }
+                ]
+              }
+            "#,
+        )
+        .unwrap();
+
+        let result = &resp.results[0];
+        assert!(result.categories.harassment_threatening);
+        assert!(!result.categories.illicit_violent);
+        assert!(result.category_scores.illicit_violent > 0.008);
+        assert_eq!(result.categories.extra.get("fraud"), Some(&true));
+        assert!(resp.any_flagged());
+        assert_eq!(
+            resp.flagged_categories().collect::<Vec<_>>(),
+            [
+                (0, "harassment"),
+                (0, "harassment/threatening"),
+                (0, "violence"),
+                (0, "violence/graphic"),
+                (0, "fraud")
+            ]
+        );
+        assert_eq!(result.category_scores.extra.get("fraud"), Some(&0.25));
+        assert_eq!(
+            result.applied_input_types("violence"),
+            [ModerationInputType::Text, ModerationInputType::Image]
+        );
+        assert_eq!(
+            result.applied_input_types("harassment"),
+            [ModerationInputType::Text]
+        );
+        assert!(result.applied_input_types("sexual").is_empty());
+
+        let param = ModerationParamBuilder::new(vec![
+            ContentPart::text("...and I want to kill them."),
+            ContentPart::image_url("https://example.com/image.png"),
+        ])
+        .model("omni-moderation-latest")
+        .build()
+        .unwrap();
+        let input = serde_json::to_value(&param).unwrap();
+        assert_eq!(input["input"][0]["type"], "text");
+        assert_eq!(
+            input["input"][1]["image_url"]["url"],
+            "https://example.com/image.png"
+        );
+
+        let categories = serde_json::to_value(&result.categories).unwrap();
+        assert_eq!(categories["fraud"], true);
+        assert_eq!(categories["self-harm/intent"], false);
     }
 }
@@ -2,3 +2,86 @@
 pub(crate) fn is_false(b: &bool) -> bool {
     !(*b)
 }
+
+// The clock of the target, the one of `std` being unsupported on wasm.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) use std::time::Instant;
+#[cfg(target_arch = "wasm32")]
+pub(crate) use web_time::Instant;
+
+// Waits for the duration, with the timer of tokio, or the one of the JavaScript host on wasm.
+pub(crate) async fn sleep(duration: std::time::Duration) {
+    #[cfg(not(target_arch = "wasm32"))]
+    tokio::time::sleep(duration).await;
+    #[cfg(target_arch = "wasm32")]
+    gloo_timers::future::sleep(duration).await;
+}
+
+// `Send` on the native targets, while nothing is on wasm, whose futures and streams are bound to the JavaScript thread.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) trait MaybeSend: Send {}
+#[cfg(not(target_arch = "wasm32"))]
+impl<T: Send> MaybeSend for T {}
+#[cfg(target_arch = "wasm32")]
+pub(crate) trait MaybeSend {}
+#[cfg(target_arch = "wasm32")]
+impl<T> MaybeSend for T {}
+
+// A random fraction between 0 and 1, to spread delays like polls and retries.
+//
+// Each `RandomState` is seeded differently, which is random enough to spread delays, without depending on `rand`.
+pub(crate) fn random_fraction() -> f64 {
+    use std::hash::{BuildHasher, Hasher};
+
+    std::collections::hash_map::RandomState::new()
+        .build_hasher()
+        .finish() as f64
+        / u64::MAX as f64
+}
+
+// Deserializes `null` into the default value of the type, for fields OpenAI may explicitly return as `null`.
+pub(crate) fn null_as_default<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: Default + serde::Deserialize<'de>,
+{
+    use serde::Deserialize;
+
+    Ok(Option::<T>::deserialize(deserializer)?.unwrap_or_default())
+}
+
+// Deserializes an embedding returned either as an array of floats, or as base64 encoded little-endian floats.
+pub(crate) fn float_or_base64<'de, D>(deserializer: D) -> Result<Vec<f32>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    use base64::Engine;
+    use serde::{de::Error, Deserialize};
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Repr {
+        Float(Vec<f32>),
+        Base64(String),
+    }
+
+    match Repr::deserialize(deserializer)? {
+        Repr::Float(floats) => Ok(floats),
+        Repr::Base64(encoded) => {
+            let bytes = base64::engine::general_purpose::STANDARD
+                .decode(encoded)
+                .map_err(D::Error::custom)?;
+            if bytes.len() % 4 != 0 {
+                return Err(D::Error::invalid_length(
+                    bytes.len(),
+                    &"a multiple of 4 bytes",
+                ));
+            }
+
+            Ok(bytes
+                .chunks_exact(4)
+                .map(|chunk| f32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+                .collect())
+        }
+    }
+}
@@ -0,0 +1,290 @@
+//! Vector math for comparing [`embeddings`](crate::embedding), like in simple semantic search.
+//!
+//! OpenAI embeddings are normalized to length 1, so the cosine similarity and the dot product of two of them are equal.
+//!
+//! Embeddings can be kept in a [`VectorStore`] and queried by similarity, like with the [`InMemoryVectorStore`].
+
+use std::collections::HashMap;
+
+use crate::{Error, Result};
+
+/// The dot product of two vectors, `None` if they don't have the same number of dimensions.
+pub fn dot(a: &[f32], b: &[f32]) -> Option<f32> {
+    if a.len() != b.len() {
+        return None;
+    }
+
+    Some(a.iter().zip(b).map(|(a, b)| a * b).sum())
+}
+
+/// The cosine similarity of two vectors, between -1 and 1, where 1 means both point in the same direction.
+///
+/// Returns 0 if either of the vectors has no length, and `None` if they don't have the same number of dimensions.
+pub fn cosine_similarity(a: &[f32], b: &[f32]) -> Option<f32> {
+    let dot = dot(a, b)?;
+    let norm = norm(a) * norm(b);
+    if norm == 0.0 {
+        return Some(0.0);
+    }
+
+    Some(dot / norm)
+}
+
+/// Scales the vector to a length of 1, leaving vectors with no length as they are.
+pub fn l2_normalize(v: &mut [f32]) {
+    let norm = norm(v);
+    if norm == 0.0 {
+        return;
+    }
+
+    v.iter_mut().for_each(|x| *x /= norm);
+}
+
+fn norm(v: &[f32]) -> f32 {
+    v.iter().map(|x| x * x).sum::<f32>().sqrt()
+}
+
+/// A vector stored along with its identifier and arbitrary metadata, like the text it was embedded from.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Record {
+    pub id: String,
+    pub vector: Vec<f32>,
+    pub metadata: serde_json::Value,
+}
+
+impl Record {
+    pub fn new(id: impl Into<String>, vector: Vec<f32>, metadata: serde_json::Value) -> Self {
+        Self {
+            id: id.into(),
+            vector,
+            metadata,
+        }
+    }
+}
+
+/// A [`Record`] matching a query, along with its cosine similarity to the queried vector.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScoredRecord {
+    pub record: Record,
+    pub score: f32,
+}
+
+/// The future of a vector store operation, `Send` but on wasm, whose futures are bound to the JavaScript thread.
+#[cfg(not(target_arch = "wasm32"))]
+pub type StoreFuture<'a, T> = futures::future::BoxFuture<'a, T>;
+/// The future of a vector store operation, `Send` but on wasm, whose futures are bound to the JavaScript thread.
+#[cfg(target_arch = "wasm32")]
+pub type StoreFuture<'a, T> = futures::future::LocalBoxFuture<'a, T>;
+
+/// Storage of vectors that can be queried by similarity.
+///
+/// Implement it to plug in an external database, once the [`InMemoryVectorStore`] isn't enough.
+pub trait VectorStore {
+    /// Inserts the record, replacing any existing record with the same id.
+    fn upsert(&mut self, record: Record) -> StoreFuture<'_, Result<()>>;
+
+    /// Returns up to `top_k` records most similar to the vector, the most similar first.
+    fn query<'a>(
+        &'a self,
+        vector: &'a [f32],
+        top_k: usize,
+    ) -> StoreFuture<'a, Result<Vec<ScoredRecord>>>;
+
+    /// Deletes the record with the given id, returning whether it existed.
+    fn delete<'a>(&'a mut self, id: &'a str) -> StoreFuture<'a, Result<bool>>;
+}
+
+/// A [`VectorStore`] keeping all records in memory, queried by brute force.
+///
+/// All the vectors of the store have the same number of dimensions, the one of the first record upserted,
+/// upserting or querying any other vector failing with [`Error::DimensionMismatch`].
+///
+/// ## Example
+/// ```
+/// use fieri::vector::{InMemoryVectorStore, Record, VectorStore};
+/// use serde_json::json;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let mut store = InMemoryVectorStore::default();
+///     store.upsert(Record::new("cat", vec![1.0, 0.0], json!({"text": "A cat."}))).await?;
+///     store.upsert(Record::new("car", vec![0.0, 1.0], json!({"text": "A car."}))).await?;
+///
+///     let matches = store.query(&[0.9, 0.1], 1).await?;
+///     assert_eq!(matches[0].record.id, "cat");
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct InMemoryVectorStore {
+    records: HashMap<String, Record>,
+}
+
+impl InMemoryVectorStore {
+    /// The number of stored records.
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    /// The record with the given id.
+    pub fn get(&self, id: &str) -> Option<&Record> {
+        self.records.get(id)
+    }
+
+    // Fails unless the vector has the dimensions of the records of the store, other than the one with the given id.
+    fn check_dimensions(&self, vector: &[f32], id: Option<&str>) -> Result<()> {
+        let other = self
+            .records
+            .values()
+            .find(|record| Some(record.id.as_str()) != id);
+
+        match other {
+            Some(other) if other.vector.len() != vector.len() => Err(Error::DimensionMismatch {
+                expected: other.vector.len(),
+                received: vector.len(),
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    // The operations of the store, all done in memory before their futures are returned.
+    fn upsert_record(&mut self, record: Record) -> Result<()> {
+        self.check_dimensions(&record.vector, Some(&record.id))?;
+        self.records.insert(record.id.clone(), record);
+
+        Ok(())
+    }
+
+    fn query_records(&self, vector: &[f32], top_k: usize) -> Result<Vec<ScoredRecord>> {
+        self.check_dimensions(vector, None)?;
+
+        let mut matches = self
+            .records
+            .values()
+            .filter_map(|record| {
+                Some(ScoredRecord {
+                    score: cosine_similarity(vector, &record.vector)?,
+                    record: record.clone(),
+                })
+            })
+            .collect::<Vec<_>>();
+
+        matches.sort_by(|a, b| b.score.total_cmp(&a.score));
+        matches.truncate(top_k);
+
+        Ok(matches)
+    }
+}
+
+impl VectorStore for InMemoryVectorStore {
+    fn upsert(&mut self, record: Record) -> StoreFuture<'_, Result<()>> {
+        let result = self.upsert_record(record);
+        Box::pin(async move { result })
+    }
+
+    fn query<'a>(
+        &'a self,
+        vector: &'a [f32],
+        top_k: usize,
+    ) -> StoreFuture<'a, Result<Vec<ScoredRecord>>> {
+        let result = self.query_records(vector, top_k);
+        Box::pin(async move { result })
+    }
+
+    fn delete<'a>(&'a mut self, id: &'a str) -> StoreFuture<'a, Result<bool>> {
+        let deleted = self.records.remove(id).is_some();
+        Box::pin(async move { Ok(deleted) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vector_math() {
+        assert_eq!(dot(&[1.0, 2.0, 3.0], &[4.0, 5.0, 6.0]), Some(32.0));
+        assert_eq!(dot(&[1.0, 2.0, 3.0], &[4.0, 5.0]), None);
+
+        assert!((cosine_similarity(&[1.0, 0.0], &[2.0, 0.0]).unwrap() - 1.0).abs() < 1e-6);
+        assert!(cosine_similarity(&[1.0, 0.0], &[0.0, 3.0]).unwrap().abs() < 1e-6);
+        assert_eq!(cosine_similarity(&[0.0, 0.0], &[1.0, 1.0]), Some(0.0));
+        assert_eq!(cosine_similarity(&[1.0, 0.0], &[1.0]), None);
+
+        let mut v = vec![3.0, 4.0];
+        l2_normalize(&mut v);
+        assert_eq!(v, vec![0.6, 0.8]);
+    }
+
+    #[tokio::test]
+    async fn test_in_memory_vector_store() {
+        let mut store = InMemoryVectorStore::default();
+        store
+            .upsert(Record::new("a", vec![1.0, 0.0], serde_json::Value::Null))
+            .await
+            .unwrap();
+        store
+            .upsert(Record::new("b", vec![0.7, 0.7], serde_json::Value::Null))
+            .await
+            .unwrap();
+        store
+            .upsert(Record::new("c", vec![0.0, 1.0], serde_json::Value::Null))
+            .await
+            .unwrap();
+
+        let ids = |matches: Vec<ScoredRecord>| {
+            matches.into_iter().map(|m| m.record.id).collect::<Vec<_>>()
+        };
+        assert_eq!(ids(store.query(&[1.0, 0.1], 2).await.unwrap()), ["a", "b"]);
+
+        store
+            .upsert(Record::new("a", vec![0.0, -1.0], serde_json::Value::Null))
+            .await
+            .unwrap();
+        assert_eq!(store.len(), 3);
+        assert_eq!(ids(store.query(&[1.0, 0.1], 2).await.unwrap()), ["b", "c"]);
+
+        assert!(store.delete("b").await.unwrap());
+        assert!(!store.delete("b").await.unwrap());
+        assert_eq!(ids(store.query(&[1.0, 0.1], 5).await.unwrap()), ["c", "a"]);
+
+        assert!(matches!(
+            store
+                .upsert(Record::new("d", vec![1.0], serde_json::Value::Null))
+                .await,
+            Err(Error::DimensionMismatch {
+                expected: 2,
+                received: 1
+            })
+        ));
+        assert!(matches!(
+            store.query(&[1.0, 0.0, 0.0], 1).await,
+            Err(Error::DimensionMismatch {
+                expected: 2,
+                received: 3
+            })
+        ));
+        assert_eq!(store.len(), 2);
+
+        let mut store = InMemoryVectorStore::default();
+        store
+            .upsert(Record::new("a", vec![1.0, 0.0], serde_json::Value::Null))
+            .await
+            .unwrap();
+        // The only record can be replaced by a vector of other dimensions.
+        store
+            .upsert(Record::new(
+                "a",
+                vec![1.0, 0.0, 0.0],
+                serde_json::Value::Null,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(ids(store.query(&[1.0, 0.0, 0.0], 1).await.unwrap()), ["a"]);
+    }
+}